@@ -0,0 +1,835 @@
+//! Contains a builder for aggregation pipelines with typed stages.
+//!
+//! A [`Pipeline`] is validated and converted into the `Vec<Document>` accepted by methods such as
+//! [`Collection::aggregate`](../struct.Collection.html#method.aggregate) when it is built:
+//!
+//! ```rust
+//! # use mongodb::{bson::{doc, Document}, error::Result, Collection};
+//! use mongodb::aggregation::{Accumulator, Group, Pipeline};
+//!
+//! # async fn run(coll: Collection<Document>) -> Result<()> {
+//! let pipeline = Pipeline::new()
+//!     .match_(doc! { "status": "shipped" })
+//!     .group(
+//!         Group::new("$customer")
+//!             .accumulator("total", Accumulator::Sum("$amount".into()))
+//!             .accumulator("orders", Accumulator::Count),
+//!     )
+//!     .sort(doc! { "total": -1 })
+//!     .limit(10)
+//!     .build()?;
+//! let cursor = coll.aggregate(pipeline, None).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(test)]
+mod test;
+
+use typed_builder::TypedBuilder;
+
+use crate::{
+    bson::{doc, Bson, Document},
+    error::{Error, ErrorKind, Result},
+    Namespace,
+};
+
+/// A sequence of aggregation stages. Stages are checked when the pipeline is built with
+/// [`Pipeline::build`].
+#[derive(Clone, Debug, Default)]
+pub struct Pipeline {
+    stages: Vec<Stage>,
+}
+
+impl Pipeline {
+    /// Creates an empty pipeline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a stage to the pipeline.
+    pub fn stage(mut self, stage: impl Into<Stage>) -> Self {
+        self.stages.push(stage.into());
+        self
+    }
+
+    /// Appends a `$match` stage that filters the documents using `filter`.
+    pub fn match_(self, filter: Document) -> Self {
+        self.stage(Stage::Match(filter))
+    }
+
+    /// Appends a `$project` stage with the given specification.
+    pub fn project(self, projection: Document) -> Self {
+        self.stage(Stage::Project(projection))
+    }
+
+    /// Appends a `$group` stage.
+    pub fn group(self, group: Group) -> Self {
+        self.stage(Stage::Group(group))
+    }
+
+    /// Appends a `$lookup` stage.
+    pub fn lookup(self, lookup: Lookup) -> Self {
+        self.stage(Stage::Lookup(lookup))
+    }
+
+    /// Appends an `$unwind` stage. A field path such as `"$items"` can be passed for the
+    /// stage's default behavior.
+    pub fn unwind(self, unwind: impl Into<Unwind>) -> Self {
+        self.stage(Stage::Unwind(unwind.into()))
+    }
+
+    /// Appends a `$sort` stage that sorts the documents by `sort`.
+    pub fn sort(self, sort: Document) -> Self {
+        self.stage(Stage::Sort(sort))
+    }
+
+    /// Appends a `$limit` stage.
+    pub fn limit(self, limit: u64) -> Self {
+        self.stage(Stage::Limit(limit))
+    }
+
+    /// Appends a `$facet` stage that runs each of the named sub-pipelines on the same input
+    /// documents.
+    pub fn facet<S: Into<String>>(self, facets: impl IntoIterator<Item = (S, Pipeline)>) -> Self {
+        let facets = facets
+            .into_iter()
+            .map(|(name, pipeline)| (name.into(), pipeline))
+            .collect();
+        self.stage(Stage::Facet(facets))
+    }
+
+    /// Appends a `$merge` stage. This must be the last stage of the pipeline.
+    pub fn merge(self, merge: Merge) -> Self {
+        self.stage(Stage::Merge(merge))
+    }
+
+    /// Appends an `$out` stage that writes the documents to `target`. This must be the last stage
+    /// of the pipeline.
+    pub fn out(self, target: impl Into<OutputCollection>) -> Self {
+        self.stage(Stage::Out(target.into()))
+    }
+
+    /// Appends a `$setWindowFields` stage.
+    pub fn set_window_fields(self, set_window_fields: SetWindowFields) -> Self {
+        self.stage(Stage::SetWindowFields(set_window_fields))
+    }
+
+    /// The stages of the pipeline.
+    pub fn stages(&self) -> &[Stage] {
+        &self.stages
+    }
+
+    /// Validates the stages of the pipeline and converts them into the documents accepted by
+    /// the `aggregate` methods.
+    ///
+    /// An [`ErrorKind::InvalidArgument`] error is returned if a stage is malformed or is not
+    /// allowed at its position in the pipeline.
+    pub fn build(&self) -> Result<Vec<Document>> {
+        self.build_common(None)
+    }
+
+    /// Builds the pipeline. If the pipeline is nested in a stage, `parent` is the name of that
+    /// stage.
+    fn build_common(&self, parent: Option<&str>) -> Result<Vec<Document>> {
+        let mut documents = Vec::with_capacity(self.stages.len());
+        for (i, stage) in self.stages.iter().enumerate() {
+            let document = stage.to_document()?;
+            let name = stage_name(&document);
+            if name == "$out" || name == "$merge" {
+                if let Some(parent) = parent {
+                    return Err(invalid_stage(
+                        &name,
+                        format!("cannot be used within a {} stage", parent),
+                    ));
+                }
+                if i + 1 != self.stages.len() {
+                    return Err(invalid_stage(&name, "must be the last stage of a pipeline"));
+                }
+            }
+            if name == "$facet" && parent == Some("$facet") {
+                return Err(invalid_stage(&name, "cannot be used within a $facet stage"));
+            }
+            documents.push(document);
+        }
+        Ok(documents)
+    }
+}
+
+impl TryFrom<Pipeline> for Vec<Document> {
+    type Error = Error;
+
+    fn try_from(pipeline: Pipeline) -> Result<Self> {
+        pipeline.build()
+    }
+}
+
+impl TryFrom<&Pipeline> for Vec<Document> {
+    type Error = Error;
+
+    fn try_from(pipeline: &Pipeline) -> Result<Self> {
+        pipeline.build()
+    }
+}
+
+/// A single stage of a [`Pipeline`].
+///
+/// See the MongoDB [manual](https://www.mongodb.com/docs/manual/reference/operator/aggregation-pipeline/)
+/// for more information on each stage.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Stage {
+    /// A `$match` stage with the given filter.
+    Match(Document),
+
+    /// A `$project` stage with the given specification.
+    Project(Document),
+
+    /// A `$group` stage.
+    Group(Group),
+
+    /// A `$lookup` stage.
+    Lookup(Lookup),
+
+    /// An `$unwind` stage.
+    Unwind(Unwind),
+
+    /// A `$sort` stage with the given sort specification.
+    Sort(Document),
+
+    /// A `$limit` stage.
+    Limit(u64),
+
+    /// A `$facet` stage with the given named sub-pipelines.
+    Facet(Vec<(String, Pipeline)>),
+
+    /// A `$merge` stage.
+    Merge(Merge),
+
+    /// An `$out` stage.
+    Out(OutputCollection),
+
+    /// A `$setWindowFields` stage.
+    SetWindowFields(SetWindowFields),
+
+    /// Any other stage, specified as a document with a single field whose name is the name of
+    /// the stage, e.g. `{ "$skip": 10 }`.
+    Custom(Document),
+}
+
+impl Stage {
+    fn to_document(&self) -> Result<Document> {
+        let document = match self {
+            Stage::Match(filter) => {
+                if filter.contains_key("$where") {
+                    return Err(invalid_stage(
+                        "$match",
+                        "cannot contain a $where expression",
+                    ));
+                }
+                doc! { "$match": filter.clone() }
+            }
+            Stage::Project(projection) => {
+                if projection.is_empty() {
+                    return Err(invalid_stage("$project", "must specify at least one field"));
+                }
+                doc! { "$project": projection.clone() }
+            }
+            Stage::Group(group) => doc! { "$group": group.to_document()? },
+            Stage::Lookup(lookup) => doc! { "$lookup": lookup.to_document()? },
+            Stage::Unwind(unwind) => doc! { "$unwind": unwind.to_bson()? },
+            Stage::Sort(sort) => {
+                validate_sort("$sort", sort)?;
+                doc! { "$sort": sort.clone() }
+            }
+            Stage::Limit(limit) => {
+                let limit = i64::try_from(*limit)
+                    .ok()
+                    .filter(|limit| *limit > 0)
+                    .ok_or_else(|| invalid_stage("$limit", "must be a positive 64-bit integer"))?;
+                doc! { "$limit": limit }
+            }
+            Stage::Facet(facets) => {
+                if facets.is_empty() {
+                    return Err(invalid_stage(
+                        "$facet",
+                        "must specify at least one sub-pipeline",
+                    ));
+                }
+                let mut spec = Document::new();
+                for (name, pipeline) in facets {
+                    if name.is_empty() || name.starts_with('$') || name.contains('.') {
+                        return Err(invalid_stage(
+                            "$facet",
+                            format!("has an invalid output field name {:?}", name),
+                        ));
+                    }
+                    spec.insert(name, pipeline.build_common(Some("$facet"))?);
+                }
+                doc! { "$facet": spec }
+            }
+            Stage::Merge(merge) => doc! { "$merge": merge.to_document()? },
+            Stage::Out(target) => doc! { "$out": target.to_bson() },
+            Stage::SetWindowFields(set_window_fields) => {
+                doc! { "$setWindowFields": set_window_fields.to_document()? }
+            }
+            Stage::Custom(stage) => {
+                let mut keys = stage.keys();
+                match (keys.next(), keys.next()) {
+                    (Some(name), None) if name.starts_with('$') => stage.clone(),
+                    _ => {
+                        return Err(ErrorKind::InvalidArgument {
+                            message: format!(
+                                "a pipeline stage must be a document with a single field naming \
+                                 the stage, got {}",
+                                stage
+                            ),
+                        }
+                        .into())
+                    }
+                }
+            }
+        };
+        Ok(document)
+    }
+}
+
+impl From<Group> for Stage {
+    fn from(group: Group) -> Self {
+        Stage::Group(group)
+    }
+}
+
+impl From<Lookup> for Stage {
+    fn from(lookup: Lookup) -> Self {
+        Stage::Lookup(lookup)
+    }
+}
+
+impl From<Unwind> for Stage {
+    fn from(unwind: Unwind) -> Self {
+        Stage::Unwind(unwind)
+    }
+}
+
+impl From<Merge> for Stage {
+    fn from(merge: Merge) -> Self {
+        Stage::Merge(merge)
+    }
+}
+
+impl From<SetWindowFields> for Stage {
+    fn from(set_window_fields: SetWindowFields) -> Self {
+        Stage::SetWindowFields(set_window_fields)
+    }
+}
+
+impl From<Document> for Stage {
+    fn from(stage: Document) -> Self {
+        Stage::Custom(stage)
+    }
+}
+
+/// The specification of a `$group` stage.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct Group {
+    /// The expression that the documents are grouped by.
+    pub id: Bson,
+
+    /// The output fields computed for each group, along with their accumulators.
+    pub accumulators: Vec<(String, Accumulator)>,
+}
+
+impl Group {
+    /// Creates a group stage that groups the documents by `id`, e.g. `"$field"`. Use
+    /// `Bson::Null` to compute the accumulators over all of the input documents.
+    pub fn new(id: impl Into<Bson>) -> Self {
+        Self {
+            id: id.into(),
+            accumulators: Vec::new(),
+        }
+    }
+
+    /// Adds an output field to each group, computed by `accumulator`.
+    pub fn accumulator(mut self, field: impl Into<String>, accumulator: Accumulator) -> Self {
+        self.accumulators.push((field.into(), accumulator));
+        self
+    }
+
+    fn to_document(&self) -> Result<Document> {
+        let mut spec = doc! { "_id": self.id.clone() };
+        for (field, accumulator) in &self.accumulators {
+            if field.is_empty() || field == "_id" || field.starts_with('$') || field.contains('.') {
+                return Err(invalid_stage(
+                    "$group",
+                    format!("has an invalid output field name {:?}", field),
+                ));
+            }
+            if spec.contains_key(field) {
+                return Err(invalid_stage(
+                    "$group",
+                    format!("has a duplicate output field {:?}", field),
+                ));
+            }
+            spec.insert(field, accumulator.to_document());
+        }
+        Ok(spec)
+    }
+}
+
+/// An accumulator used to compute an output field of a `$group` stage.
+///
+/// See the MongoDB [manual](https://www.mongodb.com/docs/manual/reference/operator/aggregation/group/#accumulator-operator)
+/// for more information.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Accumulator {
+    /// `$sum` of the expression.
+    Sum(Bson),
+
+    /// `$avg` of the expression.
+    Avg(Bson),
+
+    /// `$min` of the expression.
+    Min(Bson),
+
+    /// `$max` of the expression.
+    Max(Bson),
+
+    /// `$first` value of the expression.
+    First(Bson),
+
+    /// `$last` value of the expression.
+    Last(Bson),
+
+    /// `$push` each value of the expression into an array.
+    Push(Bson),
+
+    /// `$addToSet` each distinct value of the expression.
+    AddToSet(Bson),
+
+    /// `$count` of the documents in the group.
+    Count,
+
+    /// Any other accumulator, specified as a document such as `{ "$stdDevPop": "$x" }`.
+    Custom(Document),
+}
+
+impl Accumulator {
+    fn to_document(&self) -> Document {
+        match self {
+            Accumulator::Sum(expr) => doc! { "$sum": expr.clone() },
+            Accumulator::Avg(expr) => doc! { "$avg": expr.clone() },
+            Accumulator::Min(expr) => doc! { "$min": expr.clone() },
+            Accumulator::Max(expr) => doc! { "$max": expr.clone() },
+            Accumulator::First(expr) => doc! { "$first": expr.clone() },
+            Accumulator::Last(expr) => doc! { "$last": expr.clone() },
+            Accumulator::Push(expr) => doc! { "$push": expr.clone() },
+            Accumulator::AddToSet(expr) => doc! { "$addToSet": expr.clone() },
+            Accumulator::Count => doc! { "$count": {} },
+            Accumulator::Custom(accumulator) => accumulator.clone(),
+        }
+    }
+}
+
+/// The specification of a `$lookup` stage. Either both `local_field` and `foreign_field`, or
+/// `pipeline`, or all three must be specified.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct Lookup {
+    /// The collection in the same database to join with.
+    #[builder(!default)]
+    pub from: String,
+
+    /// The field of the input documents to match against `foreign_field`.
+    pub local_field: Option<String>,
+
+    /// The field of the documents in `from` to match against `local_field`.
+    pub foreign_field: Option<String>,
+
+    /// Variables to make available to `pipeline`, defined in terms of the input documents.
+    pub let_vars: Option<Document>,
+
+    /// The pipeline to run on the documents in `from`. It may not contain `$out` or `$merge`
+    /// stages.
+    pub pipeline: Option<Pipeline>,
+
+    /// The name of the array field added to the input documents that contains the joined
+    /// documents.
+    #[builder(!default)]
+    pub as_field: String,
+}
+
+impl Lookup {
+    fn to_document(&self) -> Result<Document> {
+        if self.from.is_empty() {
+            return Err(invalid_stage(
+                "$lookup",
+                "must specify a collection to join",
+            ));
+        }
+        if self.as_field.is_empty() {
+            return Err(invalid_stage("$lookup", "must specify an output field"));
+        }
+
+        let mut spec = doc! { "from": self.from.clone() };
+        match (&self.local_field, &self.foreign_field) {
+            (Some(local_field), Some(foreign_field)) => {
+                spec.insert("localField", local_field);
+                spec.insert("foreignField", foreign_field);
+            }
+            (None, None) if self.pipeline.is_some() => {}
+            (None, None) => {
+                return Err(invalid_stage(
+                    "$lookup",
+                    "must specify either a pipeline or both localField and foreignField",
+                ))
+            }
+            _ => {
+                return Err(invalid_stage(
+                    "$lookup",
+                    "must specify both localField and foreignField",
+                ))
+            }
+        }
+        if let Some(ref let_vars) = self.let_vars {
+            if self.pipeline.is_none() {
+                return Err(invalid_stage(
+                    "$lookup",
+                    "can only specify let variables with a pipeline",
+                ));
+            }
+            spec.insert("let", let_vars.clone());
+        }
+        if let Some(ref pipeline) = self.pipeline {
+            spec.insert("pipeline", pipeline.build_common(Some("$lookup"))?);
+        }
+        spec.insert("as", self.as_field.clone());
+        Ok(spec)
+    }
+}
+
+/// The specification of an `$unwind` stage.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct Unwind {
+    /// The field path of the array to unwind, prefixed with `$`.
+    #[builder(!default)]
+    pub path: String,
+
+    /// The name of a field to add to each output document containing the array index of the
+    /// element.
+    pub include_array_index: Option<String>,
+
+    /// Whether to output a document if `path` is null, missing or an empty array.
+    pub preserve_null_and_empty_arrays: Option<bool>,
+}
+
+impl Unwind {
+    fn to_bson(&self) -> Result<Bson> {
+        if !self.path.starts_with('$') || self.path.len() == 1 {
+            return Err(invalid_stage(
+                "$unwind",
+                format!(
+                    "path must be a field path prefixed with $, got {:?}",
+                    self.path
+                ),
+            ));
+        }
+        if self.include_array_index.is_none() && self.preserve_null_and_empty_arrays.is_none() {
+            return Ok(Bson::String(self.path.clone()));
+        }
+
+        let mut spec = doc! { "path": self.path.clone() };
+        if let Some(ref include_array_index) = self.include_array_index {
+            if include_array_index.starts_with('$') {
+                return Err(invalid_stage(
+                    "$unwind",
+                    "includeArrayIndex must be a field name without a $ prefix",
+                ));
+            }
+            spec.insert("includeArrayIndex", include_array_index);
+        }
+        if let Some(preserve) = self.preserve_null_and_empty_arrays {
+            spec.insert("preserveNullAndEmptyArrays", preserve);
+        }
+        Ok(Bson::Document(spec))
+    }
+}
+
+impl From<&str> for Unwind {
+    fn from(path: &str) -> Self {
+        Self::builder().path(path).build()
+    }
+}
+
+impl From<String> for Unwind {
+    fn from(path: String) -> Self {
+        Self::builder().path(path).build()
+    }
+}
+
+/// The collection that an `$out` or `$merge` stage writes to.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum OutputCollection {
+    /// A collection in the database the aggregation is run against.
+    Collection(String),
+
+    /// A collection in any database.
+    Namespace(Namespace),
+}
+
+impl OutputCollection {
+    fn to_bson(&self) -> Bson {
+        match self {
+            OutputCollection::Collection(coll) => Bson::String(coll.clone()),
+            OutputCollection::Namespace(ns) => {
+                Bson::Document(doc! { "db": ns.db.clone(), "coll": ns.coll.clone() })
+            }
+        }
+    }
+}
+
+impl From<&str> for OutputCollection {
+    fn from(coll: &str) -> Self {
+        OutputCollection::Collection(coll.to_string())
+    }
+}
+
+impl From<String> for OutputCollection {
+    fn from(coll: String) -> Self {
+        OutputCollection::Collection(coll)
+    }
+}
+
+impl From<Namespace> for OutputCollection {
+    fn from(ns: Namespace) -> Self {
+        OutputCollection::Namespace(ns)
+    }
+}
+
+/// The specification of a `$merge` stage.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct Merge {
+    /// The collection to merge the documents into.
+    #[builder(!default)]
+    pub into: OutputCollection,
+
+    /// The fields that identify a document in `into`. The server uses `_id` by default.
+    pub on: Option<Vec<String>>,
+
+    /// Variables to make available to a [`MergeWhenMatched::Pipeline`], defined in terms of the
+    /// documents output by the aggregation.
+    pub let_vars: Option<Document>,
+
+    /// The behavior when a document matches an existing document in `into`.
+    pub when_matched: Option<MergeWhenMatched>,
+
+    /// The behavior when a document does not match any existing document in `into`.
+    pub when_not_matched: Option<MergeWhenNotMatched>,
+}
+
+impl Merge {
+    fn to_document(&self) -> Result<Document> {
+        let mut spec = doc! { "into": self.into.to_bson() };
+        if let Some(ref on) = self.on {
+            if on.is_empty() {
+                return Err(invalid_stage(
+                    "$merge",
+                    "on must specify at least one field",
+                ));
+            }
+            spec.insert("on", on.clone());
+        }
+        if let Some(ref let_vars) = self.let_vars {
+            if !matches!(self.when_matched, Some(MergeWhenMatched::Pipeline(_))) {
+                return Err(invalid_stage(
+                    "$merge",
+                    "can only specify let variables with a whenMatched pipeline",
+                ));
+            }
+            spec.insert("let", let_vars.clone());
+        }
+        if let Some(ref when_matched) = self.when_matched {
+            spec.insert("whenMatched", when_matched.to_bson());
+        }
+        if let Some(ref when_not_matched) = self.when_not_matched {
+            spec.insert("whenNotMatched", when_not_matched.as_str());
+        }
+        Ok(spec)
+    }
+}
+
+/// The behavior of a `$merge` stage when a document matches an existing document.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum MergeWhenMatched {
+    /// Replace the existing document.
+    Replace,
+
+    /// Keep the existing document.
+    KeepExisting,
+
+    /// Merge the fields of the documents (default).
+    Merge,
+
+    /// Stop the aggregation with an error.
+    Fail,
+
+    /// Update the existing document with the given update pipeline.
+    Pipeline(Vec<Document>),
+}
+
+impl MergeWhenMatched {
+    fn to_bson(&self) -> Bson {
+        match self {
+            MergeWhenMatched::Replace => Bson::String("replace".to_string()),
+            MergeWhenMatched::KeepExisting => Bson::String("keepExisting".to_string()),
+            MergeWhenMatched::Merge => Bson::String("merge".to_string()),
+            MergeWhenMatched::Fail => Bson::String("fail".to_string()),
+            MergeWhenMatched::Pipeline(pipeline) => Bson::from(pipeline.clone()),
+        }
+    }
+}
+
+/// The behavior of a `$merge` stage when a document does not match any existing document.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum MergeWhenNotMatched {
+    /// Insert the document (default).
+    Insert,
+
+    /// Discard the document.
+    Discard,
+
+    /// Stop the aggregation with an error.
+    Fail,
+}
+
+impl MergeWhenNotMatched {
+    fn as_str(&self) -> &'static str {
+        match self {
+            MergeWhenNotMatched::Insert => "insert",
+            MergeWhenNotMatched::Discard => "discard",
+            MergeWhenNotMatched::Fail => "fail",
+        }
+    }
+}
+
+/// The specification of a `$setWindowFields` stage.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct SetWindowFields {
+    /// The expression that partitions the documents. All documents are in one partition by
+    /// default.
+    pub partition_by: Option<Bson>,
+
+    /// The order of the documents within each partition. This is required for windows with
+    /// bounds.
+    pub sort_by: Option<Document>,
+
+    /// The fields to add to each document, along with the window operators that compute them.
+    #[builder(!default)]
+    pub output: Document,
+}
+
+impl SetWindowFields {
+    fn to_document(&self) -> Result<Document> {
+        if self.output.is_empty() {
+            return Err(invalid_stage(
+                "$setWindowFields",
+                "must specify at least one output field",
+            ));
+        }
+
+        let mut spec = Document::new();
+        if let Some(ref partition_by) = self.partition_by {
+            spec.insert("partitionBy", partition_by.clone());
+        }
+        if let Some(ref sort_by) = self.sort_by {
+            validate_sort("$setWindowFields", sort_by)?;
+            spec.insert("sortBy", sort_by.clone());
+        }
+        for (field, output) in &self.output {
+            let window = match output {
+                Bson::Document(output) => output.get_document("window").ok(),
+                _ => {
+                    return Err(invalid_stage(
+                        "$setWindowFields",
+                        format!("output field {:?} must be a document", field),
+                    ))
+                }
+            };
+            let window = match window {
+                Some(window) => window,
+                None => continue,
+            };
+            if self.sort_by.is_none() {
+                return Err(invalid_stage(
+                    "$setWindowFields",
+                    format!(
+                        "output field {:?} specifies a window but sortBy is not set",
+                        field
+                    ),
+                ));
+            }
+            if window.contains_key("range") && self.sort_by.as_ref().map(Document::len) != Some(1) {
+                return Err(invalid_stage(
+                    "$setWindowFields",
+                    format!(
+                        "output field {:?} specifies a range window, which requires sortBy to \
+                         have exactly one field",
+                        field
+                    ),
+                ));
+            }
+        }
+        spec.insert("output", self.output.clone());
+        Ok(spec)
+    }
+}
+
+/// Returns the name of the stage represented by `document`.
+fn stage_name(document: &Document) -> String {
+    document.keys().next().cloned().unwrap_or_default()
+}
+
+/// Checks that each value of a sort specification is 1, -1 or a `$meta` document.
+fn validate_sort(stage: &str, sort: &Document) -> Result<()> {
+    if sort.is_empty() {
+        return Err(invalid_stage(stage, "sort must specify at least one field"));
+    }
+    for (field, order) in sort {
+        let valid = match order {
+            Bson::Int32(i) => *i == 1 || *i == -1,
+            Bson::Int64(i) => *i == 1 || *i == -1,
+            Bson::Double(f) => *f == 1.0 || *f == -1.0,
+            Bson::Document(d) => d.contains_key("$meta"),
+            _ => false,
+        };
+        if !valid {
+            return Err(invalid_stage(
+                stage,
+                format!(
+                    "sort order for {:?} must be 1, -1 or a $meta expression, got {}",
+                    field, order
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn invalid_stage(stage: &str, message: impl AsRef<str>) -> Error {
+    ErrorKind::InvalidArgument {
+        message: format!("invalid {} stage: {}", stage, message.as_ref()),
+    }
+    .into()
+}
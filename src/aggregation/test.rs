@@ -0,0 +1,305 @@
+use crate::{
+    aggregation::{
+        Accumulator,
+        Group,
+        Lookup,
+        Merge,
+        MergeWhenMatched,
+        MergeWhenNotMatched,
+        Pipeline,
+        SetWindowFields,
+        Stage,
+        Unwind,
+    },
+    bson::{doc, Bson, Document},
+    error::ErrorKind,
+    Namespace,
+};
+
+fn assert_invalid(pipeline: Pipeline, expected: &str) {
+    let error = pipeline.build().unwrap_err();
+    match *error.kind {
+        ErrorKind::InvalidArgument { ref message } => assert!(
+            message.contains(expected),
+            "expected {:?} in {:?}",
+            expected,
+            message
+        ),
+        ref e => panic!("expected invalid argument error, got {:?}", e),
+    }
+}
+
+#[test]
+fn build() {
+    let pipeline = Pipeline::new()
+        .match_(doc! { "status": "A" })
+        .lookup(
+            Lookup::builder()
+                .from("inventory")
+                .local_field("item".to_string())
+                .foreign_field("sku".to_string())
+                .as_field("inventory")
+                .build(),
+        )
+        .unwind("$inventory")
+        .group(
+            Group::new("$cust_id")
+                .accumulator("total", Accumulator::Sum("$amount".into()))
+                .accumulator("count", Accumulator::Count)
+                .accumulator(
+                    "stddev",
+                    Accumulator::Custom(doc! { "$stdDevPop": "$amount" }),
+                ),
+        )
+        .project(doc! { "total": 1 })
+        .sort(doc! { "total": -1 })
+        .limit(5)
+        .stage(doc! { "$skip": 1 })
+        .out("results");
+
+    assert_eq!(
+        pipeline.build().unwrap(),
+        vec![
+            doc! { "$match": { "status": "A" } },
+            doc! {
+                "$lookup": {
+                    "from": "inventory",
+                    "localField": "item",
+                    "foreignField": "sku",
+                    "as": "inventory",
+                },
+            },
+            doc! { "$unwind": "$inventory" },
+            doc! {
+                "$group": {
+                    "_id": "$cust_id",
+                    "total": { "$sum": "$amount" },
+                    "count": { "$count": {} },
+                    "stddev": { "$stdDevPop": "$amount" },
+                },
+            },
+            doc! { "$project": { "total": 1 } },
+            doc! { "$sort": { "total": -1 } },
+            doc! { "$limit": 5_i64 },
+            doc! { "$skip": 1 },
+            doc! { "$out": "results" },
+        ]
+    );
+    assert_eq!(pipeline.stages().len(), 9);
+    assert_eq!(Vec::<Document>::try_from(pipeline).unwrap().len(), 9);
+}
+
+#[test]
+fn build_nested_pipelines() {
+    let pipeline = Pipeline::new()
+        .lookup(
+            Lookup::builder()
+                .from("orders")
+                .let_vars(doc! { "id": "$_id" })
+                .pipeline(Pipeline::new().match_(doc! { "$expr": { "$eq": ["$cust", "$$id"] } }))
+                .as_field("orders")
+                .build(),
+        )
+        .facet(vec![
+            ("count", Pipeline::new().stage(doc! { "$count": "n" })),
+            ("top", Pipeline::new().sort(doc! { "x": -1 }).limit(1)),
+        ]);
+
+    assert_eq!(
+        pipeline.build().unwrap(),
+        vec![
+            doc! {
+                "$lookup": {
+                    "from": "orders",
+                    "let": { "id": "$_id" },
+                    "pipeline": [{ "$match": { "$expr": { "$eq": ["$cust", "$$id"] } } }],
+                    "as": "orders",
+                },
+            },
+            doc! {
+                "$facet": {
+                    "count": [{ "$count": "n" }],
+                    "top": [{ "$sort": { "x": -1 } }, { "$limit": 1_i64 }],
+                },
+            },
+        ]
+    );
+}
+
+#[test]
+fn build_merge_and_window_fields() {
+    let pipeline = Pipeline::new()
+        .unwind(
+            Unwind::builder()
+                .path("$sizes")
+                .include_array_index("index".to_string())
+                .preserve_null_and_empty_arrays(true)
+                .build(),
+        )
+        .set_window_fields(
+            SetWindowFields::builder()
+                .partition_by(Bson::from("$state"))
+                .sort_by(doc! { "date": 1 })
+                .output(doc! {
+                    "runningTotal": {
+                        "$sum": "$quantity",
+                        "window": { "documents": ["unbounded", "current"] },
+                    },
+                })
+                .build(),
+        )
+        .merge(
+            Merge::builder()
+                .into(Namespace {
+                    db: "reporting".to_string(),
+                    coll: "totals".to_string(),
+                })
+                .on(vec!["state".to_string()])
+                .let_vars(doc! { "total": "$runningTotal" })
+                .when_matched(MergeWhenMatched::Pipeline(vec![
+                    doc! { "$set": { "total": "$$total" } },
+                ]))
+                .when_not_matched(MergeWhenNotMatched::Discard)
+                .build(),
+        );
+
+    let documents = pipeline.build().unwrap();
+    assert_eq!(
+        documents[0],
+        doc! {
+            "$unwind": {
+                "path": "$sizes",
+                "includeArrayIndex": "index",
+                "preserveNullAndEmptyArrays": true,
+            },
+        }
+    );
+    assert_eq!(
+        documents[1],
+        doc! {
+            "$setWindowFields": {
+                "partitionBy": "$state",
+                "sortBy": { "date": 1 },
+                "output": {
+                    "runningTotal": {
+                        "$sum": "$quantity",
+                        "window": { "documents": ["unbounded", "current"] },
+                    },
+                },
+            },
+        }
+    );
+    assert_eq!(
+        documents[2],
+        doc! {
+            "$merge": {
+                "into": { "db": "reporting", "coll": "totals" },
+                "on": ["state"],
+                "let": { "total": "$runningTotal" },
+                "whenMatched": [{ "$set": { "total": "$$total" } }],
+                "whenNotMatched": "discard",
+            },
+        }
+    );
+}
+
+#[test]
+fn output_stage_must_be_last() {
+    assert_invalid(
+        Pipeline::new().out("results").match_(doc! {}),
+        "invalid $out stage: must be the last stage",
+    );
+    assert_invalid(
+        Pipeline::new()
+            .merge(Merge::builder().into("results").build())
+            .limit(1),
+        "invalid $merge stage: must be the last stage",
+    );
+    assert_invalid(
+        Pipeline::new().stage(doc! { "$out": "results" }).limit(1),
+        "invalid $out stage: must be the last stage",
+    );
+}
+
+#[test]
+fn output_stage_not_allowed_in_sub_pipeline() {
+    assert_invalid(
+        Pipeline::new().facet(vec![("out", Pipeline::new().out("results"))]),
+        "invalid $out stage: cannot be used within a $facet stage",
+    );
+    assert_invalid(
+        Pipeline::new().lookup(
+            Lookup::builder()
+                .from("other")
+                .pipeline(Pipeline::new().merge(Merge::builder().into("results").build()))
+                .as_field("joined")
+                .build(),
+        ),
+        "invalid $merge stage: cannot be used within a $lookup stage",
+    );
+    assert_invalid(
+        Pipeline::new().facet(vec![(
+            "nested",
+            Pipeline::new().facet(vec![("inner", Pipeline::new().limit(1))]),
+        )]),
+        "invalid $facet stage: cannot be used within a $facet stage",
+    );
+}
+
+#[test]
+fn invalid_stages() {
+    assert_invalid(Pipeline::new().limit(0), "invalid $limit stage");
+    assert_invalid(Pipeline::new().sort(doc! {}), "invalid $sort stage");
+    assert_invalid(
+        Pipeline::new().sort(doc! { "x": 2 }),
+        "sort order for \"x\" must be 1, -1 or a $meta expression",
+    );
+    assert_invalid(Pipeline::new().unwind("items"), "invalid $unwind stage");
+    assert_invalid(
+        Pipeline::new().match_(doc! { "$where": "this.x > 1" }),
+        "invalid $match stage",
+    );
+    assert_invalid(
+        Pipeline::new().group(Group::new(Bson::Null).accumulator("_id", Accumulator::Count)),
+        "invalid $group stage: has an invalid output field name",
+    );
+    assert_invalid(
+        Pipeline::new().group(
+            Group::new(Bson::Null)
+                .accumulator("n", Accumulator::Count)
+                .accumulator("n", Accumulator::Count),
+        ),
+        "invalid $group stage: has a duplicate output field",
+    );
+    assert_invalid(
+        Pipeline::new().lookup(
+            Lookup::builder()
+                .from("other")
+                .local_field("x".to_string())
+                .as_field("joined")
+                .build(),
+        ),
+        "must specify both localField and foreignField",
+    );
+    assert_invalid(
+        Pipeline::new().merge(
+            Merge::builder()
+                .into("results")
+                .let_vars(doc! { "x": 1 })
+                .build(),
+        ),
+        "can only specify let variables with a whenMatched pipeline",
+    );
+    assert_invalid(
+        Pipeline::new().set_window_fields(
+            SetWindowFields::builder()
+                .output(doc! { "total": { "$sum": "$x", "window": { "range": [-1, 0] } } })
+                .build(),
+        ),
+        "specifies a window but sortBy is not set",
+    );
+    assert_invalid(
+        Pipeline::new().stage(Stage::Custom(doc! { "skip": 1 })),
+        "a pipeline stage must be a document with a single field",
+    );
+}
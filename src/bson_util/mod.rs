@@ -1,10 +1,10 @@
 use std::{
-    convert::TryFrom,
+    convert::{TryFrom, TryInto},
     io::{Read, Write},
     time::Duration,
 };
 
-use bson::RawBsonRef;
+use bson::{oid::ObjectId, RawBsonRef, RawDocumentBuf};
 use serde::{de::Error as SerdeDeError, ser, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::{
@@ -180,6 +180,39 @@ where
         .ok_or_else(|| D::Error::custom(format!("could not deserialize u64 from {:?}", bson)))
 }
 
+/// Returns the `_id` field of the provided document. If the document does not have an `_id`
+/// field, a new [`ObjectId`] is generated and inserted at the beginning of the document.
+pub(crate) fn get_or_prepend_id_field(doc: &mut RawDocumentBuf) -> Result<Bson> {
+    if let Some(id) = doc.get("_id")? {
+        return Ok(id.try_into()?);
+    }
+
+    let id = Bson::ObjectId(ObjectId::new());
+    prepend_id_field(doc, &id)?;
+    Ok(id)
+}
+
+/// Inserts the given `_id` field at the beginning of the provided document, which must not already
+/// contain one.
+pub(crate) fn prepend_id_field(doc: &mut RawDocumentBuf, id: &Bson) -> Result<()> {
+    let new_doc = bson::to_raw_document_buf(&doc! { "_id": id.clone() })?;
+
+    let mut new_bytes = new_doc.into_bytes();
+    new_bytes.pop(); // remove trailing null byte
+
+    let mut bytes = std::mem::replace(doc, RawDocumentBuf::new()).into_bytes();
+    let id_slice = &new_bytes[4..];
+    // insert id at beginning of document
+    bytes.splice(4..4, id_slice.iter().cloned());
+
+    // overwrite old length
+    let new_length = (bytes.len() as i32).to_le_bytes();
+    bytes[0..4].copy_from_slice(&new_length);
+    *doc = RawDocumentBuf::from_bytes(bytes)?;
+
+    Ok(())
+}
+
 /// The size in bytes of the provided document's entry in a BSON array at the given index.
 pub(crate) fn array_entry_size_bytes(index: usize, doc_len: usize) -> u64 {
     //   * type (1 byte)
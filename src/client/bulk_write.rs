@@ -0,0 +1,274 @@
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+use super::Client;
+use crate::{
+    bson::Document,
+    bson_util,
+    error::{ClientBulkWriteFailure, Error, ErrorKind, Result, WriteError},
+    operation::{ClientBulkWrite, ClientBulkWriteSummary, SingleOperationResponse},
+    options::{ClientBulkWriteOptions, UpdateModel, UpdateModifications, WriteModel},
+    results::{ClientBulkWriteResult, DeleteResult, InsertOneResult, UpdateResult},
+    ClientSession,
+    Namespace,
+};
+
+impl Client {
+    /// Performs the writes specified by `models`, each of which is performed on the [`Namespace`]
+    /// it is paired with. The writes are sent to the server in as few `bulkWrite` commands as the
+    /// server's size limits allow, regardless of how many namespaces they target. This requires
+    /// MongoDB 8.0 or later.
+    ///
+    /// If the writes are ordered (the default), the remaining writes are not performed after a
+    /// write fails. The indexes in the returned [`ClientBulkWriteResult`] and in any
+    /// [`ClientBulkWriteFailure`] correspond to the positions of the models in `models`.
+    ///
+    /// This operation is not supported when automatic encryption is enabled.
+    pub async fn bulk_write<T: Serialize>(
+        &self,
+        models: impl IntoIterator<Item = (Namespace, WriteModel<T>)>,
+        options: impl Into<Option<ClientBulkWriteOptions>>,
+    ) -> Result<ClientBulkWriteResult> {
+        self.bulk_write_common(models, options, None).await
+    }
+
+    /// Performs the writes specified by `models` using the provided session. See
+    /// [`Client::bulk_write`] for more information.
+    pub async fn bulk_write_with_session<T: Serialize>(
+        &self,
+        models: impl IntoIterator<Item = (Namespace, WriteModel<T>)>,
+        options: impl Into<Option<ClientBulkWriteOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<ClientBulkWriteResult> {
+        self.bulk_write_common(models, options, Some(session)).await
+    }
+
+    async fn bulk_write_common<T: Serialize>(
+        &self,
+        models: impl IntoIterator<Item = (Namespace, WriteModel<T>)>,
+        options: impl Into<Option<ClientBulkWriteOptions>>,
+        mut session: Option<&mut ClientSession>,
+    ) -> Result<ClientBulkWriteResult> {
+        let models: Vec<_> = models.into_iter().collect();
+        let mut options = options.into();
+        resolve_write_concern_with_session!(self, options, session.as_ref())?;
+
+        if models.is_empty() {
+            return Err(ErrorKind::InvalidArgument {
+                message: "No write models provided to bulk_write".to_string(),
+            }
+            .into());
+        }
+        for (_, model) in &models {
+            if let WriteModel::UpdateOne(UpdateModel {
+                update: UpdateModifications::Document(ref d),
+                ..
+            })
+            | WriteModel::UpdateMany(UpdateModel {
+                update: UpdateModifications::Document(ref d),
+                ..
+            }) = model
+            {
+                bson_util::update_document_check(d)?;
+            }
+        }
+
+        #[cfg(feature = "csfle")]
+        if self.auto_encryption_opts().await.is_some() {
+            return Err(ErrorKind::InvalidArgument {
+                message: "Client::bulk_write is not supported with automatic encryption"
+                    .to_string(),
+            }
+            .into());
+        }
+
+        let ordered = options.as_ref().and_then(|o| o.ordered).unwrap_or(true);
+        let verbose = options
+            .as_ref()
+            .and_then(|o| o.verbose_results)
+            .unwrap_or(false);
+
+        let mut results = BulkWriteResults::new(&models, verbose);
+        let mut n_attempted = 0;
+        while n_attempted < models.len() {
+            let mut current_batch_size = 0;
+            let bulk_write = ClientBulkWrite::new(
+                &models[n_attempted..],
+                n_attempted,
+                options.clone(),
+                &mut current_batch_size,
+            );
+            let (summary, documents) = match self
+                .execute_cursor_operation_with_output(bulk_write, session.as_deref_mut())
+                .await
+            {
+                Ok(output) => output,
+                Err(error) => return Err(results.into_error(error)),
+            };
+
+            let offset = n_attempted;
+            results.add_summary(&summary);
+            match documents {
+                Ok(documents) => {
+                    for document in documents {
+                        results.add_operation_response(document, offset, &summary)?;
+                    }
+                }
+                Err(error) => return Err(results.into_error(error)),
+            }
+
+            // An ordered command stops at its first error, so the remaining models were not
+            // attempted.
+            if ordered && summary.n_errors > 0 {
+                break;
+            }
+            n_attempted += current_batch_size;
+        }
+
+        results.finish()
+    }
+}
+
+/// Accumulates the results of the commands sent as part of a [`Client::bulk_write`].
+struct BulkWriteResults<'a, T> {
+    models: &'a [(Namespace, WriteModel<T>)],
+    verbose: bool,
+    result: ClientBulkWriteResult,
+    failure: ClientBulkWriteFailure,
+    labels: HashSet<String>,
+}
+
+impl<'a, T> BulkWriteResults<'a, T> {
+    fn new(models: &'a [(Namespace, WriteModel<T>)], verbose: bool) -> Self {
+        Self {
+            models,
+            verbose,
+            result: ClientBulkWriteResult::new(),
+            failure: ClientBulkWriteFailure::new(),
+            labels: HashSet::new(),
+        }
+    }
+
+    fn add_summary(&mut self, summary: &ClientBulkWriteSummary) {
+        self.result.inserted_count += summary.n_inserted;
+        self.result.upserted_count += summary.n_upserted;
+        self.result.matched_count += summary.n_matched;
+        self.result.modified_count += summary.n_modified;
+        self.result.deleted_count += summary.n_deleted;
+        if let Some(ref wc_error) = summary.write_concern_error {
+            self.labels.extend(wc_error.labels.iter().cloned());
+            self.failure.write_concern_errors.push(wc_error.clone());
+        }
+    }
+
+    /// Records the result of a single write returned through the cursor of a command whose first
+    /// model was at index `offset` of `models`.
+    fn add_operation_response(
+        &mut self,
+        document: Document,
+        offset: usize,
+        summary: &ClientBulkWriteSummary,
+    ) -> Result<()> {
+        let response: SingleOperationResponse = bson::from_document(document.clone())?;
+        let index = offset + response.idx;
+        if response.ok == 0 {
+            let error: WriteError = bson::from_document(document)?;
+            self.failure.write_errors.insert(index, error);
+            return Ok(());
+        }
+        if !self.verbose {
+            return Ok(());
+        }
+
+        let model = self.models.get(index).map(|(_, model)| model);
+        match model {
+            Some(WriteModel::InsertOne(_)) => {
+                let inserted_id = summary.inserted_ids.get(&index).cloned().ok_or_else(|| {
+                    Error::internal(format!("no inserted id recorded for write {}", index))
+                })?;
+                self.result
+                    .insert_results
+                    .insert(index, InsertOneResult { inserted_id });
+            }
+            Some(WriteModel::UpdateOne(_))
+            | Some(WriteModel::UpdateMany(_))
+            | Some(WriteModel::ReplaceOne(_)) => {
+                let upserted_id = response.upserted.map(|upserted| upserted.id);
+                // An upserted document is included in `n` but did not match the filter.
+                let matched_count = if upserted_id.is_some() {
+                    response.n.saturating_sub(1)
+                } else {
+                    response.n
+                };
+                self.result.update_results.insert(
+                    index,
+                    UpdateResult {
+                        matched_count,
+                        modified_count: response.n_modified.unwrap_or(0),
+                        upserted_id,
+                    },
+                );
+            }
+            Some(WriteModel::DeleteOne(_)) | Some(WriteModel::DeleteMany(_)) => {
+                self.result.delete_results.insert(
+                    index,
+                    DeleteResult {
+                        deleted_count: response.n,
+                    },
+                );
+            }
+            None => {
+                return Err(ErrorKind::InvalidResponse {
+                    message: format!("bulkWrite returned a result for unknown write {}", index),
+                }
+                .into())
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether any of the writes are known to have been performed.
+    fn has_partial_result(&self) -> bool {
+        let result = &self.result;
+        result.inserted_count
+            + result.upserted_count
+            + result.matched_count
+            + result.modified_count
+            + result.deleted_count
+            > 0
+    }
+
+    /// Returns the error to report when the bulk write is stopped by `error`. If any writes were
+    /// performed or failed before then, the error is reported as the source of a
+    /// [`ClientBulkWriteFailure`] containing them.
+    fn into_error(mut self, error: Error) -> Error {
+        if !self.has_partial_result()
+            && self.failure.write_errors.is_empty()
+            && self.failure.write_concern_errors.is_empty()
+        {
+            return error;
+        }
+
+        self.labels.extend(error.labels().iter().cloned());
+        if self.has_partial_result() {
+            self.failure.partial_result = Some(self.result);
+        }
+        Error::new(ErrorKind::ClientBulkWrite(self.failure), Some(self.labels)).with_source(error)
+    }
+
+    fn finish(mut self) -> Result<ClientBulkWriteResult> {
+        if self.failure.write_errors.is_empty() && self.failure.write_concern_errors.is_empty() {
+            return Ok(self.result);
+        }
+
+        if self.has_partial_result() {
+            self.failure.partial_result = Some(self.result);
+        }
+        Err(Error::new(
+            ErrorKind::ClientBulkWrite(self.failure),
+            Some(self.labels),
+        ))
+    }
+}
@@ -3,6 +3,7 @@ use bson::RawDocumentBuf;
 use bson::{doc, RawBsonRef, RawDocument, Timestamp};
 #[cfg(feature = "csfle")]
 use futures_core::future::BoxFuture;
+use futures_util::TryStreamExt;
 use lazy_static::lazy_static;
 use serde::de::DeserializeOwned;
 
@@ -10,7 +11,7 @@ use std::{collections::HashSet, sync::Arc, time::Instant};
 
 use super::{session::TransactionState, Client, ClientSession};
 use crate::{
-    bson::Document,
+    bson::{Bson, Document},
     change_stream::{
         event::ChangeStreamEvent,
         session::SessionChangeStream,
@@ -194,6 +195,47 @@ impl Client {
         ))
     }
 
+    /// Execute the given operation, which creates a cursor alongside some additional output, and
+    /// collect the documents from the cursor. An error that occurs while iterating the cursor is
+    /// returned alongside the additional output rather than in place of it.
+    pub(crate) async fn execute_cursor_operation_with_output<Op, R>(
+        &self,
+        op: Op,
+        session: Option<&mut ClientSession>,
+    ) -> Result<(R, Result<Vec<Document>>)>
+    where
+        Op: Operation<O = (CursorSpecification, R)>,
+    {
+        Box::pin(async {
+            match session {
+                Some(session) => {
+                    let mut details = self
+                        .execute_operation_with_details(op, &mut *session)
+                        .await?;
+                    let (cursor_spec, output) = details.output.operation_output;
+                    let pinned = self.pin_connection_for_session(
+                        &cursor_spec,
+                        &mut details.output.connection,
+                        session,
+                    )?;
+                    let mut cursor = SessionCursor::new(self.clone(), cursor_spec, pinned);
+                    let documents = cursor.stream(session).try_collect().await;
+                    Ok((output, documents))
+                }
+                None => {
+                    let mut details = self.execute_operation_with_details(op, None).await?;
+                    let (cursor_spec, output) = details.output.operation_output;
+                    let pinned = self
+                        .pin_connection_for_cursor(&cursor_spec, &mut details.output.connection)?;
+                    let cursor: Cursor<Document> =
+                        Cursor::new(self.clone(), cursor_spec, details.implicit_session, pinned);
+                    Ok((output, cursor.try_collect().await))
+                }
+            }
+        })
+        .await
+    }
+
     fn is_load_balanced(&self) -> bool {
         self.inner.options.load_balanced.unwrap_or(false)
     }
@@ -600,6 +642,7 @@ impl Client {
 
         let cmd_name = cmd.name.clone();
         let target_db = cmd.target_db.clone();
+        let document_sequences = std::mem::take(&mut cmd.document_sequences);
 
         let serialized = op.serialize_command(cmd)?;
         #[cfg(feature = "csfle")]
@@ -622,14 +665,29 @@ impl Client {
             target_db,
             exhaust_allowed: false,
             bytes: serialized,
+            document_sequences,
         };
 
         self.emit_command_event(|handler| {
             let command_body = if should_redact {
                 Document::new()
             } else {
-                Document::from_reader(raw_cmd.bytes.as_slice())
-                    .unwrap_or_else(|e| doc! { "serialization error": e.to_string() })
+                let mut command_body = Document::from_reader(raw_cmd.bytes.as_slice())
+                    .unwrap_or_else(|e| doc! { "serialization error": e.to_string() });
+                // Monitoring events report document sequences as arrays in the command.
+                for sequence in &raw_cmd.document_sequences {
+                    let documents: Vec<Bson> = sequence
+                        .documents
+                        .iter()
+                        .map(|doc| {
+                            doc.to_document()
+                                .map(Bson::Document)
+                                .unwrap_or_else(|e| Bson::String(e.to_string()))
+                        })
+                        .collect();
+                    command_body.insert(sequence.identifier.clone(), documents);
+                }
+                command_body
             };
             let command_started_event = CommandStartedEvent {
                 command: command_body,
@@ -1,4 +1,5 @@
 pub mod auth;
+mod bulk_write;
 #[cfg(feature = "csfle")]
 pub(crate) mod csfle;
 mod executor;
@@ -9,13 +9,14 @@ use std::{
     time::{Duration, Instant},
 };
 
+use futures_core::future::BoxFuture;
 use lazy_static::lazy_static;
 use uuid::Uuid;
 
 use crate::{
     bson::{doc, spec::BinarySubtype, Binary, Bson, Document, Timestamp},
     cmap::conn::PinnedConnectionHandle,
-    error::{ErrorKind, Result},
+    error::{ErrorKind, Result, TRANSIENT_TRANSACTION_ERROR, UNKNOWN_TRANSACTION_COMMIT_RESULT},
     operation::{AbortTransaction, CommitTransaction, Operation},
     options::{SessionOptions, TransactionOptions},
     runtime,
@@ -28,6 +29,9 @@ pub(super) use pool::ServerSessionPool;
 
 use super::options::ServerAddress;
 
+/// The amount of time after which [`ClientSession::with_transaction`] stops retrying.
+pub(crate) const WITH_TRANSACTION_TIMEOUT: Duration = Duration::from_secs(120);
+
 lazy_static! {
     pub(crate) static ref SESSIONS_UNSUPPORTED_COMMANDS: HashSet<&'static str> = {
         let mut hash_set = HashSet::new();
@@ -561,6 +565,107 @@ impl ClientSession {
         }
     }
 
+    /// Starts a transaction, runs the given callback, and commits the transaction, retrying as
+    /// described below. The callback is passed this session, which must be passed to each
+    /// operation that should be executed within the transaction. Returns the value returned by
+    /// the final invocation of the callback.
+    ///
+    /// If the callback returns an error, the transaction is aborted. The callback is then run
+    /// again in a new transaction if the error includes a
+    /// [`crate::error::TRANSIENT_TRANSACTION_ERROR`] label; otherwise, the error is returned. If
+    /// committing the transaction returns an error with a
+    /// [`crate::error::UNKNOWN_TRANSACTION_COMMIT_RESULT`] label, the commit is retried, and if
+    /// it returns an error with a [`crate::error::TRANSIENT_TRANSACTION_ERROR`] label, the whole
+    /// transaction is retried. No retries are started once 120 seconds have elapsed since this
+    /// method was called, and the most recent error is returned instead.
+    ///
+    /// Because the callback may be run multiple times, it should not have side effects other
+    /// than the operations it performs within the transaction. If the callback commits or aborts
+    /// the transaction itself, the value it returns is returned without any further attempts to
+    /// commit.
+    ///
+    /// ```rust
+    /// # use futures::FutureExt;
+    /// # use mongodb::{bson::{doc, Document}, error::Result, Client, ClientSession};
+    /// #
+    /// # async fn do_stuff() -> Result<()> {
+    /// # let client = Client::with_uri_str("mongodb://example.com").await?;
+    /// # let mut session = client.start_session(None).await?;
+    /// let coll = client.database("foo").collection::<Document>("bar");
+    /// let deleted_count = session
+    ///     .with_transaction(
+    ///         |session| {
+    ///             let coll = coll.clone();
+    ///             async move {
+    ///                 coll.insert_one_with_session(doc! { "x": 1 }, None, &mut *session)
+    ///                     .await?;
+    ///                 let result = coll
+    ///                     .delete_many_with_session(doc! { "y": 2 }, None, session)
+    ///                     .await?;
+    ///                 Ok(result.deleted_count)
+    ///             }
+    ///             .boxed()
+    ///         },
+    ///         None,
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn with_transaction<R, F>(
+        &mut self,
+        mut callback: F,
+        options: impl Into<Option<TransactionOptions>>,
+    ) -> Result<R>
+    where
+        F: for<'a> FnMut(&'a mut ClientSession) -> BoxFuture<'a, Result<R>>,
+    {
+        let options = options.into();
+        let start = Instant::now();
+
+        'transaction: loop {
+            self.start_transaction(options.clone()).await?;
+            let value = match callback(self).await {
+                Ok(value) => value,
+                Err(error) => {
+                    if self.in_transaction() {
+                        self.abort_transaction().await?;
+                    }
+                    if error.contains_label(TRANSIENT_TRANSACTION_ERROR)
+                        && start.elapsed() < WITH_TRANSACTION_TIMEOUT
+                    {
+                        continue 'transaction;
+                    }
+                    return Err(error);
+                }
+            };
+
+            if !self.in_transaction() {
+                // The callback committed or aborted the transaction itself.
+                return Ok(value);
+            }
+
+            loop {
+                let error = match self.commit_transaction().await {
+                    Ok(()) => return Ok(value),
+                    Err(error) => error,
+                };
+                if start.elapsed() >= WITH_TRANSACTION_TIMEOUT {
+                    return Err(error);
+                }
+                if error.contains_label(UNKNOWN_TRANSACTION_COMMIT_RESULT)
+                    && !error.is_max_time_ms_expired_error()
+                {
+                    continue;
+                }
+                if error.contains_label(TRANSIENT_TRANSACTION_ERROR) {
+                    continue 'transaction;
+                }
+                return Err(error);
+            }
+        }
+    }
+
     fn default_transaction_options(&self) -> Option<&TransactionOptions> {
         self.options
             .as_ref()
@@ -21,6 +21,8 @@ pub(crate) struct RawCommand {
     /// Whether or not the server may respond to this command multiple times via the moreToComeBit.
     pub(crate) exhaust_allowed: bool,
     pub(crate) bytes: Vec<u8>,
+    /// The document sequences to send alongside the command body.
+    pub(crate) document_sequences: Vec<DocumentSequence>,
 }
 
 impl RawCommand {
@@ -30,6 +32,29 @@ impl RawCommand {
     }
 }
 
+/// A sequence of documents sent in its own section of an OP_MSG rather than as an array within the
+/// command body, which allows the total size of the documents to exceed maxBsonObjectSize.
+#[derive(Clone, Debug)]
+pub(crate) struct DocumentSequence {
+    /// The name of the command field that the documents belong to.
+    pub(crate) identifier: String,
+    pub(crate) documents: Vec<RawDocumentBuf>,
+}
+
+impl DocumentSequence {
+    /// The number of bytes the sequence occupies in a message, excluding the payload type.
+    pub(crate) fn size_bytes(&self) -> usize {
+        std::mem::size_of::<i32>()
+            + self.identifier.len()
+            + 1
+            + self
+                .documents
+                .iter()
+                .map(|doc| doc.as_bytes().len())
+                .sum::<usize>()
+    }
+}
+
 /// Driver-side model of a database command.
 #[serde_with::skip_serializing_none]
 #[derive(Clone, Debug, Serialize, Default)]
@@ -41,6 +66,10 @@ pub(crate) struct Command<T = Document> {
     #[serde(skip)]
     pub(crate) exhaust_allowed: bool,
 
+    /// Sent as separate sections of the message, so these are not serialized with the command.
+    #[serde(skip)]
+    pub(crate) document_sequences: Vec<DocumentSequence>,
+
     #[serde(flatten)]
     pub(crate) body: T,
 
@@ -75,6 +104,7 @@ impl<T> Command<T> {
             name,
             target_db,
             exhaust_allowed: false,
+            document_sequences: Vec::new(),
             body,
             lsid: None,
             cluster_time: None,
@@ -98,6 +128,7 @@ impl<T> Command<T> {
             name,
             target_db,
             exhaust_allowed: false,
+            document_sequences: Vec::new(),
             body,
             lsid: None,
             cluster_time: None,
@@ -111,6 +142,17 @@ impl<T> Command<T> {
         }
     }
 
+    pub(crate) fn add_document_sequence(
+        &mut self,
+        identifier: impl Into<String>,
+        documents: Vec<RawDocumentBuf>,
+    ) {
+        self.document_sequences.push(DocumentSequence {
+            identifier: identifier.into(),
+            documents,
+        });
+    }
+
     pub(crate) fn set_session(&mut self, session: &ClientSession) {
         self.lsid = Some(session.id().clone())
     }
@@ -1,6 +1,7 @@
 use std::io::Read;
 
 use bitflags::bitflags;
+use bson::RawDocumentBuf;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 
 use super::header::{Header, OpCode};
@@ -38,6 +39,7 @@ impl Message {
                 target_db: command.target_db,
                 name: command.name,
                 exhaust_allowed: command.exhaust_allowed,
+                document_sequences: command.document_sequences,
             },
             request_id,
         ))
@@ -52,10 +54,23 @@ impl Message {
             flags |= MessageFlags::EXHAUST_ALLOWED;
         }
 
+        let mut sections = vec![MessageSection::Document(command.bytes)];
+        for sequence in command.document_sequences {
+            sections.push(MessageSection::Sequence {
+                size: sequence.size_bytes() as i32,
+                documents: sequence
+                    .documents
+                    .into_iter()
+                    .map(RawDocumentBuf::into_bytes)
+                    .collect(),
+                identifier: sequence.identifier,
+            });
+        }
+
         Self {
             response_to: 0,
             flags,
-            sections: vec![MessageSection::Document(command.bytes)],
+            sections,
             checksum: None,
             request_id,
         }
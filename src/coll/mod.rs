@@ -1,4 +1,7 @@
 pub mod options;
+mod paginate;
+mod sync_indexes;
+mod tail;
 
 use std::{borrow::Borrow, collections::HashSet, fmt, fmt::Debug, sync::Arc};
 
@@ -14,6 +17,10 @@ use serde::{
 };
 
 use self::options::*;
+pub use self::{
+    paginate::{ContinuationToken, Page, Paginator},
+    tail::TailableCursor,
+};
 use crate::{
     bson::{doc, to_document, Bson, Document},
     bson_util,
@@ -27,26 +34,37 @@ use crate::{
     cmap::conn::PinnedConnectionHandle,
     concern::{ReadConcern, WriteConcern},
     error::{convert_bulk_errors, BulkWriteError, BulkWriteFailure, Error, ErrorKind, Result},
-    index::IndexModel,
+    index::{IndexModel, SearchIndexModel},
     operation::{
         Aggregate,
+        BulkWrite,
+        ConvertToCapped,
         Count,
         CountDocuments,
         CreateIndexes,
+        CreateSearchIndexes,
         Delete,
         Distinct,
         DropCollection,
         DropIndexes,
+        DropSearchIndex,
+        Explain,
         Find,
         FindAndModify,
         Insert,
         ListIndexes,
+        ModifyCollection,
+        Operation,
+        RenameCollection,
         Update,
+        UpdateSearchIndex,
     },
     results::{
+        BulkWriteResult,
         CreateIndexResult,
         CreateIndexesResult,
         DeleteResult,
+        ExplainResult,
         InsertManyResult,
         InsertOneResult,
         UpdateResult,
@@ -253,6 +271,114 @@ impl<T> Collection<T> {
         self.drop_common(options, session).await
     }
 
+    async fn rename_common(
+        &self,
+        to: Namespace,
+        drop_target: bool,
+        options: impl Into<Option<RenameCollectionOptions>>,
+        session: impl Into<Option<&mut ClientSession>>,
+    ) -> Result<()> {
+        let mut options: Option<RenameCollectionOptions> = options.into();
+        resolve_options!(self, options, [write_concern]);
+
+        let rename = RenameCollection::new(self.namespace(), to, drop_target, options);
+        self.client().execute_operation(rename, session).await
+    }
+
+    /// Renames the collection to the namespace `to`, which may be in a different database. If
+    /// `drop_target` is true, an existing collection at `to` is dropped first; otherwise renaming
+    /// to an existing collection fails.
+    ///
+    /// This `Collection` continues to refer to the old namespace; use
+    /// [`Client::database`](struct.Client.html#method.database) and
+    /// [`Database::collection`](struct.Database.html#method.collection) to get a handle to the
+    /// renamed collection.
+    pub async fn rename(
+        &self,
+        to: Namespace,
+        drop_target: bool,
+        options: impl Into<Option<RenameCollectionOptions>>,
+    ) -> Result<()> {
+        self.rename_common(to, drop_target, options, None).await
+    }
+
+    /// Renames the collection to the namespace `to` using the provided `ClientSession`. If
+    /// `drop_target` is true, an existing collection at `to` is dropped first; otherwise renaming
+    /// to an existing collection fails.
+    pub async fn rename_with_session(
+        &self,
+        to: Namespace,
+        drop_target: bool,
+        options: impl Into<Option<RenameCollectionOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        self.rename_common(to, drop_target, options, session).await
+    }
+
+    async fn modify_common(
+        &self,
+        options: ModifyCollectionOptions,
+        session: impl Into<Option<&mut ClientSession>>,
+    ) -> Result<()> {
+        let mut options = Some(options);
+        resolve_options!(self, options, [write_concern]);
+
+        let modify = ModifyCollection::new(self.namespace(), options);
+        self.client().execute_operation(modify, session).await
+    }
+
+    /// Changes the properties of the collection that are set in `options`, such as its validator,
+    /// the TTL of an index or whether an index is hidden, using the
+    /// [`collMod`](https://www.mongodb.com/docs/manual/reference/command/collMod/) command.
+    pub async fn modify(&self, options: ModifyCollectionOptions) -> Result<()> {
+        self.modify_common(options, None).await
+    }
+
+    /// Changes the properties of the collection that are set in `options` using the provided
+    /// `ClientSession`.
+    pub async fn modify_with_session(
+        &self,
+        options: ModifyCollectionOptions,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        self.modify_common(options, session).await
+    }
+
+    async fn convert_to_capped_common(
+        &self,
+        size: u64,
+        options: impl Into<Option<ConvertToCappedOptions>>,
+        session: impl Into<Option<&mut ClientSession>>,
+    ) -> Result<()> {
+        let mut options: Option<ConvertToCappedOptions> = options.into();
+        resolve_options!(self, options, [write_concern]);
+
+        let convert = ConvertToCapped::new(self.namespace(), size, options);
+        self.client().execute_operation(convert, session).await
+    }
+
+    /// Converts the collection into a capped collection with a maximum size of `size` bytes, using
+    /// the [`convertToCapped`](https://www.mongodb.com/docs/manual/reference/command/convertToCapped/)
+    /// command. The indexes of the collection other than the `_id` index are dropped.
+    pub async fn convert_to_capped(
+        &self,
+        size: u64,
+        options: impl Into<Option<ConvertToCappedOptions>>,
+    ) -> Result<()> {
+        self.convert_to_capped_common(size, options, None).await
+    }
+
+    /// Converts the collection into a capped collection with a maximum size of `size` bytes using
+    /// the provided `ClientSession`.
+    pub async fn convert_to_capped_with_session(
+        &self,
+        size: u64,
+        options: impl Into<Option<ConvertToCappedOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        self.convert_to_capped_common(size, options, session).await
+    }
+
     #[cfg(feature = "csfle")]
     #[allow(clippy::needless_option_as_deref)]
     async fn drop_aux_collections(
@@ -323,6 +449,50 @@ impl<T> Collection<T> {
         pipeline: impl IntoIterator<Item = Document>,
         options: impl Into<Option<AggregateOptions>>,
     ) -> Result<Cursor<Document>> {
+        self.aggregate_as(pipeline, options).await
+    }
+
+    /// Runs an aggregation operation using the provided `ClientSession`.
+    ///
+    /// See the documentation [here](https://www.mongodb.com/docs/manual/aggregation/) for more
+    /// information on aggregations.
+    pub async fn aggregate_with_session(
+        &self,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<AggregateOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<SessionCursor<Document>> {
+        self.aggregate_as_with_session(pipeline, options, session)
+            .await
+    }
+
+    /// Runs an aggregation operation whose output documents are deserialized as `R`. Each
+    /// document is deserialized from the cursor's buffered batch when it is returned.
+    ///
+    /// ```rust
+    /// # use mongodb::{bson::{doc, Document}, error::Result, Collection, Cursor};
+    /// # use serde::Deserialize;
+    /// #[derive(Deserialize)]
+    /// struct Total {
+    ///     #[serde(rename = "_id")]
+    ///     customer: String,
+    ///     total: i64,
+    /// }
+    ///
+    /// # async fn run(coll: Collection<Document>) -> Result<()> {
+    /// let pipeline = vec![doc! { "$group": { "_id": "$customer", "total": { "$sum": "$amount" } } }];
+    /// let totals: Cursor<Total> = coll.aggregate_as(pipeline, None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// See the documentation [here](https://www.mongodb.com/docs/manual/aggregation/) for more
+    /// information on aggregations.
+    pub async fn aggregate_as<R>(
+        &self,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<AggregateOptions>>,
+    ) -> Result<Cursor<R>> {
         let mut options = options.into();
         resolve_options!(
             self,
@@ -335,16 +505,14 @@ impl<T> Collection<T> {
         client.execute_cursor_operation(aggregate).await
     }
 
-    /// Runs an aggregation operation using the provided `ClientSession`.
-    ///
-    /// See the documentation [here](https://www.mongodb.com/docs/manual/aggregation/) for more
-    /// information on aggregations.
-    pub async fn aggregate_with_session(
+    /// Runs an aggregation operation whose output documents are deserialized as `R` using the
+    /// provided `ClientSession`. See [`Collection::aggregate_as`] for more information.
+    pub async fn aggregate_as_with_session<R>(
         &self,
         pipeline: impl IntoIterator<Item = Document>,
         options: impl Into<Option<AggregateOptions>>,
         session: &mut ClientSession,
-    ) -> Result<SessionCursor<Document>> {
+    ) -> Result<SessionCursor<R>> {
         let mut options = options.into();
         resolve_read_concern_with_session!(self, options, Some(&mut *session))?;
         resolve_write_concern_with_session!(self, options, Some(&mut *session))?;
@@ -732,6 +900,105 @@ impl<T> Collection<T> {
         self.list_index_names_common(cursor.stream(session)).await
     }
 
+    /// Creates the given Atlas Search or Atlas Vector Search index on this collection, returning
+    /// its name.
+    ///
+    /// Search indexes are built asynchronously; use
+    /// [`Collection::list_search_indexes`](#method.list_search_indexes) to check whether the index
+    /// is ready to be queried. This method is only supported on Atlas deployments.
+    pub async fn create_search_index(
+        &self,
+        model: SearchIndexModel,
+        options: impl Into<Option<CreateSearchIndexOptions>>,
+    ) -> Result<String> {
+        let mut names = self.create_search_indexes(vec![model], options).await?;
+        names
+            .pop()
+            .ok_or_else(|| Error::internal("no search index was created"))
+    }
+
+    /// Creates the given Atlas Search or Atlas Vector Search indexes on this collection, returning
+    /// their names.
+    ///
+    /// Search indexes are built asynchronously; use
+    /// [`Collection::list_search_indexes`](#method.list_search_indexes) to check whether the
+    /// indexes are ready to be queried. This method is only supported on Atlas deployments.
+    pub async fn create_search_indexes(
+        &self,
+        models: impl IntoIterator<Item = SearchIndexModel>,
+        options: impl Into<Option<CreateSearchIndexOptions>>,
+    ) -> Result<Vec<String>> {
+        let create_search_indexes = CreateSearchIndexes::new(
+            self.namespace(),
+            models.into_iter().collect(),
+            options.into(),
+        );
+        self.client()
+            .execute_operation(create_search_indexes, None)
+            .await
+    }
+
+    /// Replaces the definition of the Atlas Search or Atlas Vector Search index with the given
+    /// `name`. This method is only supported on Atlas deployments.
+    pub async fn update_search_index(
+        &self,
+        name: impl AsRef<str>,
+        definition: Document,
+        options: impl Into<Option<UpdateSearchIndexOptions>>,
+    ) -> Result<()> {
+        let update_search_index = UpdateSearchIndex::new(
+            self.namespace(),
+            name.as_ref().to_string(),
+            definition,
+            options.into(),
+        );
+        self.client()
+            .execute_operation(update_search_index, None)
+            .await
+    }
+
+    /// Drops the Atlas Search or Atlas Vector Search index with the given `name`. Dropping an index
+    /// that does not exist succeeds. This method is only supported on Atlas deployments.
+    pub async fn drop_search_index(
+        &self,
+        name: impl AsRef<str>,
+        options: impl Into<Option<DropSearchIndexOptions>>,
+    ) -> Result<()> {
+        let drop_search_index =
+            DropSearchIndex::new(self.namespace(), name.as_ref().to_string(), options.into());
+        self.client()
+            .execute_operation(drop_search_index, None)
+            .await
+    }
+
+    /// Lists the Atlas Search and Atlas Vector Search indexes on this collection, or only the
+    /// index with the given `name` if one is specified, using a `$listSearchIndexes` aggregation.
+    /// This method is only supported on Atlas deployments.
+    ///
+    /// See the [documentation](https://www.mongodb.com/docs/manual/reference/operator/aggregation/listSearchIndexes/)
+    /// for the fields of the returned documents.
+    pub async fn list_search_indexes(
+        &self,
+        name: impl Into<Option<&str>>,
+        options: impl Into<Option<ListSearchIndexesOptions>>,
+    ) -> Result<Cursor<Document>> {
+        let stage = match name.into() {
+            Some(name) => doc! { "name": name },
+            None => doc! {},
+        };
+        let options = options.into().unwrap_or_default();
+        let aggregate_options = AggregateOptions::builder()
+            .batch_size(options.batch_size)
+            .max_time(options.max_time)
+            .comment_bson(options.comment)
+            .build();
+        self.aggregate(
+            vec![doc! { "$listSearchIndexes": stage }],
+            aggregate_options,
+        )
+        .await
+    }
+
     async fn update_many_common(
         &self,
         query: Document,
@@ -856,6 +1123,7 @@ impl<T> Collection<T> {
         cursor_id: i64,
         pinned_connection: Option<&PinnedConnectionHandle>,
         drop_address: Option<ServerAddress>,
+        session: Option<&mut ClientSession>,
     ) -> Result<()> {
         let ns = self.namespace();
 
@@ -867,7 +1135,7 @@ impl<T> Collection<T> {
                     "cursors": [cursor_id]
                 },
                 drop_address.map(SelectionCriteria::from_address),
-                None,
+                session,
                 pinned_connection,
             )
             .await?;
@@ -961,6 +1229,179 @@ impl<T> Collection<T> {
     }
 }
 
+impl<T> Collection<T> {
+    /// Returns the query plan the server would use to find the documents in the collection
+    /// matching `filter`, with the amount of detail specified by `verbosity`.
+    ///
+    /// See the MongoDB [manual](https://www.mongodb.com/docs/manual/reference/command/explain/)
+    /// for more information on explaining operations.
+    pub async fn explain_find(
+        &self,
+        filter: impl Into<Option<Document>>,
+        options: impl Into<Option<FindOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<ExplainResult> {
+        let mut options = options.into();
+        resolve_options!(self, options, [selection_criteria]);
+
+        let find = Find::new(self.namespace(), filter.into(), options);
+        self.explain_common(find, verbosity).await
+    }
+
+    /// Returns the query plan the server would use to run the aggregation `pipeline` on the
+    /// collection, with the amount of detail specified by `verbosity`.
+    ///
+    /// See the MongoDB [manual](https://www.mongodb.com/docs/manual/reference/command/explain/)
+    /// for more information on explaining operations.
+    pub async fn explain_aggregate(
+        &self,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<AggregateOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<ExplainResult> {
+        let mut options = options.into();
+        resolve_options!(self, options, [selection_criteria]);
+
+        let aggregate = Aggregate::new(self.namespace(), pipeline, options);
+        self.explain_common(aggregate, verbosity).await
+    }
+
+    /// Returns the query plan the server would use to count the documents in the collection
+    /// matching `filter`, with the amount of detail specified by `verbosity`.
+    ///
+    /// See the MongoDB [manual](https://www.mongodb.com/docs/manual/reference/command/explain/)
+    /// for more information on explaining operations.
+    pub async fn explain_count_documents(
+        &self,
+        filter: impl Into<Option<Document>>,
+        options: impl Into<Option<CountOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<ExplainResult> {
+        let mut options = options.into();
+        resolve_options!(self, options, [selection_criteria]);
+
+        let op = CountDocuments::new(self.namespace(), filter.into(), options)?;
+        self.explain_common(op, verbosity).await
+    }
+
+    /// Returns the query plan the server would use to find the distinct values of the field
+    /// specified by `field_name` across the collection, with the amount of detail specified by
+    /// `verbosity`.
+    ///
+    /// See the MongoDB [manual](https://www.mongodb.com/docs/manual/reference/command/explain/)
+    /// for more information on explaining operations.
+    pub async fn explain_distinct(
+        &self,
+        field_name: impl AsRef<str>,
+        filter: impl Into<Option<Document>>,
+        options: impl Into<Option<DistinctOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<ExplainResult> {
+        let mut options = options.into();
+        resolve_options!(self, options, [selection_criteria]);
+
+        let op = Distinct::new(
+            self.namespace(),
+            field_name.as_ref().to_string(),
+            filter.into(),
+            options,
+        );
+        self.explain_common(op, verbosity).await
+    }
+
+    /// Returns the query plan the server would use to update up to one document matching `query`
+    /// in the collection, with the amount of detail specified by `verbosity`. The update is
+    /// never performed, regardless of `verbosity`.
+    ///
+    /// See the MongoDB [manual](https://www.mongodb.com/docs/manual/reference/command/explain/)
+    /// for more information on explaining operations.
+    pub async fn explain_update_one(
+        &self,
+        query: Document,
+        update: impl Into<UpdateModifications>,
+        options: impl Into<Option<UpdateOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<ExplainResult> {
+        self.explain_update_common(query, update, false, options, verbosity)
+            .await
+    }
+
+    /// Returns the query plan the server would use to update all documents matching `query` in
+    /// the collection, with the amount of detail specified by `verbosity`. The update is
+    /// never performed, regardless of `verbosity`.
+    ///
+    /// See the MongoDB [manual](https://www.mongodb.com/docs/manual/reference/command/explain/)
+    /// for more information on explaining operations.
+    pub async fn explain_update_many(
+        &self,
+        query: Document,
+        update: impl Into<UpdateModifications>,
+        options: impl Into<Option<UpdateOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<ExplainResult> {
+        self.explain_update_common(query, update, true, options, verbosity)
+            .await
+    }
+
+    async fn explain_update_common(
+        &self,
+        query: Document,
+        update: impl Into<UpdateModifications>,
+        multi: bool,
+        options: impl Into<Option<UpdateOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<ExplainResult> {
+        let update = update.into();
+        if let UpdateModifications::Document(ref d) = update {
+            bson_util::update_document_check(d)?;
+        }
+
+        let update = Update::new(self.namespace(), query, update, multi, options.into());
+        self.explain_common(update, verbosity).await
+    }
+
+    /// Returns the query plan the server would use to delete up to one document matching `query`
+    /// in the collection, with the amount of detail specified by `verbosity`. The delete is
+    /// never performed, regardless of `verbosity`.
+    ///
+    /// See the MongoDB [manual](https://www.mongodb.com/docs/manual/reference/command/explain/)
+    /// for more information on explaining operations.
+    pub async fn explain_delete_one(
+        &self,
+        query: Document,
+        options: impl Into<Option<DeleteOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<ExplainResult> {
+        let delete = Delete::new(self.namespace(), query, Some(1), options.into());
+        self.explain_common(delete, verbosity).await
+    }
+
+    /// Returns the query plan the server would use to delete all documents matching `query` in
+    /// the collection, with the amount of detail specified by `verbosity`. The delete is
+    /// never performed, regardless of `verbosity`.
+    ///
+    /// See the MongoDB [manual](https://www.mongodb.com/docs/manual/reference/command/explain/)
+    /// for more information on explaining operations.
+    pub async fn explain_delete_many(
+        &self,
+        query: Document,
+        options: impl Into<Option<DeleteOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<ExplainResult> {
+        let delete = Delete::new(self.namespace(), query, None, options.into());
+        self.explain_common(delete, verbosity).await
+    }
+
+    async fn explain_common<Op>(&self, op: Op, verbosity: ExplainVerbosity) -> Result<ExplainResult>
+    where
+        Op: Operation<Command = Document>,
+    {
+        self.client()
+            .execute_operation(Explain::new(op, verbosity), None)
+            .await
+    }
+}
+
 impl<T> Collection<T>
 where
     T: DeserializeOwned + Unpin + Send + Sync,
@@ -1431,6 +1872,164 @@ where
         self.replace_one_common(query, replacement, options, session)
             .await
     }
+
+    #[allow(clippy::needless_option_as_deref)]
+    async fn bulk_write_common(
+        &self,
+        models: impl IntoIterator<Item = WriteModel<T>>,
+        options: impl Into<Option<BulkWriteOptions>>,
+        mut session: Option<&mut ClientSession>,
+    ) -> Result<BulkWriteResult> {
+        let models: Vec<_> = models.into_iter().collect();
+        let mut options = options.into();
+        resolve_write_concern_with_session!(self, options, session.as_ref())?;
+
+        if models.is_empty() {
+            return Err(ErrorKind::InvalidArgument {
+                message: "No write models provided to bulk_write".to_string(),
+            }
+            .into());
+        }
+        for model in &models {
+            if let WriteModel::UpdateOne(UpdateModel {
+                update: UpdateModifications::Document(ref d),
+                ..
+            })
+            | WriteModel::UpdateMany(UpdateModel {
+                update: UpdateModifications::Document(ref d),
+                ..
+            }) = model
+            {
+                bson_util::update_document_check(d)?;
+            }
+        }
+
+        let ordered = options.as_ref().and_then(|o| o.ordered).unwrap_or(true);
+        #[cfg(feature = "csfle")]
+        let encrypted = self.client().auto_encryption_opts().await.is_some();
+        #[cfg(not(feature = "csfle"))]
+        let encrypted = false;
+
+        let mut cumulative_failure: Option<BulkWriteFailure> = None;
+        let mut error_labels: HashSet<String> = Default::default();
+        let mut cumulative_result = BulkWriteResult::new();
+
+        let mut n_attempted = 0;
+        while n_attempted < models.len() {
+            // consecutive models that use the same command are sent together, split into as many
+            // batches as the server's limits require.
+            let command_name = models[n_attempted].command_name();
+            let run_end = models[n_attempted..]
+                .iter()
+                .position(|model| model.command_name() != command_name)
+                .map_or(models.len(), |len| n_attempted + len);
+
+            let mut current_batch_size = 0;
+            let bulk_write = BulkWrite::new(
+                self.namespace(),
+                &models[n_attempted..run_end],
+                n_attempted,
+                options.clone(),
+                encrypted,
+                &mut current_batch_size,
+            );
+            let result = self
+                .client()
+                .execute_operation(bulk_write, session.as_deref_mut())
+                .await;
+
+            match result {
+                Ok(result) => cumulative_result.merge(result),
+                Err(e) => {
+                    let labels = e.labels().clone();
+                    match *e.kind {
+                        ErrorKind::BulkWrite(bw) => {
+                            // The writes in the failed command that succeeded are still part of
+                            // the result.
+                            if let Some(partial_result) = bw.partial_result {
+                                cumulative_result.merge(partial_result);
+                            }
+                            let failure_ref =
+                                cumulative_failure.get_or_insert_with(BulkWriteFailure::new);
+                            if let Some(write_errors) = bw.write_errors {
+                                failure_ref
+                                    .write_errors
+                                    .get_or_insert_with(Default::default)
+                                    .extend(write_errors);
+                            }
+
+                            if let Some(wc_error) = bw.write_concern_error {
+                                failure_ref.write_concern_error = Some(wc_error);
+                            }
+
+                            error_labels.extend(labels);
+
+                            if ordered {
+                                // this will always be true since we invoked get_or_insert_with
+                                // above.
+                                if let Some(mut failure) = cumulative_failure {
+                                    failure.partial_result = Some(cumulative_result);
+                                    return Err(Error::new(
+                                        ErrorKind::BulkWrite(failure),
+                                        Some(error_labels),
+                                    ));
+                                }
+                            }
+                        }
+                        _ => return Err(e),
+                    }
+                }
+            }
+
+            n_attempted += current_batch_size;
+        }
+
+        match cumulative_failure {
+            Some(mut failure) => {
+                failure.partial_result = Some(cumulative_result);
+                Err(Error::new(
+                    ErrorKind::BulkWrite(failure),
+                    Some(error_labels),
+                ))
+            }
+            None => Ok(cumulative_result),
+        }
+    }
+
+    /// Performs the writes specified by `models` on the collection.
+    ///
+    /// Consecutive models of the same kind (inserts, updates and replacements, or deletes) are
+    /// sent to the server together in as few commands as the server's size limits allow. If the
+    /// writes are ordered (the default), the remaining writes are not performed after a write
+    /// fails. The indexes in the returned [`BulkWriteResult`] and in any
+    /// [`BulkWriteFailure`](crate::error::BulkWriteFailure) correspond to the positions of the
+    /// models in `models`. If any write fails, the writes that were performed are reported in the
+    /// failure's `partial_result`.
+    ///
+    /// The writes will be retried once upon failure if the connection and encountered error
+    /// support retryability. Each [`WriteModel::UpdateMany`] or [`WriteModel::DeleteMany`] model
+    /// starts a new command, which is not retried along with the models after it in the same
+    /// command. See the documentation
+    /// [here](https://www.mongodb.com/docs/manual/core/retryable-writes/) for more information on
+    /// retryable writes.
+    pub async fn bulk_write(
+        &self,
+        models: impl IntoIterator<Item = WriteModel<T>>,
+        options: impl Into<Option<BulkWriteOptions>>,
+    ) -> Result<BulkWriteResult> {
+        self.bulk_write_common(models, options, None).await
+    }
+
+    /// Performs the writes specified by `models` on the collection using the provided
+    /// `ClientSession`. See [`Collection::bulk_write`] for more details.
+    pub async fn bulk_write_with_session(
+        &self,
+        models: impl IntoIterator<Item = WriteModel<T>>,
+        options: impl Into<Option<BulkWriteOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<BulkWriteResult> {
+        self.bulk_write_common(models, options, Some(session)).await
+    }
 }
 
 /// A struct modeling the canonical name for a collection in MongoDB.
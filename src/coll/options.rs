@@ -9,7 +9,7 @@ use crate::{
     bson::{doc, Bson, Document},
     bson_util,
     concern::{ReadConcern, WriteConcern},
-    options::Collation,
+    options::{ChangeStreamPreAndPostImages, Collation, ValidationAction, ValidationLevel},
     selection_criteria::SelectionCriteria,
 };
 
@@ -93,6 +93,25 @@ pub enum CursorType {
     TailableAwait,
 }
 
+/// The amount of information returned when explaining an operation with one of the `explain_*`
+/// methods on [`Collection`](../struct.Collection.html).
+///
+/// See the MongoDB [manual](https://www.mongodb.com/docs/manual/reference/command/explain/#verbosity-modes)
+/// for more information.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum ExplainVerbosity {
+    /// Only run the query optimizer and report the winning plan.
+    QueryPlanner,
+
+    /// Also execute the winning plan and report its execution statistics.
+    ExecutionStats,
+
+    /// Also report partial execution statistics for the rejected plans.
+    AllPlansExecution,
+}
+
 /// Specifies the options to a
 /// [`Collection::insert_one`](../struct.Collection.html#method.insert_one) operation.
 #[skip_serializing_none]
@@ -338,6 +357,210 @@ pub struct DeleteOptions {
     pub comment: Option<Bson>,
 }
 
+/// Specifies the options to a
+/// [`Collection::bulk_write`](../struct.Collection.html#method.bulk_write) operation.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct BulkWriteOptions {
+    /// Opt out of document-level validation.
+    pub bypass_document_validation: Option<bool>,
+
+    /// If true, when a write fails, return without performing the remaining writes. If false,
+    /// when a write fails, continue with the remaining writes, if any.
+    ///
+    /// Defaults to true.
+    pub ordered: Option<bool>,
+
+    /// The write concern for the operation.
+    pub write_concern: Option<WriteConcern>,
+
+    /// Map of parameter names and values. Values must be constant or closed
+    /// expressions that do not reference document fields. Parameters can then be
+    /// accessed as variables in an aggregate expression context (e.g. "$$var").
+    ///
+    /// These are not applied to inserts. Only available in MongoDB 5.0+.
+    #[serde(rename = "let")]
+    pub let_vars: Option<Document>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    ///
+    /// This option is only available on server versions 4.4+.
+    pub comment: Option<Bson>,
+}
+
+/// Specifies the options to a
+/// [`Client::bulk_write`](../struct.Client.html#method.bulk_write) operation.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct ClientBulkWriteOptions {
+    /// Opt out of document-level validation.
+    pub bypass_document_validation: Option<bool>,
+
+    /// If true, when a write fails, return without performing the remaining writes. If false,
+    /// when a write fails, continue with the remaining writes, if any.
+    ///
+    /// Defaults to true.
+    pub ordered: Option<bool>,
+
+    /// Whether the result should include the outcome of each individual write in addition to the
+    /// summary counts. Requesting these results requires the server to return a document for every
+    /// write rather than only for those that failed.
+    ///
+    /// Defaults to false.
+    pub verbose_results: Option<bool>,
+
+    /// The write concern for the operation.
+    pub write_concern: Option<WriteConcern>,
+
+    /// Map of parameter names and values. Values must be constant or closed
+    /// expressions that do not reference document fields. Parameters can then be
+    /// accessed as variables in an aggregate expression context (e.g. "$$var").
+    ///
+    /// These are not applied to inserts.
+    #[serde(rename = "let")]
+    pub let_vars: Option<Document>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+/// A single write to perform as part of a
+/// [`Collection::bulk_write`](../struct.Collection.html#method.bulk_write) operation.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum WriteModel<T> {
+    /// Inserts a single document.
+    InsertOne(InsertOneModel<T>),
+
+    /// Updates up to one document matching a filter.
+    UpdateOne(UpdateModel),
+
+    /// Updates all documents matching a filter.
+    UpdateMany(UpdateModel),
+
+    /// Replaces up to one document matching a filter.
+    ReplaceOne(ReplaceOneModel<T>),
+
+    /// Deletes up to one document matching a filter.
+    DeleteOne(DeleteModel),
+
+    /// Deletes all documents matching a filter.
+    DeleteMany(DeleteModel),
+}
+
+impl<T> WriteModel<T> {
+    /// The name of the write command used to perform this write.
+    pub(crate) fn command_name(&self) -> &'static str {
+        match self {
+            Self::InsertOne(_) => "insert",
+            Self::UpdateOne(_) | Self::UpdateMany(_) | Self::ReplaceOne(_) => "update",
+            Self::DeleteOne(_) | Self::DeleteMany(_) => "delete",
+        }
+    }
+
+    /// Whether this write can affect more than one document.
+    pub(crate) fn is_multi(&self) -> bool {
+        matches!(self, Self::UpdateMany(_) | Self::DeleteMany(_))
+    }
+}
+
+/// Specifies a document to insert as part of a
+/// [`Collection::bulk_write`](../struct.Collection.html#method.bulk_write) operation.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct InsertOneModel<T> {
+    /// The document to insert.
+    #[builder(!default)]
+    pub document: T,
+}
+
+/// Specifies an update to perform as part of a
+/// [`Collection::bulk_write`](../struct.Collection.html#method.bulk_write) operation. Used by
+/// both [`WriteModel::UpdateOne`] and [`WriteModel::UpdateMany`].
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct UpdateModel {
+    /// The filter selecting the document(s) to update.
+    #[builder(!default)]
+    pub filter: Document,
+
+    /// The modifications to apply.
+    ///
+    /// Note: pipeline updates are only supported in MongoDB 4.2+.
+    #[builder(!default)]
+    pub update: UpdateModifications,
+
+    /// A set of filters specifying to which array elements an update should apply.
+    pub array_filters: Option<Vec<Document>>,
+
+    /// The collation to use for the update.
+    pub collation: Option<Collation>,
+
+    /// A document or string that specifies the index to use to support the query predicate.
+    ///
+    /// Only available in MongoDB 4.2+.
+    pub hint: Option<Hint>,
+
+    /// If true, insert a document if no matching document is found.
+    pub upsert: Option<bool>,
+}
+
+/// Specifies a replacement to perform as part of a
+/// [`Collection::bulk_write`](../struct.Collection.html#method.bulk_write) operation.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct ReplaceOneModel<T> {
+    /// The filter selecting the document to replace.
+    #[builder(!default)]
+    pub filter: Document,
+
+    /// The replacement document.
+    #[builder(!default)]
+    pub replacement: T,
+
+    /// The collation to use for the replacement.
+    pub collation: Option<Collation>,
+
+    /// A document or string that specifies the index to use to support the query predicate.
+    ///
+    /// Only available in MongoDB 4.2+.
+    pub hint: Option<Hint>,
+
+    /// If true, insert a document if no matching document is found.
+    pub upsert: Option<bool>,
+}
+
+/// Specifies a delete to perform as part of a
+/// [`Collection::bulk_write`](../struct.Collection.html#method.bulk_write) operation. Used by
+/// both [`WriteModel::DeleteOne`] and [`WriteModel::DeleteMany`].
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct DeleteModel {
+    /// The filter selecting the document(s) to delete.
+    #[builder(!default)]
+    pub filter: Document,
+
+    /// The collation to use for the delete.
+    pub collation: Option<Collation>,
+
+    /// The index to use for the delete.
+    ///
+    /// Only available in MongoDB 4.4+.
+    pub hint: Option<Hint>,
+}
+
 /// Specifies the options to a
 /// [`Collection::find_one_and_delete`](../struct.Collection.html#method.find_one_and_delete)
 /// operation.
@@ -1017,6 +1240,56 @@ pub struct FindOneOptions {
     pub let_vars: Option<Document>,
 }
 
+/// Specifies the options to a [`Collection::tail`](../struct.Collection.html#method.tail)
+/// operation.
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct TailOptions {
+    /// Whether the server should block on receiving more results if none are available. Defaults
+    /// to true, i.e. the cursor is created with [`CursorType::TailableAwait`].
+    pub await_data: Option<bool>,
+
+    /// The maximum amount of time for the server to wait on new documents before returning an
+    /// empty batch, which is only sent to the server if `await_data` is true. This is also how
+    /// long to wait before re-issuing the find query after the server closes the cursor, which
+    /// defaults to one second.
+    pub max_await_time: Option<Duration>,
+
+    /// The number of documents the server should return per cursor batch.
+    pub batch_size: Option<u32>,
+
+    /// Limits the fields of the document being returned. The projection must include the
+    /// `resume_field`.
+    pub projection: Option<Document>,
+
+    /// The top-level field used to track the position of the stream, which must increase with the
+    /// insertion order of the collection, e.g. an `ObjectId` or a timestamp. Defaults to `_id`.
+    pub resume_field: Option<String>,
+
+    /// Only return documents whose `resume_field` is greater than this value, e.g. a value
+    /// previously returned by
+    /// [`TailableCursor::resume_value`](../struct.TailableCursor.html#method.resume_value).
+    pub resume_after: Option<Bson>,
+
+    /// The read concern to use for the find queries.
+    ///
+    /// If none specified, the default set on the collection will be used.
+    pub read_concern: Option<ReadConcern>,
+
+    /// The criteria used to select a server for the find queries.
+    ///
+    /// If none specified, the default set on the collection will be used.
+    pub selection_criteria: Option<SelectionCriteria>,
+
+    /// Tags the queries with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    ///
+    /// This option is only supported on server versions 4.4+.
+    pub comment: Option<Bson>,
+}
+
 /// Specifies the options to a
 /// [`Collection::create_index`](../struct.Collection.html#method.create_index) or [`Collection::
 /// create_indexes`](../struct.Collection.html#method.create_indexes) operation.
@@ -1070,6 +1343,139 @@ pub struct DropCollectionOptions {
     pub encrypted_fields: Option<Document>,
 }
 
+/// Specifies the options to a [`Collection::rename`](../struct.Collection.html#method.rename)
+/// operation.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct RenameCollectionOptions {
+    /// The write concern for the operation.
+    pub write_concern: Option<WriteConcern>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    ///
+    /// This option is only available on server versions 4.4+.
+    pub comment: Option<Bson>,
+}
+
+/// Specifies the options to a [`Collection::modify`](../struct.Collection.html#method.modify)
+/// operation, which runs the
+/// [`collMod`](https://www.mongodb.com/docs/manual/reference/command/collMod/) command. Only the
+/// properties of the collection that are set are changed.
+///
+/// `collMod` cannot turn an uncapped collection into a capped one; use
+/// [`Collection::convert_to_capped`](../struct.Collection.html#method.convert_to_capped) for that.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct ModifyCollectionOptions {
+    /// Replaces the validator used to restrict the schema of documents in the collection.
+    pub validator: Option<Document>,
+
+    /// Specifies how strictly the database should apply the validation rules to existing documents
+    /// during an update.
+    pub validation_level: Option<ValidationLevel>,
+
+    /// Specifies whether the database should return an error or simply raise a warning if inserted
+    /// documents do not pass the validation.
+    pub validation_action: Option<ValidationAction>,
+
+    /// Changes the name of the source collection or view of a view. Must be set together with
+    /// `pipeline`.
+    pub view_on: Option<String>,
+
+    /// Changes the aggregation pipeline that determines the contents of a view. Must be set
+    /// together with `view_on`.
+    pub pipeline: Option<Vec<Document>>,
+
+    /// Changes the TTL of an index on the collection or hides it from the query planner.
+    pub index: Option<ModifyIndex>,
+
+    /// Changes the time after which documents in a time series collection are deleted.
+    #[serde(
+        default,
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_seconds",
+        serialize_with = "bson_util::serialize_duration_option_as_int_secs"
+    )]
+    pub expire_after_seconds: Option<Duration>,
+
+    /// Enables or disables support for change stream pre- and post-images.
+    pub change_stream_pre_and_post_images: Option<ChangeStreamPreAndPostImages>,
+
+    /// Changes the maximum size (in bytes) of a capped collection.
+    ///
+    /// This option is only available on server versions 6.0+.
+    #[serde(serialize_with = "bson_util::serialize_u64_option_as_i64")]
+    pub capped_size: Option<u64>,
+
+    /// Changes the maximum number of documents in a capped collection.
+    ///
+    /// This option is only available on server versions 6.0+.
+    #[serde(serialize_with = "bson_util::serialize_u64_option_as_i64")]
+    pub capped_max: Option<u64>,
+
+    /// The write concern for the operation.
+    pub write_concern: Option<WriteConcern>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    ///
+    /// This option is only available on server versions 4.4+.
+    pub comment: Option<Bson>,
+}
+
+/// Specifies the options to a
+/// [`Collection::convert_to_capped`](../struct.Collection.html#method.convert_to_capped)
+/// operation.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct ConvertToCappedOptions {
+    /// The write concern for the operation.
+    pub write_concern: Option<WriteConcern>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    ///
+    /// This option is only available on server versions 4.4+.
+    pub comment: Option<Bson>,
+}
+
+/// Specifies an index to change with [`ModifyCollectionOptions::index`]. The index is identified
+/// by either its `name` or its `key_pattern`.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct ModifyIndex {
+    /// The name of the index.
+    pub name: Option<String>,
+
+    /// The key pattern of the index.
+    pub key_pattern: Option<Document>,
+
+    /// Changes the time after which documents are deleted from the collection by a TTL index.
+    #[serde(
+        default,
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_seconds",
+        serialize_with = "bson_util::serialize_duration_option_as_int_secs"
+    )]
+    pub expire_after_seconds: Option<Duration>,
+
+    /// Hides the index from the query planner, or unhides it.
+    ///
+    /// This option is only available on server versions 4.4+.
+    pub hidden: Option<bool>,
+}
+
 /// Specifies the options to a
 /// [`Collection::drop_index`](../struct.Collection.html#method.drop_index) or
 /// [`Collection::drop_indexes`](../struct.Collection.html#method.drop_indexes) operation.
@@ -1132,6 +1538,95 @@ pub struct ListIndexesOptions {
     pub comment: Option<Bson>,
 }
 
+/// Specifies the options to a
+/// [`Collection::sync_indexes`](../struct.Collection.html#method.sync_indexes) operation.
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct SyncIndexOptions {
+    /// Whether to drop the existing indexes that are not in the requested set of indexes. The
+    /// `_id_` index is never dropped. Defaults to false.
+    pub drop_unlisted: Option<bool>,
+
+    /// Whether to return an error without making any changes if a requested index conflicts with
+    /// an existing one. If false, conflicting indexes are left unchanged and reported in
+    /// [`SyncIndexesResult::conflicts`](crate::results::SyncIndexesResult::conflicts). Defaults
+    /// to false.
+    pub fail_on_conflict: Option<bool>,
+
+    /// Whether to only plan the changes without applying them. Defaults to false.
+    pub dry_run: Option<bool>,
+
+    /// The write concern for the index creations and drops.
+    pub write_concern: Option<WriteConcern>,
+}
+
+/// Specifies the options to a
+/// [`Collection::create_search_index`](../struct.Collection.html#method.create_search_index) or
+/// [`Collection::create_search_indexes`](../struct.Collection.html#method.create_search_indexes)
+/// operation.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct CreateSearchIndexOptions {
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+/// Specifies the options to a
+/// [`Collection::update_search_index`](../struct.Collection.html#method.update_search_index)
+/// operation.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct UpdateSearchIndexOptions {
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+/// Specifies the options to a
+/// [`Collection::drop_search_index`](../struct.Collection.html#method.drop_search_index)
+/// operation.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct DropSearchIndexOptions {
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
+/// Specifies the options to a
+/// [`Collection::list_search_indexes`](../struct.Collection.html#method.list_search_indexes)
+/// operation.
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct ListSearchIndexesOptions {
+    /// The number of indexes the server should return per cursor batch.
+    pub batch_size: Option<u32>,
+
+    /// The maximum amount of time to allow the query to run.
+    ///
+    /// This option maps to the `maxTimeMS` MongoDB query option, so the duration will be sent
+    /// across the wire as an integer number of milliseconds.
+    pub max_time: Option<Duration>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    pub comment: Option<Bson>,
+}
+
 /// The minimum number of data-bearing voting replica set members (i.e. commit quorum), including
 /// the primary, that must report a successful index build before the primary marks the indexes as
 /// ready.
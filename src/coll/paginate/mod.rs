@@ -0,0 +1,302 @@
+#[cfg(test)]
+mod test;
+
+use std::{fmt, str::FromStr};
+
+use futures_util::stream::TryStreamExt;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    bson::{doc, Bson, Document, RawDocumentBuf},
+    error::{Error, ErrorKind, Result},
+    options::FindOptions,
+    Collection,
+};
+
+impl<T> Collection<T> {
+    /// Returns a [`Paginator`] that reads the documents in the collection matching `filter` in
+    /// pages of up to `page_size` documents, ordered by `sort`.
+    ///
+    /// Rather than skipping the documents of earlier pages, each page is queried with a range
+    /// filter starting after the sort key of the last document returned, so every page is as
+    /// cheap to fetch as the first when there is an index on the sort fields followed by `_id`.
+    /// `_id` is appended to `sort` as a tie-breaker if it is not already present.
+    ///
+    /// Every field in `sort` must be sorted in ascending (`1`) or descending (`-1`) order, and
+    /// should be present with a consistent type in each matching document.
+    pub fn paginate(
+        &self,
+        filter: impl Into<Option<Document>>,
+        sort: Document,
+        page_size: u32,
+    ) -> Result<Paginator<T>> {
+        if page_size == 0 {
+            return Err(invalid_argument("page size must be positive"));
+        }
+
+        let mut keys = Vec::with_capacity(sort.len() + 1);
+        for (field, direction) in &sort {
+            let ascending = match direction {
+                Bson::Int32(1) | Bson::Int64(1) => true,
+                Bson::Int32(-1) | Bson::Int64(-1) => false,
+                Bson::Double(d) if *d == 1.0 => true,
+                Bson::Double(d) if *d == -1.0 => false,
+                other => {
+                    return Err(invalid_argument(format!(
+                        "sort direction for {:?} must be 1 or -1, got {}",
+                        field, other
+                    )))
+                }
+            };
+            keys.push((field.clone(), ascending));
+        }
+        if !keys.iter().any(|(field, _)| field == "_id") {
+            keys.push(("_id".to_string(), true));
+        }
+
+        Ok(Paginator {
+            coll: self.clone(),
+            filter: filter.into().unwrap_or_default(),
+            keys,
+            page_size,
+            last_values: None,
+            exhausted: false,
+        })
+    }
+}
+
+/// Reads the results of a query in pages using keyset pagination. A `Paginator` is created with
+/// [`Collection::paginate`].
+///
+/// Each [`Page`] other than the last includes a [`ContinuationToken`] that can be passed to
+/// [`Paginator::resume`] to continue from the end of that page, e.g. in a later request to a web
+/// service.
+///
+/// ```rust
+/// # use mongodb::{bson::{doc, Document}, error::Result, Collection};
+/// # async fn run(coll: Collection<Document>) -> Result<()> {
+/// let mut paginator = coll.paginate(doc! { "status": "A" }, doc! { "created": -1 }, 20)?;
+/// while let Some(page) = paginator.next_page().await? {
+///     for document in page.items {
+///         println!("{}", document);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Paginator<T> {
+    coll: Collection<T>,
+    filter: Document,
+    /// The sort fields and whether each is sorted in ascending order.
+    keys: Vec<(String, bool)>,
+    page_size: u32,
+    /// The sort key values of the last document returned.
+    last_values: Option<Vec<Bson>>,
+    exhausted: bool,
+}
+
+impl<T> Paginator<T> {
+    /// Continues from the end of the page that `token` was returned with. The token must have
+    /// been created by a paginator with the same sort fields and directions.
+    pub fn resume(mut self, token: &ContinuationToken) -> Result<Self> {
+        if token.keys != self.keys {
+            return Err(invalid_argument(format!(
+                "continuation token was created for sort {}, but the paginator sorts by {}",
+                sort_document(&token.keys),
+                sort_document(&self.keys)
+            )));
+        }
+        self.last_values = Some(token.values.clone());
+        self.exhausted = false;
+        Ok(self)
+    }
+
+    /// The filter used to query the next page: the paginator's filter combined with the range
+    /// after the last sort key returned.
+    fn page_filter(&self) -> Document {
+        let last_values = match self.last_values {
+            Some(ref last_values) => last_values,
+            None => return self.filter.clone(),
+        };
+
+        // A document comes after the last one if it has equal values for a prefix of the sort
+        // fields and a greater (or lesser, if descending) value for the next field.
+        let mut ranges = Vec::with_capacity(self.keys.len());
+        for (i, ((field, ascending), value)) in self.keys.iter().zip(last_values).enumerate() {
+            let mut range = Document::new();
+            for ((prefix_field, _), prefix_value) in self.keys.iter().zip(last_values).take(i) {
+                range.insert(prefix_field, doc! { "$eq": prefix_value.clone() });
+            }
+            let operator = if *ascending { "$gt" } else { "$lt" };
+            range.insert(field, doc! { operator: value.clone() });
+            ranges.push(range);
+        }
+        let range = doc! { "$or": ranges };
+
+        if self.filter.is_empty() {
+            range
+        } else {
+            doc! { "$and": [self.filter.clone(), range] }
+        }
+    }
+}
+
+impl<T> Paginator<T>
+where
+    T: DeserializeOwned,
+{
+    /// Fetches the next page of results, or returns `None` if the previous page was the last.
+    pub async fn next_page(&mut self) -> Result<Option<Page<T>>> {
+        if self.exhausted {
+            return Ok(None);
+        }
+
+        let sort = sort_document(&self.keys);
+        // Fetch one more document than needed to find out whether there is another page.
+        let options = FindOptions::builder()
+            .sort(sort)
+            .limit(i64::from(self.page_size) + 1)
+            .build();
+        let mut documents: Vec<RawDocumentBuf> = self
+            .coll
+            .clone_with_type::<RawDocumentBuf>()
+            .find(self.page_filter(), options)
+            .await?
+            .try_collect()
+            .await?;
+
+        let has_more = documents.len() > self.page_size as usize;
+        documents.truncate(self.page_size as usize);
+
+        let continuation = match documents.last() {
+            Some(last) if has_more => {
+                let last = last.to_document()?;
+                let values: Vec<Bson> = self
+                    .keys
+                    .iter()
+                    .map(|(field, _)| get_path(&last, field))
+                    .collect();
+                self.last_values = Some(values.clone());
+                Some(ContinuationToken {
+                    keys: self.keys.clone(),
+                    values,
+                })
+            }
+            _ => {
+                self.exhausted = true;
+                None
+            }
+        };
+
+        let items = documents
+            .iter()
+            .map(|document| bson::from_slice(document.as_bytes()).map_err(Error::from))
+            .collect::<Result<Vec<T>>>()?;
+        Ok(Some(Page {
+            items,
+            continuation,
+        }))
+    }
+}
+
+/// A page of results returned by [`Paginator::next_page`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct Page<T> {
+    /// The documents in the page.
+    pub items: Vec<T>,
+
+    /// A token to resume pagination after this page with [`Paginator::resume`]. This is `None`
+    /// if this is the last page.
+    pub continuation: Option<ContinuationToken>,
+}
+
+/// An opaque token recording the position after a [`Page`], which can be converted to and from a
+/// URL-safe string.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContinuationToken {
+    /// The sort fields and whether each is sorted in ascending order.
+    keys: Vec<(String, bool)>,
+    values: Vec<Bson>,
+}
+
+impl fmt::Display for ContinuationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fields: Vec<&str> = self.keys.iter().map(|(field, _)| field.as_str()).collect();
+        let directions: Vec<i32> = self
+            .keys
+            .iter()
+            .map(|(_, ascending)| if *ascending { 1 } else { -1 })
+            .collect();
+        let document = doc! { "f": fields, "d": directions, "v": self.values.clone() };
+        let mut bytes = Vec::new();
+        document.to_writer(&mut bytes).map_err(|_| fmt::Error)?;
+        f.write_str(&base64::encode_config(bytes, base64::URL_SAFE_NO_PAD))
+    }
+}
+
+impl FromStr for ContinuationToken {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || invalid_argument("invalid continuation token");
+        let bytes = base64::decode_config(s, base64::URL_SAFE_NO_PAD).map_err(|_| invalid())?;
+        let document = Document::from_reader(bytes.as_slice()).map_err(|_| invalid())?;
+
+        let fields = document
+            .get_array("f")
+            .map_err(|_| invalid())?
+            .iter()
+            .map(|field| field.as_str().map(String::from))
+            .collect::<Option<Vec<String>>>()
+            .ok_or_else(invalid)?;
+        let directions = document
+            .get_array("d")
+            .map_err(|_| invalid())?
+            .iter()
+            .map(|direction| match direction {
+                Bson::Int32(1) => Some(true),
+                Bson::Int32(-1) => Some(false),
+                _ => None,
+            })
+            .collect::<Option<Vec<bool>>>()
+            .ok_or_else(invalid)?;
+        let values = document.get_array("v").map_err(|_| invalid())?.clone();
+        if fields.len() != directions.len() || fields.len() != values.len() {
+            return Err(invalid());
+        }
+        Ok(Self {
+            keys: fields.into_iter().zip(directions).collect(),
+            values,
+        })
+    }
+}
+
+/// Returns the sort document for `keys`.
+fn sort_document(keys: &[(String, bool)]) -> Document {
+    keys.iter()
+        .map(|(field, ascending)| (field.clone(), Bson::Int32(if *ascending { 1 } else { -1 })))
+        .collect()
+}
+
+/// Returns the value at the dotted `path` in `document`, or `Bson::Null` if there is none.
+fn get_path(document: &Document, path: &str) -> Bson {
+    let mut current = document;
+    let mut parts = path.split('.').peekable();
+    while let Some(part) = parts.next() {
+        match current.get(part) {
+            Some(Bson::Document(d)) if parts.peek().is_some() => current = d,
+            Some(value) if parts.peek().is_none() => return value.clone(),
+            _ => break,
+        }
+    }
+    Bson::Null
+}
+
+fn invalid_argument(message: impl Into<String>) -> Error {
+    ErrorKind::InvalidArgument {
+        message: message.into(),
+    }
+    .into()
+}
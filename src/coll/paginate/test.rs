@@ -0,0 +1,119 @@
+use std::str::FromStr;
+
+use crate::{
+    bson::{doc, Bson, Document},
+    coll::paginate::ContinuationToken,
+    error::ErrorKind,
+    Client,
+};
+
+async fn collection() -> crate::Collection<Document> {
+    Client::with_uri_str("mongodb://localhost:27017")
+        .await
+        .unwrap()
+        .database("db")
+        .collection("coll")
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn sort_validation() {
+    let coll = collection().await;
+
+    let paginator = coll.paginate(None, doc! { "a": 1, "b": -1.0 }, 10).unwrap();
+    assert_eq!(
+        paginator.keys,
+        vec![
+            ("a".to_string(), true),
+            ("b".to_string(), false),
+            ("_id".to_string(), true)
+        ]
+    );
+
+    let paginator = coll.paginate(None, doc! { "_id": -1 }, 10).unwrap();
+    assert_eq!(paginator.keys, vec![("_id".to_string(), false)]);
+
+    for sort in [
+        doc! { "a": 2 },
+        doc! { "a": "text" },
+        doc! { "a": { "$meta": "textScore" } },
+    ] {
+        let error = coll.paginate(None, sort, 10).unwrap_err();
+        assert!(matches!(*error.kind, ErrorKind::InvalidArgument { .. }));
+    }
+
+    let error = coll.paginate(None, doc! { "a": 1 }, 0).unwrap_err();
+    assert!(matches!(*error.kind, ErrorKind::InvalidArgument { .. }));
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn page_filter() {
+    let coll = collection().await;
+    let mut paginator = coll
+        .paginate(doc! { "status": "A" }, doc! { "a": 1, "b": -1 }, 10)
+        .unwrap();
+    assert_eq!(paginator.page_filter(), doc! { "status": "A" });
+
+    paginator.last_values = Some(vec![
+        Bson::Int32(5),
+        Bson::String("x".into()),
+        Bson::Int32(7),
+    ]);
+    assert_eq!(
+        paginator.page_filter(),
+        doc! {
+            "$and": [
+                { "status": "A" },
+                {
+                    "$or": [
+                        { "a": { "$gt": 5 } },
+                        { "a": { "$eq": 5 }, "b": { "$lt": "x" } },
+                        { "a": { "$eq": 5 }, "b": { "$eq": "x" }, "_id": { "$gt": 7 } },
+                    ]
+                },
+            ]
+        }
+    );
+
+    let mut paginator = coll.paginate(None, doc! { "_id": -1 }, 10).unwrap();
+    paginator.last_values = Some(vec![Bson::Int32(3)]);
+    assert_eq!(
+        paginator.page_filter(),
+        doc! { "$or": [{ "_id": { "$lt": 3 } }] }
+    );
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn continuation_token() {
+    let token = ContinuationToken {
+        keys: vec![("a.b".to_string(), true), ("_id".to_string(), true)],
+        values: vec![Bson::Null, Bson::String("x".into())],
+    };
+    let encoded = token.to_string();
+    assert!(encoded
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+    assert_eq!(ContinuationToken::from_str(&encoded).unwrap(), token);
+
+    for invalid in ["", "not a token", "AAAA"] {
+        let error = ContinuationToken::from_str(invalid).unwrap_err();
+        assert!(matches!(*error.kind, ErrorKind::InvalidArgument { .. }));
+    }
+
+    let coll = collection().await;
+    let paginator = coll.paginate(None, doc! { "a.b": 1 }, 10).unwrap();
+    let paginator = paginator.resume(&token).unwrap();
+    assert_eq!(paginator.last_values, Some(token.values.clone()));
+
+    for sort in [
+        doc! { "c": 1 },
+        doc! { "a.b": -1 },
+        doc! { "a.b": 1, "_id": -1 },
+    ] {
+        let paginator = coll.paginate(None, sort, 10).unwrap();
+        let error = paginator.resume(&token).unwrap_err();
+        assert!(matches!(*error.kind, ErrorKind::InvalidArgument { .. }));
+    }
+}
@@ -0,0 +1,301 @@
+#[cfg(test)]
+mod test;
+
+use futures_util::stream::TryStreamExt;
+
+use crate::{
+    bson::{Bson, Document},
+    error::{ErrorKind, Result},
+    index::IndexModel,
+    options::{Collation, CreateIndexOptions, DropIndexOptions, SyncIndexOptions},
+    results::{IndexConflict, SyncIndexesResult},
+    Collection,
+};
+
+impl<T> Collection<T> {
+    /// Brings the indexes on this collection in line with `models`: the requested indexes that do
+    /// not exist are created, and, if [`SyncIndexOptions::drop_unlisted`] is set, the existing
+    /// indexes that were not requested are dropped. The `_id_` index is never dropped.
+    ///
+    /// A requested index matches an existing index with the same keys, and each existing index
+    /// matches at most one requested index. The fields of a text index are compared with the
+    /// `weights` of the existing index, since the server lists the keys of a text index as
+    /// `{ _fts: "text", _ftsx: 1 }`. If their `unique`, `partialFilterExpression`,
+    /// `expireAfterSeconds`, `collation` or `weights` options, or the name given in the requested
+    /// index, differ, the indexes conflict. Because an index cannot be changed
+    /// without being dropped, conflicting indexes are left unchanged and reported in the result,
+    /// or cause an error if [`SyncIndexOptions::fail_on_conflict`] is set. Only the collation
+    /// fields specified in the requested index are compared.
+    ///
+    /// If [`SyncIndexOptions::dry_run`] is set, the planned changes are returned without being
+    /// applied.
+    pub async fn sync_indexes(
+        &self,
+        models: impl IntoIterator<Item = IndexModel>,
+        options: impl Into<Option<SyncIndexOptions>>,
+    ) -> Result<SyncIndexesResult> {
+        let options = options.into().unwrap_or_default();
+
+        let existing: Vec<IndexModel> = match self.list_indexes(None).await {
+            Ok(cursor) => cursor.try_collect().await?,
+            // The collection does not exist yet, so it has no indexes.
+            Err(e) if e.is_ns_not_found() => Vec::new(),
+            Err(e) => return Err(e),
+        };
+
+        let mut result = plan(
+            models.into_iter().collect(),
+            existing,
+            options.drop_unlisted.unwrap_or(false),
+        );
+
+        if options.fail_on_conflict.unwrap_or(false) && !result.conflicts.is_empty() {
+            let conflicts: Vec<String> = result
+                .conflicts
+                .iter()
+                .map(|conflict| {
+                    format!(
+                        "{} ({})",
+                        conflict.requested.get_name().unwrap_or_default(),
+                        conflict.differences.join(", ")
+                    )
+                })
+                .collect();
+            return Err(ErrorKind::InvalidArgument {
+                message: format!(
+                    "requested indexes conflict with existing indexes: {}",
+                    conflicts.join("; ")
+                ),
+            }
+            .into());
+        }
+
+        if options.dry_run.unwrap_or(false) {
+            result.dry_run = true;
+            return Ok(result);
+        }
+
+        if !result.created.is_empty() {
+            let create_options = CreateIndexOptions::builder()
+                .write_concern(options.write_concern.clone())
+                .build();
+            self.create_indexes(result.created.clone(), create_options)
+                .await?;
+        }
+        for name in &result.dropped {
+            let drop_options = DropIndexOptions::builder()
+                .write_concern(options.write_concern.clone())
+                .build();
+            self.drop_index(name, drop_options).await?;
+        }
+
+        Ok(result)
+    }
+}
+
+/// Computes the changes needed to go from the `existing` indexes to the `requested` ones.
+fn plan(
+    requested: Vec<IndexModel>,
+    existing: Vec<IndexModel>,
+    drop_unlisted: bool,
+) -> SyncIndexesResult {
+    let mut created = Vec::new();
+    let mut conflicts = Vec::new();
+    let mut listed = vec![false; existing.len()];
+
+    for mut model in requested {
+        let explicit_name = model.get_name().is_some();
+        model.update_name();
+        let name = model.get_name();
+
+        // Several indexes can share a key pattern if their options differ, so an index that also
+        // has the requested options is preferred over one with only the same keys. A requested
+        // index without a collation matches any collation, but one without a collation is
+        // preferred.
+        // Existing indexes that were already matched by an earlier requested index are skipped.
+        let options_match =
+            |index: &IndexModel| differences(&model, index, explicit_name).is_empty();
+        let unlisted = || existing.iter().enumerate().filter(|(i, _)| !listed[*i]);
+        let position = unlisted()
+            .find(|(_, index)| {
+                options_match(index) && (has_collation(&model) || !has_collation(index))
+            })
+            .or_else(|| unlisted().find(|(_, index)| options_match(index)))
+            .or_else(|| unlisted().find(|(_, index)| keys_match(&index.keys, &model.keys)))
+            .or_else(|| unlisted().find(|(_, index)| index.get_name() == name))
+            .map(|(i, _)| i);
+        match position {
+            Some(i) => {
+                listed[i] = true;
+                let differences = differences(&model, &existing[i], explicit_name);
+                if !differences.is_empty() {
+                    conflicts.push(IndexConflict {
+                        requested: model,
+                        existing: existing[i].clone(),
+                        differences,
+                    });
+                }
+            }
+            None => created.push(model),
+        }
+    }
+
+    let dropped = if drop_unlisted {
+        existing
+            .iter()
+            .zip(listed)
+            .filter(|(_, listed)| !listed)
+            .filter_map(|(index, _)| index.get_name())
+            .filter(|name| name != "_id_")
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    SyncIndexesResult {
+        created,
+        dropped,
+        conflicts,
+        dry_run: false,
+    }
+}
+
+/// Returns the names of the properties that differ between the `requested` and `existing` index.
+fn differences(requested: &IndexModel, existing: &IndexModel, explicit_name: bool) -> Vec<String> {
+    let default_options = Default::default();
+    let requested_options = requested.options.as_ref().unwrap_or(&default_options);
+    let existing_options = existing.options.as_ref().unwrap_or(&default_options);
+
+    let mut differences = Vec::new();
+    if !keys_match(&requested.keys, &existing.keys) {
+        differences.push("key");
+    }
+    if explicit_name && requested_options.name != existing_options.name {
+        differences.push("name");
+    }
+    if requested_options.unique.unwrap_or(false) != existing_options.unique.unwrap_or(false) {
+        differences.push("unique");
+    }
+    if requested_options.partial_filter_expression != existing_options.partial_filter_expression {
+        differences.push("partialFilterExpression");
+    }
+    if requested_options.expire_after != existing_options.expire_after {
+        differences.push("expireAfterSeconds");
+    }
+    if let Some(ref collation) = requested_options.collation {
+        if !collation_matches(collation, existing_options.collation.as_ref()) {
+            differences.push("collation");
+        }
+    }
+    if let (Some(requested_weights), Some(existing_weights)) =
+        (text_weights(requested), existing_options.weights.as_ref())
+    {
+        if !weights_match(&requested_weights, existing_weights) {
+            differences.push("weights");
+        }
+    }
+    differences.into_iter().map(String::from).collect()
+}
+
+/// Returns the weights of the fields of a text index: those given in the `weights` option, and a
+/// weight of 1 for the other fields in its keys. Returns `None` if the index is not a text index.
+fn text_weights(index: &IndexModel) -> Option<Document> {
+    let mut weights = index
+        .options
+        .as_ref()
+        .and_then(|options| options.weights.clone())
+        .unwrap_or_default();
+    let mut is_text = false;
+    for (field, value) in &index.keys {
+        if is_text_key(value) && field != "_fts" {
+            is_text = true;
+            if !weights.contains_key(field) {
+                weights.insert(field, 1);
+            }
+        }
+    }
+    if is_text {
+        Some(weights)
+    } else {
+        None
+    }
+}
+
+/// Whether two sets of text index weights are equal, regardless of their order.
+fn weights_match(a: &Document, b: &Document) -> bool {
+    a.len() == b.len()
+        && a.iter().all(|(field, a_value)| match b.get(field) {
+            Some(b_value) => match (as_f64(a_value), as_f64(b_value)) {
+                (Some(a), Some(b)) => a == b,
+                _ => a_value == b_value,
+            },
+            None => false,
+        })
+}
+
+fn is_text_key(value: &Bson) -> bool {
+    matches!(value, Bson::String(s) if s == "text")
+}
+
+/// Returns the key pattern the server lists for an index with the keys `keys`. The fields of a
+/// text index, including the wildcard `$**`, are replaced with `_fts: "text", _ftsx: 1` at the
+/// position of the first of them.
+fn normalize_keys(keys: &Document) -> Document {
+    let mut normalized = Document::new();
+    for (field, value) in keys {
+        if is_text_key(value) {
+            if !normalized.contains_key("_fts") {
+                normalized.insert("_fts", "text");
+                normalized.insert("_ftsx", 1);
+            }
+        } else if field != "_ftsx" || !normalized.contains_key("_fts") {
+            normalized.insert(field, value.clone());
+        }
+    }
+    normalized
+}
+
+fn has_collation(index: &IndexModel) -> bool {
+    matches!(index.options, Some(ref options) if options.collation.is_some())
+}
+
+/// Whether two key patterns are equal, treating numerically equal directions of different types
+/// (e.g. `1` and `1.0`) as equal and text index fields as the `_fts` and `_ftsx` keys listed by
+/// the server.
+fn keys_match(a: &Document, b: &Document) -> bool {
+    let (a, b) = (normalize_keys(a), normalize_keys(b));
+    a.len() == b.len()
+        && a.iter()
+            .zip(b.iter())
+            .all(|((a_key, a_value), (b_key, b_value))| {
+                a_key == b_key
+                    && match (as_f64(a_value), as_f64(b_value)) {
+                        (Some(a), Some(b)) => a == b,
+                        _ => a_value == b_value,
+                    }
+            })
+}
+
+fn as_f64(value: &Bson) -> Option<f64> {
+    match *value {
+        Bson::Int32(i) => Some(i.into()),
+        Bson::Int64(i) => Some(i as f64),
+        Bson::Double(d) => Some(d),
+        _ => None,
+    }
+}
+
+/// Whether the fields specified in the `requested` collation have the same values in the
+/// `existing` collation. The server reports the simple collation as no collation.
+fn collation_matches(requested: &Collation, existing: Option<&Collation>) -> bool {
+    let existing = match existing {
+        Some(existing) => existing,
+        None => return requested.locale == "simple",
+    };
+    match (bson::to_document(requested), bson::to_document(existing)) {
+        (Ok(requested), Ok(existing)) => requested
+            .iter()
+            .all(|(key, value)| existing.get(key) == Some(value)),
+        _ => false,
+    }
+}
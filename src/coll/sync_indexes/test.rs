@@ -0,0 +1,300 @@
+use std::time::Duration;
+
+use crate::{
+    bson::{doc, Document},
+    coll::sync_indexes::plan,
+    index::{options::IndexOptions, IndexModel},
+    options::{Collation, CollationStrength},
+};
+
+fn index(keys: Document, options: IndexOptions) -> IndexModel {
+    IndexModel::builder().keys(keys).options(options).build()
+}
+
+fn named(keys: Document, name: &str) -> IndexModel {
+    index(keys, IndexOptions::builder().name(name.to_string()).build())
+}
+
+fn names(models: &[IndexModel]) -> Vec<String> {
+    models.iter().filter_map(IndexModel::get_name).collect()
+}
+
+#[test]
+fn create_missing() {
+    let existing = vec![
+        named(doc! { "_id": 1 }, "_id_"),
+        named(doc! { "a": 1 }, "a_1"),
+    ];
+    let requested = vec![
+        IndexModel::builder().keys(doc! { "a": 1.0 }).build(),
+        IndexModel::builder().keys(doc! { "b": 1, "c": -1 }).build(),
+    ];
+
+    let result = plan(requested, existing, false);
+    assert_eq!(names(&result.created), vec!["b_1_c_-1".to_string()]);
+    assert!(result.dropped.is_empty());
+    assert!(result.conflicts.is_empty());
+    assert!(!result.dry_run);
+}
+
+#[test]
+fn drop_unlisted() {
+    let existing = vec![
+        named(doc! { "_id": 1 }, "_id_"),
+        named(doc! { "a": 1 }, "a_1"),
+        named(doc! { "b": 1 }, "b_1"),
+    ];
+    let requested = vec![IndexModel::builder().keys(doc! { "a": 1 }).build()];
+
+    let result = plan(requested.clone(), existing.clone(), false);
+    assert!(result.dropped.is_empty());
+
+    let result = plan(requested, existing, true);
+    assert!(result.created.is_empty());
+    assert_eq!(result.dropped, vec!["b_1".to_string()]);
+}
+
+#[test]
+fn key_order_matters() {
+    let existing = vec![named(doc! { "a": 1, "b": 1 }, "a_1_b_1")];
+    let requested = vec![IndexModel::builder().keys(doc! { "b": 1, "a": 1 }).build()];
+
+    let result = plan(requested, existing, true);
+    assert_eq!(names(&result.created), vec!["b_1_a_1".to_string()]);
+    assert_eq!(result.dropped, vec!["a_1_b_1".to_string()]);
+}
+
+#[test]
+fn conflicts() {
+    let existing = vec![
+        index(
+            doc! { "a": 1 },
+            IndexOptions::builder()
+                .name("a_1".to_string())
+                .unique(true)
+                .build(),
+        ),
+        index(
+            doc! { "b": 1 },
+            IndexOptions::builder()
+                .name("b_1".to_string())
+                .expire_after(Duration::from_secs(60))
+                .partial_filter_expression(doc! { "x": { "$exists": true } })
+                .build(),
+        ),
+        named(doc! { "c": 1 }, "c_1"),
+        named(doc! { "d": 1 }, "custom"),
+    ];
+    let requested = vec![
+        IndexModel::builder().keys(doc! { "a": 1 }).build(),
+        index(
+            doc! { "b": 1 },
+            IndexOptions::builder()
+                .expire_after(Duration::from_secs(120))
+                .build(),
+        ),
+        named(doc! { "c": -1 }, "c_1"),
+        named(doc! { "d": 1 }, "d_1"),
+    ];
+
+    let result = plan(requested, existing, true);
+    assert!(result.created.is_empty());
+    assert!(result.dropped.is_empty());
+
+    let differences: Vec<(String, Vec<String>)> = result
+        .conflicts
+        .iter()
+        .map(|conflict| {
+            (
+                conflict.existing.get_name().unwrap(),
+                conflict.differences.clone(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        differences,
+        vec![
+            ("a_1".to_string(), vec!["unique".to_string()]),
+            (
+                "b_1".to_string(),
+                vec![
+                    "partialFilterExpression".to_string(),
+                    "expireAfterSeconds".to_string()
+                ]
+            ),
+            ("c_1".to_string(), vec!["key".to_string()]),
+            ("custom".to_string(), vec!["name".to_string()]),
+        ]
+    );
+}
+
+#[test]
+fn same_keys_different_options() {
+    let partial = || {
+        IndexOptions::builder()
+            .partial_filter_expression(doc! { "b": { "$exists": true } })
+            .build()
+    };
+    let collation = || {
+        IndexOptions::builder()
+            .collation(Collation::builder().locale("fr".to_string()).build())
+            .build()
+    };
+    let existing = vec![
+        index(
+            doc! { "a": 1 },
+            IndexOptions::builder()
+                .name("a_1_partial".to_string())
+                .partial_filter_expression(doc! { "b": { "$exists": true } })
+                .build(),
+        ),
+        index(
+            doc! { "a": 1 },
+            IndexOptions::builder()
+                .name("a_1_fr".to_string())
+                .collation(Collation::builder().locale("fr".to_string()).build())
+                .build(),
+        ),
+        named(doc! { "a": 1 }, "a_1"),
+    ];
+
+    let requested = vec![
+        IndexModel::builder().keys(doc! { "a": 1 }).build(),
+        index(doc! { "a": 1 }, collation()),
+        index(doc! { "a": 1 }, partial()),
+    ];
+    let result = plan(requested, existing.clone(), true);
+    assert!(result.created.is_empty());
+    assert!(result.dropped.is_empty());
+    assert!(result.conflicts.is_empty());
+
+    let requested = vec![index(doc! { "a": 1 }, partial())];
+    let result = plan(requested, existing, true);
+    assert!(result.conflicts.is_empty());
+    assert_eq!(
+        result.dropped,
+        vec!["a_1_fr".to_string(), "a_1".to_string()]
+    );
+}
+
+#[test]
+fn collation() {
+    let existing_collation = Collation::builder()
+        .locale("en_US".to_string())
+        .strength(CollationStrength::Secondary)
+        .case_level(false)
+        .build();
+    let existing = vec![
+        index(
+            doc! { "a": 1 },
+            IndexOptions::builder()
+                .name("a_1".to_string())
+                .collation(existing_collation)
+                .build(),
+        ),
+        named(doc! { "b": 1 }, "b_1"),
+    ];
+
+    let requested_collation = |locale: &str| {
+        Collation::builder()
+            .locale(locale.to_string())
+            .strength(CollationStrength::Secondary)
+            .build()
+    };
+    let requested = vec![
+        index(
+            doc! { "a": 1 },
+            IndexOptions::builder()
+                .collation(requested_collation("en_US"))
+                .build(),
+        ),
+        index(
+            doc! { "b": 1 },
+            IndexOptions::builder()
+                .collation(Collation::builder().locale("simple".to_string()).build())
+                .build(),
+        ),
+    ];
+    let result = plan(requested, existing.clone(), false);
+    assert!(result.conflicts.is_empty());
+
+    let requested = vec![
+        index(
+            doc! { "a": 1 },
+            IndexOptions::builder()
+                .collation(requested_collation("fr"))
+                .build(),
+        ),
+        index(
+            doc! { "b": 1 },
+            IndexOptions::builder()
+                .collation(requested_collation("en_US"))
+                .build(),
+        ),
+    ];
+    let result = plan(requested, existing, false);
+    assert_eq!(result.conflicts.len(), 2);
+    assert!(result
+        .conflicts
+        .iter()
+        .all(|conflict| conflict.differences == vec!["collation".to_string()]));
+}
+
+#[test]
+fn existing_index_matches_once() {
+    let existing = vec![named(doc! { "a": 1 }, "a_1")];
+    let requested = vec![
+        IndexModel::builder().keys(doc! { "a": 1 }).build(),
+        index(
+            doc! { "a": 1 },
+            IndexOptions::builder()
+                .name("a_1_unique".to_string())
+                .unique(true)
+                .build(),
+        ),
+    ];
+
+    let result = plan(requested, existing, true);
+    assert_eq!(names(&result.created), vec!["a_1_unique".to_string()]);
+    assert!(result.conflicts.is_empty());
+    assert!(result.dropped.is_empty());
+}
+
+#[test]
+fn text_indexes() {
+    let text = |name: &str, weights: Document| {
+        index(
+            doc! { "a": 1, "_fts": "text", "_ftsx": 1 },
+            IndexOptions::builder()
+                .name(name.to_string())
+                .weights(weights)
+                .build(),
+        )
+    };
+    let existing = vec![
+        text("a_1_title_text_body_text", doc! { "body": 1, "title": 5 }),
+        text("$**_text", doc! { "$**": 1 }),
+    ];
+
+    let requested = vec![
+        index(
+            doc! { "a": 1, "title": "text", "body": "text" },
+            IndexOptions::builder().weights(doc! { "title": 5 }).build(),
+        ),
+        IndexModel::builder()
+            .keys(doc! { "a": 1, "$**": "text" })
+            .build(),
+    ];
+    let result = plan(requested, existing.clone(), true);
+    assert!(result.created.is_empty());
+    assert!(result.dropped.is_empty());
+    assert!(result.conflicts.is_empty());
+
+    let requested = vec![IndexModel::builder()
+        .keys(doc! { "a": 1, "title": "text" })
+        .build()];
+    let result = plan(requested, existing, false);
+    assert!(result.created.is_empty());
+    assert_eq!(result.conflicts.len(), 1);
+    assert_eq!(result.conflicts[0].differences, vec!["weights".to_string()]);
+}
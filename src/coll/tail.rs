@@ -0,0 +1,245 @@
+use std::{
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use derivative::Derivative;
+use futures_core::{future::BoxFuture, Stream};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    bson::{doc, Bson, Document, RawDocumentBuf},
+    error::Result,
+    options::{CursorType, FindOptions, TailOptions},
+    runtime,
+    Collection,
+    Cursor,
+};
+
+/// How long to wait before re-issuing the find query after the server closes the cursor, if no
+/// `max_await_time` is specified.
+const DEFAULT_RESTART_DELAY: Duration = Duration::from_secs(1);
+
+impl<T> Collection<T>
+where
+    T: DeserializeOwned + Unpin + Send + Sync,
+{
+    /// Tails the documents in a capped collection that match `filter`, returning a
+    /// [`TailableCursor`] that yields documents as they are inserted.
+    ///
+    /// Unlike a [`Cursor`] created with [`CursorType::Tailable`], the `TailableCursor` remembers
+    /// the value of the `resume_field` (`_id` by default) of the last document it returned, and
+    /// re-issues the find query for the documents after it when it encounters a resumable error,
+    /// such as a network error or a primary stepping down. The query is also re-issued when the
+    /// server closes the cursor, e.g. because the collection was empty, after waiting for the
+    /// `max_await_time` (one second by default), so the stream does not end.
+    pub async fn tail(
+        &self,
+        filter: impl Into<Option<Document>>,
+        options: impl Into<Option<TailOptions>>,
+    ) -> Result<TailableCursor<T>> {
+        let options = options.into().unwrap_or_default();
+        let args = TailArgs {
+            coll: self.clone_with_type(),
+            filter: filter.into().unwrap_or_default(),
+            resume_field: options
+                .resume_field
+                .clone()
+                .unwrap_or_else(|| "_id".to_string()),
+            options,
+        };
+        let resume_value = args.options.resume_after.clone();
+        let cursor = args.execute(resume_value.clone()).await?;
+
+        Ok(TailableCursor {
+            cursor,
+            args,
+            resume_value,
+            resume_attempted: false,
+            pending_resume: None,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+/// A stream of the documents inserted into a capped collection that resumes after errors. A
+/// `TailableCursor` is created with [`Collection::tail`].
+///
+/// A `TailableCursor` can be iterated like any other [`Stream`]:
+///
+/// ```rust
+/// # use futures::stream::TryStreamExt;
+/// # use mongodb::{bson::{doc, Document}, error::Result, options::TailOptions, Collection};
+/// # use std::time::Duration;
+/// # async fn run(coll: Collection<Document>) -> Result<()> {
+/// let options = TailOptions::builder()
+///     .max_await_time(Duration::from_secs(10))
+///     .build();
+/// let mut cursor = coll.tail(doc! { "level": "error" }, options).await?;
+/// while let Some(entry) = cursor.try_next().await? {
+///     println!("{}", entry);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct TailableCursor<T> {
+    /// The cursor of the current find query.
+    cursor: Cursor<RawDocumentBuf>,
+
+    /// Arguments to `tail` that created this cursor.
+    args: TailArgs,
+
+    /// The value of the resume field of the last document returned.
+    resume_value: Option<Bson>,
+
+    /// Whether a resume has been attempted since the last successful resume, used to attempt a
+    /// resume only once.
+    resume_attempted: bool,
+
+    /// A pending future for a resume.
+    #[derivative(Debug = "ignore")]
+    pending_resume: Option<BoxFuture<'static, Result<Cursor<RawDocumentBuf>>>>,
+
+    #[derivative(Debug = "ignore")]
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> TailableCursor<T> {
+    /// Returns the value of the resume field of the most recently returned document, which can be
+    /// passed to [`TailOptions::resume_after`] to continue from the same position with a new
+    /// `TailableCursor`.
+    pub fn resume_value(&self) -> Option<&Bson> {
+        self.resume_value.as_ref()
+    }
+
+    /// Returns whether the cursor of the current find query is open. A tailable cursor is closed
+    /// by the server if the collection is empty or the cursor's position has been overwritten, in
+    /// which case the query is re-issued the next time the `TailableCursor` is polled.
+    pub fn is_alive(&self) -> bool {
+        self.pending_resume.is_some() || !self.cursor.is_exhausted()
+    }
+}
+
+impl<T> Stream for TailableCursor<T>
+where
+    T: DeserializeOwned,
+{
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(mut pending) = self.pending_resume.take() {
+                match Pin::new(&mut pending).poll(cx) {
+                    Poll::Pending => {
+                        self.pending_resume = Some(pending);
+                        return Poll::Pending;
+                    }
+                    Poll::Ready(Ok(cursor)) => {
+                        // Ensure that the old cursor is killed on the server selected for the new
+                        // one.
+                        self.cursor.set_drop_address(cursor.address().clone());
+                        self.cursor = cursor;
+                        // After a successful resume, another resume must be allowed.
+                        self.resume_attempted = false;
+                        continue;
+                    }
+                    Poll::Ready(Err(e)) => return Poll::Ready(Some(Err(e))),
+                }
+            }
+
+            match Pin::new(&mut self.cursor).poll_next(cx) {
+                Poll::Ready(Some(Ok(doc))) => {
+                    return Poll::Ready(Some(self.process(doc)));
+                }
+                Poll::Ready(Some(Err(e))) if e.is_tail_resumable() && !self.resume_attempted => {
+                    self.resume_attempted = true;
+                    let args = self.args.clone();
+                    let resume_value = self.resume_value.clone();
+                    self.pending_resume =
+                        Some(Box::pin(async move { args.execute(resume_value).await }));
+                    // Iterate the loop so the new future gets polled and can register wakers.
+                    continue;
+                }
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) => {
+                    // The server closed the cursor, so wait for more documents to be inserted
+                    // before querying for the documents after the last one returned.
+                    let args = self.args.clone();
+                    let resume_value = self.resume_value.clone();
+                    self.pending_resume = Some(Box::pin(async move {
+                        runtime::delay_for(args.restart_delay()).await;
+                        args.execute(resume_value).await
+                    }));
+                    continue;
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl<T> TailableCursor<T>
+where
+    T: DeserializeOwned,
+{
+    fn process(&mut self, doc: RawDocumentBuf) -> Result<T> {
+        if let Some(value) = doc.get(&self.args.resume_field)? {
+            self.resume_value = Some(value.to_raw_bson().try_into()?);
+        }
+        Ok(bson::from_slice(doc.as_bytes())?)
+    }
+}
+
+/// Arguments passed to `tail`, captured to allow resume.
+#[derive(Clone, Debug)]
+struct TailArgs {
+    coll: Collection<RawDocumentBuf>,
+    filter: Document,
+    resume_field: String,
+    options: TailOptions,
+}
+
+impl TailArgs {
+    /// How long to wait before re-issuing the find query after the server closes the cursor.
+    fn restart_delay(&self) -> Duration {
+        self.options.max_await_time.unwrap_or(DEFAULT_RESTART_DELAY)
+    }
+
+    /// Runs the find query for the documents after `resume_value`.
+    async fn execute(&self, resume_value: Option<Bson>) -> Result<Cursor<RawDocumentBuf>> {
+        let filter = match resume_value {
+            Some(value) => {
+                let range = doc! { self.resume_field.as_str(): { "$gt": value } };
+                if self.filter.is_empty() {
+                    range
+                } else {
+                    doc! { "$and": [self.filter.clone(), range] }
+                }
+            }
+            None => self.filter.clone(),
+        };
+
+        let options = &self.options;
+        // The server rejects maxTimeMS on the getMores of a cursor that isn't awaitData.
+        let (cursor_type, max_await_time) = if options.await_data.unwrap_or(true) {
+            (CursorType::TailableAwait, options.max_await_time)
+        } else {
+            (CursorType::Tailable, None)
+        };
+        let find_options = FindOptions::builder()
+            .cursor_type(cursor_type)
+            .max_await_time(max_await_time)
+            .batch_size(options.batch_size)
+            .projection(options.projection.clone())
+            .read_concern(options.read_concern.clone())
+            .selection_criteria(options.selection_criteria.clone())
+            .comment_bson(options.comment.clone())
+            .build();
+        self.coll.find(filter, find_options).await
+    }
+}
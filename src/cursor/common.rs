@@ -23,6 +23,7 @@ use crate::{
     results::GetMoreResult,
     runtime,
     Client,
+    ClientSession,
     Namespace,
 };
 
@@ -114,6 +115,11 @@ where
             // if moving the offset puts us at the end of the buffer, perform another
             // getMore if the cursor is still alive.
 
+            if self.provider.executing_future().is_some() {
+                self.finish_get_more().await?;
+                continue;
+            }
+
             if self.state().exhausted {
                 return Ok(false);
             }
@@ -129,6 +135,57 @@ where
         Ok(true)
     }
 
+    /// Returns the documents remaining in the current batch, performing getMores until a
+    /// non-empty batch is received if there are none. Returns `None` once the cursor is exhausted.
+    pub(super) async fn next_batch(&mut self) -> Result<Option<VecDeque<RawDocumentBuf>>> {
+        loop {
+            self.finish_get_more().await?;
+            // The document `advance` moved to has already been returned.
+            self.state_mut().buffer.discard_current();
+
+            if !self.state().buffer.is_empty() {
+                return Ok(Some(self.state_mut().buffer.take()));
+            }
+
+            if self.state().exhausted || self.state().pinned_connection.is_invalid() {
+                return Ok(None);
+            }
+
+            let client = self.client.clone();
+            let spec = self.info.clone();
+            let pin = self.state().pinned_connection.replicate();
+
+            let result = self.provider.execute(spec, client, pin).await;
+            self.handle_get_more_result(result)?;
+        }
+    }
+
+    /// Starts a getMore for the next batch if the cursor is still alive and there isn't one
+    /// in flight already.
+    pub(super) fn start_get_more(&mut self) {
+        if self.state().exhausted || self.state().pinned_connection.is_invalid() {
+            return;
+        }
+        let info = self.info.clone();
+        let client = self.client.clone();
+        let state = self.state.as_mut().unwrap();
+        self.provider
+            .start_execution(info, client, state.pinned_connection.handle());
+    }
+
+    /// Waits for a getMore started by polling the cursor or by `start_get_more` to complete, if
+    /// one is in flight.
+    async fn finish_get_more(&mut self) -> Result<()> {
+        if let Some(future) = self.provider.executing_future() {
+            let (result, session) = future.await.into_parts();
+            let output = self.handle_get_more_result(result);
+            self.provider
+                .clear_execution(session, self.state().exhausted);
+            output?;
+        }
+        Ok(())
+    }
+
     pub(super) fn take_state(&mut self) -> CursorState {
         self.state.take().unwrap()
     }
@@ -157,6 +214,21 @@ where
         self.state().post_batch_resume_token.as_ref()
     }
 
+    pub(super) async fn close(
+        &mut self,
+        drop_address: Option<ServerAddress>,
+        session: Option<&mut ClientSession>,
+    ) -> Result<()> {
+        close_cursor(
+            &self.client,
+            &self.info,
+            self.state.as_mut().unwrap(),
+            drop_address,
+            session,
+        )
+        .await
+    }
+
     fn mark_exhausted(&mut self) {
         self.state_mut().exhausted = true;
         self.state_mut().pinned_connection = PinnedConnection::Unpinned;
@@ -483,7 +555,7 @@ pub(super) fn kill_cursor(
     runtime::execute(async move {
         if !pinned_conn.is_invalid() {
             let _ = coll
-                .kill_cursor(cursor_id, pinned_conn.handle(), drop_address)
+                .kill_cursor(cursor_id, pinned_conn.handle(), drop_address, None)
                 .await;
             #[cfg(test)]
             if let Some(tx) = kill_watcher {
@@ -493,6 +565,39 @@ pub(super) fn kill_cursor(
     });
 }
 
+/// Kills the server-side cursor with a `killCursors` command on its pinned connection or the
+/// server it was created on, waiting for the result. The cursor is marked exhausted and its
+/// buffered results are discarded.
+pub(super) async fn close_cursor(
+    client: &Client,
+    info: &CursorInformation,
+    state: &mut CursorState,
+    drop_address: Option<ServerAddress>,
+    session: Option<&mut ClientSession>,
+) -> Result<()> {
+    if state.exhausted {
+        return Ok(());
+    }
+    state.exhausted = true;
+    state.buffer = CursorBuffer::new(VecDeque::new());
+    let pinned_conn = std::mem::replace(&mut state.pinned_connection, PinnedConnection::Unpinned);
+    // The server closes a cursor when its pinned connection is closed.
+    if pinned_conn.is_invalid() {
+        return Ok(());
+    }
+
+    client
+        .database(info.ns.db.as_str())
+        .collection::<Document>(info.ns.coll.as_str())
+        .kill_cursor(
+            info.id,
+            pinned_conn.handle(),
+            Some(drop_address.unwrap_or_else(|| info.address.clone())),
+            session,
+        )
+        .await
+}
+
 #[derive(Debug)]
 pub(crate) struct CursorState {
     pub(crate) buffer: CursorBuffer,
@@ -506,6 +611,8 @@ pub(crate) struct CursorBuffer {
     docs: VecDeque<RawDocumentBuf>,
     /// whether the buffer is at the front or not
     fresh: bool,
+    /// whether the document at the front of the buffer is the current document of `advance`
+    advanced: bool,
 }
 
 impl CursorBuffer {
@@ -513,6 +620,7 @@ impl CursorBuffer {
         Self {
             docs: initial_buffer,
             fresh: true,
+            advanced: false,
         }
     }
 
@@ -522,6 +630,7 @@ impl CursorBuffer {
 
     pub(crate) fn next(&mut self) -> Option<RawDocumentBuf> {
         self.fresh = false;
+        self.advanced = false;
         self.docs.pop_front()
     }
 
@@ -530,14 +639,29 @@ impl CursorBuffer {
         // hasn't been consumed yet.
         if self.fresh {
             self.fresh = false;
-            return;
+        } else {
+            self.next();
+        }
+        self.advanced = true;
+    }
+
+    /// Removes the current document of `advance` from the buffer, if there is one.
+    pub(crate) fn discard_current(&mut self) {
+        if self.advanced {
+            self.next();
         }
-        self.next();
     }
 
     pub(crate) fn current(&self) -> Option<&RawDocument> {
         self.docs.front().map(|d| d.as_ref())
     }
+
+    /// Removes and returns all of the documents in the buffer.
+    pub(crate) fn take(&mut self) -> VecDeque<RawDocumentBuf> {
+        self.fresh = false;
+        self.advanced = false;
+        std::mem::take(&mut self.docs)
+    }
 }
 
 impl AsRef<VecDeque<RawDocumentBuf>> for CursorBuffer {
@@ -1,17 +1,13 @@
 mod common;
 pub(crate) mod session;
 
-#[cfg(test)]
-use std::collections::VecDeque;
 use std::{
+    collections::VecDeque,
     pin::Pin,
     task::{Context, Poll},
 };
 
-use bson::RawDocument;
-
-#[cfg(test)]
-use bson::RawDocumentBuf;
+use bson::{RawDocument, RawDocumentBuf};
 use futures_core::{future::BoxFuture, Stream};
 use serde::{de::DeserializeOwned, Deserialize};
 #[cfg(test)]
@@ -24,6 +20,7 @@ use crate::{
     error::{Error, Result},
     operation::GetMore,
     results::GetMoreResult,
+    runtime,
     Client,
     ClientSession,
 };
@@ -106,6 +103,7 @@ pub struct Cursor<T> {
     // that's had `with_type` called; in all other circumstances it will be `Some`.
     wrapped_cursor: Option<ImplicitSessionCursor<T>>,
     drop_address: Option<ServerAddress>,
+    prefetch: bool,
     #[cfg(test)]
     kill_watcher: Option<oneshot::Sender<()>>,
     _phantom: std::marker::PhantomData<T>,
@@ -129,6 +127,7 @@ impl<T> Cursor<T> {
                 provider,
             )),
             drop_address: None,
+            prefetch: false,
             #[cfg(test)]
             kill_watcher: None,
             _phantom: Default::default(),
@@ -149,7 +148,14 @@ impl<T> Cursor<T> {
         &self.client
     }
 
-    pub(crate) fn address(&self) -> &ServerAddress {
+    /// The server-side ID of this cursor, or 0 if the server returned all of its results in the
+    /// first batch.
+    pub fn id(&self) -> i64 {
+        self.wrapped_cursor.as_ref().unwrap().id()
+    }
+
+    /// The address of the server this cursor was created on.
+    pub fn address(&self) -> &ServerAddress {
         self.wrapped_cursor.as_ref().unwrap().address()
     }
 
@@ -192,6 +198,76 @@ impl<T> Cursor<T> {
         self.wrapped_cursor.as_mut().unwrap().advance().await
     }
 
+    /// Closes the cursor with a
+    /// [killCursors](https://www.mongodb.com/docs/manual/reference/command/killCursors/) command,
+    /// waiting for the command to complete. Any buffered results are discarded, and the cursor
+    /// will not return any further results.
+    ///
+    /// Unlike the `killCursors` command executed when a [`Cursor`] is dropped, this returns any
+    /// error encountered closing the cursor. Closing a cursor that is already exhausted has no
+    /// effect.
+    ///
+    /// ```
+    /// # use mongodb::{Client, bson::Document, error::Result};
+    /// # async fn foo() -> Result<()> {
+    /// # let client = Client::with_uri_str("mongodb://localhost:27017").await?;
+    /// # let coll = client.database("stuff").collection::<Document>("stuff");
+    /// let mut cursor = coll.find(None, None).await?;
+    /// if cursor.advance().await? {
+    ///     println!("{:?}", cursor.current());
+    /// }
+    /// cursor.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn close(&mut self) -> Result<()> {
+        let drop_address = self.drop_address.take();
+        let cursor = self.wrapped_cursor.as_mut().unwrap();
+        let provider = cursor.provider_mut();
+        let mut session = provider.take_implicit_session();
+        // Discard any prefetched batch.
+        *provider = ImplicitSessionGetMoreProvider::Done;
+        cursor.close(drop_address, session.as_mut()).await
+    }
+
+    /// Returns the rest of the current batch of results, fetching the next batch from the server
+    /// if the current one has been consumed. Returns `None` once the cursor is exhausted.
+    ///
+    /// This allows documents to be processed in bulk, e.g. when copying them elsewhere. If
+    /// [`Cursor::set_prefetch`] is enabled, the request for the following batch is started in the
+    /// background before the batch is returned.
+    ///
+    /// ```
+    /// # use mongodb::{Client, bson::Document, error::Result, options::FindOptions};
+    /// # async fn foo() -> Result<()> {
+    /// # let client = Client::with_uri_str("mongodb://localhost:27017").await?;
+    /// # let coll = client.database("stuff").collection::<Document>("stuff");
+    /// let options = FindOptions::builder().batch_size(1000).build();
+    /// let mut cursor = coll.find(None, options).await?;
+    /// cursor.set_prefetch(true);
+    /// while let Some(batch) = cursor.next_batch().await? {
+    ///     println!("received {} documents", batch.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn next_batch(&mut self) -> Result<Option<VecDeque<RawDocumentBuf>>> {
+        let cursor = self.wrapped_cursor.as_mut().unwrap();
+        let batch = cursor.next_batch().await?;
+        if self.prefetch && batch.is_some() {
+            cursor.start_get_more();
+            cursor.provider_mut().spawn_execution();
+        }
+        Ok(batch)
+    }
+
+    /// Sets whether [`Cursor::next_batch`] should request the following batch from the server as
+    /// soon as it returns a batch, so that the network round trip overlaps with processing the
+    /// returned batch. This is disabled by default.
+    pub fn set_prefetch(&mut self, prefetch: bool) {
+        self.prefetch = prefetch;
+    }
+
     /// Returns a reference to the current result in the cursor.
     ///
     /// # Panics
@@ -259,6 +335,7 @@ impl<T> Cursor<T> {
             client: self.client.clone(),
             wrapped_cursor: self.wrapped_cursor.take().map(|c| c.with_type()),
             drop_address: self.drop_address.take(),
+            prefetch: self.prefetch,
             #[cfg(test)]
             kill_watcher: self.kill_watcher.take(),
             _phantom: Default::default(),
@@ -367,6 +444,15 @@ impl ImplicitSessionGetMoreProvider {
         }
     }
 
+    /// Runs the getMore in flight, if any, in a background task so that it makes progress
+    /// without the cursor being polled.
+    fn spawn_execution(&mut self) {
+        take_mut::take(self, |self_| match self_ {
+            Self::Executing(future) => Self::Executing(Box::pin(runtime::spawn(future))),
+            other => other,
+        })
+    }
+
     /// Extract the stored implicit session, if any.  The provider cannot be started again after
     /// this call.
     fn take_implicit_session(&mut self) -> Option<ClientSession> {
@@ -13,6 +13,7 @@ use tokio::sync::oneshot;
 
 use super::{
     common::{
+        close_cursor,
         kill_cursor,
         CursorBuffer,
         CursorInformation,
@@ -321,10 +322,36 @@ impl<T> SessionCursor<T> {
         out
     }
 
-    pub(crate) fn address(&self) -> &ServerAddress {
+    /// The server-side ID of this cursor, or 0 if the server returned all of its results in the
+    /// first batch.
+    pub fn id(&self) -> i64 {
+        self.info.id
+    }
+
+    /// The address of the server this cursor was created on.
+    pub fn address(&self) -> &ServerAddress {
         &self.info.address
     }
 
+    /// Closes the cursor with a
+    /// [killCursors](https://www.mongodb.com/docs/manual/reference/command/killCursors/) command
+    /// using the provided session, waiting for the command to complete. Any buffered results are
+    /// discarded, and the cursor will not return any further results.
+    ///
+    /// Unlike the `killCursors` command executed when a [`SessionCursor`] is dropped, this returns
+    /// any error encountered closing the cursor. Closing a cursor that is already exhausted has no
+    /// effect.
+    pub async fn close(&mut self, session: &mut ClientSession) -> Result<()> {
+        close_cursor(
+            &self.client,
+            &self.info,
+            self.state.as_mut().unwrap(),
+            self.drop_address.take(),
+            Some(session),
+        )
+        .await
+    }
+
     pub(crate) fn set_drop_address(&mut self, address: ServerAddress) {
         self.drop_address = Some(address);
     }
@@ -20,14 +20,25 @@ use crate::{
     cursor::Cursor,
     error::{Error, ErrorKind, Result},
     gridfs::{options::GridFsBucketOptions, GridFsBucket},
-    operation::{Aggregate, AggregateTarget, Create, DropDatabase, ListCollections, RunCommand},
+    operation::{
+        Aggregate,
+        AggregateTarget,
+        Create,
+        DropDatabase,
+        ListCollections,
+        ModifyCollection,
+        RunCommand,
+    },
     options::{
         AggregateOptions,
         CollectionOptions,
         CreateCollectionOptions,
+        CreateViewOptions,
         DatabaseOptions,
         DropDatabaseOptions,
         ListCollectionsOptions,
+        ModifyCollectionOptions,
+        ModifyViewOptions,
     },
     results::CollectionSpecification,
     selection_criteria::SelectionCriteria,
@@ -403,6 +414,120 @@ impl Database {
         self.create_collection_common(name, options, session).await
     }
 
+    async fn create_view_common(
+        &self,
+        name: impl AsRef<str>,
+        source: impl AsRef<str>,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<CreateViewOptions>>,
+        session: impl Into<Option<&mut ClientSession>>,
+    ) -> Result<()> {
+        let options = options.into().unwrap_or_default();
+        let mut options = Some(
+            CreateCollectionOptions::builder()
+                .view_on(source.as_ref().to_string())
+                .pipeline(pipeline.into_iter().collect::<Vec<_>>())
+                .collation(options.collation)
+                .write_concern(options.write_concern)
+                .comment(options.comment)
+                .build(),
+        );
+        resolve_options!(self, options, [write_concern]);
+
+        let ns = Namespace {
+            db: self.name().to_string(),
+            coll: name.as_ref().to_string(),
+        };
+        let create = Create::new(ns, options);
+        self.client().execute_operation(create, session).await
+    }
+
+    /// Creates a read-only view with the given `name` whose contents are the results of running
+    /// the aggregation `pipeline` on the collection or view `source` in this database.
+    ///
+    /// See the MongoDB [manual](https://www.mongodb.com/docs/manual/core/views/) for more
+    /// information on views.
+    pub async fn create_view(
+        &self,
+        name: impl AsRef<str>,
+        source: impl AsRef<str>,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<CreateViewOptions>>,
+    ) -> Result<()> {
+        self.create_view_common(name, source, pipeline, options, None)
+            .await
+    }
+
+    /// Creates a read-only view with the given `name` whose contents are the results of running
+    /// the aggregation `pipeline` on the collection or view `source` in this database using the
+    /// provided `ClientSession`.
+    pub async fn create_view_with_session(
+        &self,
+        name: impl AsRef<str>,
+        source: impl AsRef<str>,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<CreateViewOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        self.create_view_common(name, source, pipeline, options, session)
+            .await
+    }
+
+    async fn modify_view_common(
+        &self,
+        name: impl AsRef<str>,
+        source: impl AsRef<str>,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<ModifyViewOptions>>,
+        session: impl Into<Option<&mut ClientSession>>,
+    ) -> Result<()> {
+        let options = options.into().unwrap_or_default();
+        let mut options = Some(
+            ModifyCollectionOptions::builder()
+                .view_on(source.as_ref().to_string())
+                .pipeline(pipeline.into_iter().collect::<Vec<_>>())
+                .write_concern(options.write_concern)
+                .comment(options.comment)
+                .build(),
+        );
+        resolve_options!(self, options, [write_concern]);
+
+        let ns = Namespace {
+            db: self.name().to_string(),
+            coll: name.as_ref().to_string(),
+        };
+        let modify = ModifyCollection::new(ns, options);
+        self.client().execute_operation(modify, session).await
+    }
+
+    /// Replaces the definition of the view with the given `name`, so that its contents are the
+    /// results of running the aggregation `pipeline` on the collection or view `source`.
+    pub async fn modify_view(
+        &self,
+        name: impl AsRef<str>,
+        source: impl AsRef<str>,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<ModifyViewOptions>>,
+    ) -> Result<()> {
+        self.modify_view_common(name, source, pipeline, options, None)
+            .await
+    }
+
+    /// Replaces the definition of the view with the given `name` using the provided
+    /// `ClientSession`, so that its contents are the results of running the aggregation `pipeline`
+    /// on the collection or view `source`.
+    pub async fn modify_view_with_session(
+        &self,
+        name: impl AsRef<str>,
+        source: impl AsRef<str>,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<ModifyViewOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        self.modify_view_common(name, source, pipeline, options, session)
+            .await
+    }
+
     pub(crate) async fn run_command_common(
         &self,
         command: Document,
@@ -483,6 +608,33 @@ impl Database {
         pipeline: impl IntoIterator<Item = Document>,
         options: impl Into<Option<AggregateOptions>>,
     ) -> Result<Cursor<Document>> {
+        self.aggregate_as(pipeline, options).await
+    }
+
+    /// Runs an aggregation operation with the provided `ClientSession`.
+    ///
+    /// See the documentation [here](https://www.mongodb.com/docs/manual/aggregation/) for more
+    /// information on aggregations.
+    pub async fn aggregate_with_session(
+        &self,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<AggregateOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<SessionCursor<Document>> {
+        self.aggregate_as_with_session(pipeline, options, session)
+            .await
+    }
+
+    /// Runs an aggregation operation whose output documents are deserialized as `R`. Each
+    /// document is deserialized from the cursor's buffered batch when it is returned.
+    ///
+    /// See the documentation [here](https://www.mongodb.com/docs/manual/aggregation/) for more
+    /// information on aggregations.
+    pub async fn aggregate_as<R>(
+        &self,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<AggregateOptions>>,
+    ) -> Result<Cursor<R>> {
         let mut options = options.into();
         resolve_options!(
             self,
@@ -495,16 +647,14 @@ impl Database {
         client.execute_cursor_operation(aggregate).await
     }
 
-    /// Runs an aggregation operation with the provided `ClientSession`.
-    ///
-    /// See the documentation [here](https://www.mongodb.com/docs/manual/aggregation/) for more
-    /// information on aggregations.
-    pub async fn aggregate_with_session(
+    /// Runs an aggregation operation whose output documents are deserialized as `R` with the
+    /// provided `ClientSession`. See [`Database::aggregate_as`] for more information.
+    pub async fn aggregate_as_with_session<R>(
         &self,
         pipeline: impl IntoIterator<Item = Document>,
         options: impl Into<Option<AggregateOptions>>,
         session: &mut ClientSession,
-    ) -> Result<SessionCursor<Document>> {
+    ) -> Result<SessionCursor<R>> {
         let mut options = options.into();
         resolve_options!(
             self,
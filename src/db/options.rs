@@ -122,6 +122,45 @@ pub struct CreateCollectionOptions {
     pub encrypted_fields: Option<Document>,
 }
 
+/// Specifies the options to a
+/// [`Database::create_view`](../struct.Database.html#method.create_view) operation.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct CreateViewOptions {
+    /// The default collation for the view.
+    pub collation: Option<Collation>,
+
+    /// The write concern for the operation.
+    pub write_concern: Option<WriteConcern>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    ///
+    /// This option is only available on server versions 4.4+.
+    pub comment: Option<Bson>,
+}
+
+/// Specifies the options to a
+/// [`Database::modify_view`](../struct.Database.html#method.modify_view) operation.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct ModifyViewOptions {
+    /// The write concern for the operation.
+    pub write_concern: Option<WriteConcern>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    ///
+    /// This option is only available on server versions 4.4+.
+    pub comment: Option<Bson>,
+}
+
 /// Specifies how strictly the database should apply validation rules to existing documents during
 /// an update.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
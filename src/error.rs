@@ -10,7 +10,12 @@ use bson::Bson;
 use serde::Deserialize;
 use thiserror::Error;
 
-use crate::{bson::Document, options::ServerAddress, sdam::TopologyVersion};
+use crate::{
+    bson::Document,
+    options::ServerAddress,
+    results::{BulkWriteResult, ClientBulkWriteResult},
+    sdam::TopologyVersion,
+};
 
 const RECOVERING_CODES: [i32; 5] = [11600, 11602, 13436, 189, 91];
 const NOTWRITABLEPRIMARY_CODES: [i32; 3] = [10107, 13435, 10058];
@@ -192,6 +197,7 @@ impl Error {
             self.kind.as_ref(),
             ErrorKind::Authentication { .. }
                 | ErrorKind::BulkWrite(_)
+                | ErrorKind::ClientBulkWrite(_)
                 | ErrorKind::Command(_)
                 | ErrorKind::Write(_)
         )
@@ -231,6 +237,11 @@ impl Error {
         )
     }
 
+    /// Whether this error was caused by the server exceeding an operation's `maxTimeMS`.
+    pub(crate) fn is_max_time_ms_expired_error(&self) -> bool {
+        self.code() == Some(50)
+    }
+
     /// Gets the code from this error for performing SDAM updates, if applicable.
     /// Any codes contained in WriteErrors are ignored.
     pub(crate) fn code(&self) -> Option<i32> {
@@ -260,7 +271,7 @@ impl Error {
             ErrorKind::BulkWrite(BulkWriteFailure {
                 write_concern_error,
                 write_errors,
-                inserted_ids: _,
+                ..
             }) => {
                 let mut msg = "".to_string();
                 if let Some(wc_error) = write_concern_error {
@@ -273,6 +284,22 @@ impl Error {
                 }
                 Some(msg)
             }
+            ErrorKind::ClientBulkWrite(ClientBulkWriteFailure {
+                write_errors,
+                write_concern_errors,
+                ..
+            }) => {
+                let mut msg = "".to_string();
+                for wc_error in write_concern_errors {
+                    msg.push_str(wc_error.message.as_str());
+                }
+                let mut indexes: Vec<_> = write_errors.keys().collect();
+                indexes.sort();
+                for index in indexes {
+                    msg.push_str(write_errors[index].message.as_str());
+                }
+                Some(msg)
+            }
             ErrorKind::Write(WriteFailure::WriteConcernError(wc_error)) => {
                 Some(wc_error.message.clone())
             }
@@ -355,6 +382,13 @@ impl Error {
         false
     }
 
+    /// Whether a tailable cursor should re-issue its query after this error. Unlike
+    /// [`Error::is_resumable`], this does not depend on the `ResumableChangeStreamError` label,
+    /// which the server only adds to errors from change streams.
+    pub(crate) fn is_tail_resumable(&self) -> bool {
+        self.is_network_error() || self.code() == Some(43) || self.is_state_change_error()
+    }
+
     pub(crate) fn is_incompatible_server(&self) -> bool {
         matches!(self.kind.as_ref(), ErrorKind::IncompatibleServer { .. })
     }
@@ -448,6 +482,11 @@ pub enum ErrorKind {
     #[error("An error occurred when trying to execute a write operation: {0:?}")]
     BulkWrite(BulkWriteFailure),
 
+    /// An error occurred when trying to execute a
+    /// [`Client::bulk_write`](crate::Client::bulk_write) operation.
+    #[error("An error occurred when trying to execute a client bulk write: {0:?}")]
+    ClientBulkWrite(ClientBulkWriteFailure),
+
     /// The server returned an error to an attempted operation.
     #[error("Command failed {0}")]
     Command(CommandError),
@@ -528,6 +567,10 @@ impl ErrorKind {
                 write_concern_error,
                 ..
             }) => write_concern_error.as_ref(),
+            ErrorKind::ClientBulkWrite(ClientBulkWriteFailure {
+                write_concern_errors,
+                ..
+            }) => write_concern_errors.first(),
             ErrorKind::Write(WriteFailure::WriteConcernError(err)) => Some(err),
             _ => None,
         }
@@ -651,6 +694,12 @@ pub struct BulkWriteFailure {
     /// The error that occurred on account of write concern failure.
     pub write_concern_error: Option<WriteConcernError>,
 
+    /// The result of the writes that were performed before the error occurred, including those
+    /// in the command that failed. This is only set by
+    /// [`Collection::bulk_write`](crate::Collection::bulk_write).
+    #[serde(skip)]
+    pub partial_result: Option<BulkWriteResult>,
+
     #[serde(skip)]
     pub(crate) inserted_ids: HashMap<usize, Bson>,
 }
@@ -660,11 +709,41 @@ impl BulkWriteFailure {
         BulkWriteFailure {
             write_errors: None,
             write_concern_error: None,
+            partial_result: None,
             inserted_ids: Default::default(),
         }
     }
 }
 
+/// The set of errors that occurred during a [`Client::bulk_write`](crate::Client::bulk_write)
+/// operation. The underlying error that stopped the operation, if any, is available via
+/// [`std::error::Error::source`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ClientBulkWriteFailure {
+    /// The errors that occurred for individual writes, keyed by the index of the corresponding
+    /// write model.
+    pub write_errors: HashMap<usize, WriteError>,
+
+    /// The write concern errors that occurred. A bulk write may be split across several commands,
+    /// each of which can report a write concern error.
+    pub write_concern_errors: Vec<WriteConcernError>,
+
+    /// The result of the writes that were known to have been performed before the error
+    /// occurred, if any.
+    pub partial_result: Option<ClientBulkWriteResult>,
+}
+
+impl ClientBulkWriteFailure {
+    pub(crate) fn new() -> Self {
+        ClientBulkWriteFailure {
+            write_errors: HashMap::new(),
+            write_concern_errors: Vec::new(),
+            partial_result: None,
+        }
+    }
+}
+
 /// An error that occurred when trying to execute a write operation.
 #[derive(Clone, Debug)]
 #[non_exhaustive]
@@ -715,6 +794,10 @@ pub enum GridFsErrorKind {
     #[non_exhaustive]
     MissingChunk { n: u32 },
 
+    /// More than one chunk with index 'n' was present.
+    #[non_exhaustive]
+    DuplicateChunk { n: u32 },
+
     /// The chunk was the incorrect size.
     #[non_exhaustive]
     WrongSizeChunk {
@@ -728,6 +811,15 @@ pub enum GridFsErrorKind {
         actual_number: u32,
         expected_number: u32,
     },
+
+    /// An operation was attempted on a `GridFsUploadStream` that has already been closed or
+    /// aborted.
+    UploadStreamClosed,
+
+    /// The checksum of the downloaded contents of a file did not match the checksum stored in
+    /// its files collection document.
+    #[non_exhaustive]
+    ChecksumMismatch { expected: String, actual: String },
 }
 
 /// An identifier for a file stored in a GridFS bucket.
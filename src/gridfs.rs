@@ -1,17 +1,14 @@
-// TODO(RUST-1395) Remove these allows.
-#![allow(dead_code, unused_variables)]
-
-mod download;
+mod checksum;
+pub(crate) mod download;
 pub mod options;
+mod revisions;
+mod typed;
+mod upload;
+mod verify;
 
-use core::task::{Context, Poll};
-use std::{
-    pin::Pin,
-    sync::{atomic::AtomicBool, Arc},
-};
+use std::sync::{atomic::AtomicBool, Arc};
 
-use serde::{Deserialize, Serialize};
-use tokio::io::ReadBuf;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use crate::{
     bson::{doc, oid::ObjectId, Bson, DateTime, Document, RawBinaryRef},
@@ -19,11 +16,15 @@ use crate::{
     cursor::Cursor,
     error::{ErrorKind, GridFsErrorKind, GridFsFileIdentifier, Result},
     options::{CollectionOptions, FindOptions, SelectionCriteria},
+    ClientSession,
     Collection,
     Database,
+    SessionCursor,
 };
 
 use options::*;
+pub use typed::TypedGridFsBucket;
+pub use upload::GridFsUploadStream;
 
 pub const DEFAULT_BUCKET_NAME: &str = "fs";
 pub const DEFAULT_CHUNK_SIZE_BYTES: u32 = 255 * 1024;
@@ -34,6 +35,7 @@ struct Chunk<'a> {
     #[serde(rename = "_id")]
     id: ObjectId,
     files_id: Bson,
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u32_as_i32")]
     n: u32,
     #[serde(borrow)]
     data: RawBinaryRef<'a>,
@@ -41,117 +43,102 @@ struct Chunk<'a> {
 
 /// A collection in which information about stored files is stored. There will be one files
 /// collection document per stored file.
+///
+/// The type of the `metadata` field defaults to [`Document`]. Files with a typed `metadata` field
+/// can be found and uploaded via [`GridFsBucket::typed_metadata`].
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct FilesCollectionDocument {
+pub struct FilesCollectionDocument<M = Document> {
     #[serde(rename = "_id")]
     pub id: Bson,
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
     pub length: u64,
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u32_as_i32")]
     pub chunk_size: u32,
     pub upload_date: DateTime,
     pub filename: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<Document>,
-}
-
-#[derive(Debug)]
-struct GridFsBucketInner {
-    options: GridFsBucketOptions,
-    files: Collection<FilesCollectionDocument>,
-    chunks: Collection<Chunk<'static>>,
-    created_indexes: AtomicBool,
+    pub metadata: Option<M>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<GridFsChecksum>,
 }
 
-/// Struct for storing GridFS managed files within a [`Database`].
-#[derive(Debug, Clone)]
-pub struct GridFsBucket {
-    inner: Arc<GridFsBucketInner>,
-}
+/// The result of checking the consistency of a [`GridFsBucket`] via [`GridFsBucket::verify`].
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct GridFsVerifyReport {
+    /// The chunks that have no corresponding files collection document, grouped by `files_id`.
+    pub orphaned_chunks: Vec<GridFsOrphanedChunks>,
 
-// TODO: RUST-1395 Add documentation and example code for this struct.
-pub struct GridFsUploadStream {
-    files_id: Bson,
+    /// The problems found with the chunks of files that have a files collection document.
+    pub file_errors: Vec<GridFsFileError>,
 }
 
-impl GridFsUploadStream {
-    /// Gets the file `id` for the stream.
-    pub fn files_id(&self) -> &Bson {
-        &self.files_id
-    }
-
-    /// Consumes the stream and uploads data in the stream to the server.
-    pub async fn finish(self) {
-        todo!()
-    }
-
-    /// Aborts the upload and discards the upload stream.
-    pub async fn abort(self) {
-        todo!()
+impl GridFsVerifyReport {
+    /// Whether no orphaned chunks or file errors were found.
+    pub fn is_consistent(&self) -> bool {
+        self.orphaned_chunks.is_empty() && self.file_errors.is_empty()
     }
 }
 
-impl tokio::io::AsyncWrite for GridFsUploadStream {
-    fn poll_write(
-        self: Pin<&mut Self>,
-        cx: &mut Context<'_>,
-        buf: &[u8],
-    ) -> Poll<tokio::io::Result<usize>> {
-        todo!()
-    }
-
-    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<tokio::io::Result<()>> {
-        todo!()
-    }
+/// Chunks in a [`GridFsBucket`] that share a `files_id` for which no files collection document
+/// exists.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct GridFsOrphanedChunks {
+    /// The `files_id` of the chunks.
+    pub files_id: Bson,
 
-    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<tokio::io::Result<()>> {
-        todo!()
-    }
+    /// The number of chunks with this `files_id`.
+    pub count: u64,
 }
 
-impl futures_util::AsyncWrite for GridFsUploadStream {
-    fn poll_write(
-        self: Pin<&mut Self>,
-        cx: &mut Context<'_>,
-        buf: &[u8],
-    ) -> Poll<core::result::Result<usize, futures_util::io::Error>> {
-        todo!()
-    }
-
-    fn poll_flush(
-        self: Pin<&mut Self>,
-        cx: &mut Context<'_>,
-    ) -> Poll<core::result::Result<(), futures_util::io::Error>> {
-        todo!()
-    }
+/// A problem found with the chunks of a file stored in a [`GridFsBucket`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct GridFsFileError {
+    /// The id of the file.
+    pub files_id: Bson,
+
+    /// The problem that was found. This is one of [`GridFsErrorKind::MissingChunk`],
+    /// [`GridFsErrorKind::DuplicateChunk`], [`GridFsErrorKind::WrongSizeChunk`], or
+    /// [`GridFsErrorKind::WrongNumberOfChunks`].
+    pub kind: GridFsErrorKind,
+}
 
-    fn poll_close(
-        self: Pin<&mut Self>,
-        cx: &mut Context<'_>,
-    ) -> Poll<core::result::Result<(), futures_util::io::Error>> {
-        todo!()
-    }
+/// The result of deleting orphaned chunks via [`GridFsBucket::collect_garbage`].
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct GridFsCollectGarbageResult {
+    /// The number of chunks that were deleted.
+    pub deleted_count: u64,
 }
 
-pub struct GridFsDownloadStream {
-    files_id: Bson,
+/// A checksum of the contents of a stored file, computed when the file was uploaded. See
+/// [`GridFsBucketOptions::checksum`] and [`GridFsUploadOptions::checksum`].
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub struct GridFsChecksum {
+    /// The algorithm used to compute the checksum.
+    pub algorithm: GridFsChecksumAlgorithm,
+
+    /// The hex-encoded digest of the file's contents.
+    pub digest: String,
 }
 
-impl GridFsDownloadStream {
-    /// Gets the file `id` for the stream.
-    pub fn files_id(&self) -> &Bson {
-        &self.files_id
-    }
+#[derive(Debug)]
+struct GridFsBucketInner {
+    options: GridFsBucketOptions,
+    files: Collection<FilesCollectionDocument>,
+    chunks: Collection<Chunk<'static>>,
+    created_indexes: AtomicBool,
 }
 
-impl tokio::io::AsyncRead for GridFsDownloadStream {
-    fn poll_read(
-        self: Pin<&mut Self>,
-        cx: &mut Context<'_>,
-        buf: &mut ReadBuf<'_>,
-    ) -> Poll<tokio::io::Result<()>> {
-        todo!()
-    }
+/// Struct for storing GridFS managed files within a [`Database`].
+#[derive(Debug, Clone)]
+pub struct GridFsBucket {
+    inner: Arc<GridFsBucketInner>,
 }
 
 impl GridFsBucket {
@@ -218,87 +205,26 @@ impl GridFsBucket {
             .unwrap_or(DEFAULT_CHUNK_SIZE_BYTES)
     }
 
+    /// Gets a view of the [`GridFsBucket`] in which the `metadata` field of each file is
+    /// serialized from and deserialized to the type `M`, similar to how
+    /// [`Collection::clone_with_type`] changes the type of a collection's documents.
+    pub fn typed_metadata<M>(&self) -> TypedGridFsBucket<M>
+    where
+        M: Serialize + DeserializeOwned + Unpin + Send + Sync,
+    {
+        TypedGridFsBucket::new(self.clone())
+    }
+
     /// Gets a handle to the files collection for the [`GridFsBucket`].
     fn files(&self) -> &Collection<FilesCollectionDocument> {
         &self.inner.files
     }
 
     /// Gets a handle to the chunks collection for the [`GridFsBucket`].
-    fn chunks(&self) -> &Collection<Chunk> {
+    fn chunks(&self) -> &Collection<Chunk<'static>> {
         &self.inner.chunks
     }
 
-    /// Opens a [`GridFsUploadStream`] that the application can write the contents of the file to.
-    /// The application provides a custom file id.
-    ///
-    /// Returns a [`GridFsUploadStream`] to which the application will write the contents.
-    pub async fn open_upload_stream_with_id(
-        &self,
-        id: Bson,
-        filename: String,
-        options: impl Into<Option<GridFsUploadOptions>>,
-    ) -> Result<GridFsUploadStream> {
-        todo!()
-    }
-
-    /// Uploads a user file to a GridFS bucket. The application supplies a custom file id. Uses the
-    /// `tokio` crate's `AsyncRead` trait for the `source`.
-    pub async fn upload_from_tokio_reader_with_id(
-        &self,
-        id: Bson,
-        filename: String,
-        source: impl tokio::io::AsyncRead,
-        options: impl Into<Option<GridFsUploadOptions>>,
-    ) {
-        todo!()
-    }
-
-    /// Uploads a user file to a GridFS bucket. The application supplies a custom file id. Uses the
-    /// `futures-0.3` crate's `AsyncRead` trait for the `source`.
-    pub async fn upload_from_futures_0_3_reader_with_id(
-        &self,
-        id: Bson,
-        filename: String,
-        source: impl futures_util::AsyncRead,
-        options: impl Into<Option<GridFsUploadOptions>>,
-    ) {
-        todo!()
-    }
-
-    /// Uploads a user file to a GridFS bucket. The driver generates a unique [`Bson::ObjectId`] for
-    /// the file id. Uses the `tokio` crate's `AsyncRead` trait for the `source`.
-    pub async fn upload_from_tokio_reader(
-        &self,
-        filename: String,
-        source: impl tokio::io::AsyncRead,
-        options: impl Into<Option<GridFsUploadOptions>>,
-    ) {
-        self.upload_from_tokio_reader_with_id(
-            Bson::ObjectId(ObjectId::new()),
-            filename,
-            source,
-            options,
-        )
-        .await
-    }
-
-    /// Uploads a user file to a GridFS bucket. The driver generates a unique [`Bson::ObjectId`] for
-    /// the file id. Uses the `futures-0.3` crate's `AsyncRead` trait for the `source`.
-    pub async fn upload_from_futures_0_3_reader(
-        &self,
-        filename: String,
-        source: impl futures_util::AsyncRead,
-        options: impl Into<Option<GridFsUploadOptions>>,
-    ) {
-        self.upload_from_futures_0_3_reader_with_id(
-            Bson::ObjectId(ObjectId::new()),
-            filename,
-            source,
-            options,
-        )
-        .await
-    }
-
     /// Opens a [`GridFsUploadStream`] that the application can write the contents of the file to.
     /// The driver generates a unique [`Bson::ObjectId`] for the file id.
     ///
@@ -312,35 +238,45 @@ impl GridFsBucket {
             .await
     }
 
-    /// Opens and returns a [`GridFsDownloadStream`] from which the application can read
-    /// the contents of the stored file specified by `id`.
-    pub async fn open_download_stream(&self, id: Bson) -> Result<GridFsDownloadStream> {
-        todo!()
+    /// Deletes the [`FilesCollectionDocument`] with the given `id `and its associated chunks from
+    /// this bucket.
+    pub async fn delete(&self, id: Bson) -> Result<()> {
+        self.delete_common(id, None).await
     }
 
-    /// Opens and returns a [`GridFsDownloadStream`] from which the application can read
-    /// the contents of the stored file specified by `filename` and the revision
-    /// in `options`.
-    pub async fn open_download_stream_by_name(
-        &self,
-        filename: String,
-        options: impl Into<Option<GridFsDownloadByNameOptions>>,
-    ) -> Result<GridFsDownloadStream> {
-        todo!()
+    /// Deletes the [`FilesCollectionDocument`] with the given `id `and its associated chunks from
+    /// this bucket using the provided session.
+    pub async fn delete_with_session(&self, id: Bson, session: &mut ClientSession) -> Result<()> {
+        self.delete_common(id, session).await
     }
 
-    /// Deletes the [`FilesCollectionDocument`] with the given `id `and its associated chunks from
-    /// this bucket.
-    pub async fn delete(&self, id: Bson) -> Result<()> {
-        let delete_result = self
-            .files()
-            .delete_one(doc! { "_id": id.clone() }, None)
-            .await?;
+    async fn delete_common(
+        &self,
+        id: Bson,
+        session: impl Into<Option<&mut ClientSession>>,
+    ) -> Result<()> {
+        let mut session = session.into();
+
+        let filter = doc! { "_id": id.clone() };
+        let delete_result = match session.as_deref_mut() {
+            Some(session) => {
+                self.files()
+                    .delete_one_with_session(filter, None, session)
+                    .await?
+            }
+            None => self.files().delete_one(filter, None).await?,
+        };
         // Delete chunks regardless of whether a file was found. This will remove any possibly
         // orphaned chunks.
-        self.chunks()
-            .delete_many(doc! { "files_id": id.clone() }, None)
-            .await?;
+        let filter = doc! { "files_id": id.clone() };
+        match session {
+            Some(session) => {
+                self.chunks()
+                    .delete_many_with_session(filter, None, session)
+                    .await?
+            }
+            None => self.chunks().delete_many(filter, None).await?,
+        };
 
         if delete_result.deleted_count == 0 {
             return Err(ErrorKind::GridFs(GridFsErrorKind::FileNotFound {
@@ -363,6 +299,20 @@ impl GridFsBucket {
         self.files().find(filter, find_options).await
     }
 
+    /// Finds and returns the [`FilesCollectionDocument`]s within this bucket that match the given
+    /// filter using the provided session.
+    pub async fn find_with_session(
+        &self,
+        filter: Document,
+        options: impl Into<Option<GridFsFindOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<SessionCursor<FilesCollectionDocument>> {
+        let find_options = options.into().map(FindOptions::from);
+        self.files()
+            .find_with_session(filter, find_options, session)
+            .await
+    }
+
     /// Renames the file with the given 'id' to the provided `new_filename`.
     pub async fn rename(&self, id: Bson, new_filename: impl AsRef<str>) -> Result<()> {
         self.files()
@@ -376,6 +326,26 @@ impl GridFsBucket {
         Ok(())
     }
 
+    /// Renames the file with the given 'id' to the provided `new_filename` using the provided
+    /// session.
+    pub async fn rename_with_session(
+        &self,
+        id: Bson,
+        new_filename: impl AsRef<str>,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        self.files()
+            .update_one_with_session(
+                doc! { "_id": id },
+                doc! { "$set": { "filename": new_filename.as_ref() } },
+                None,
+                session,
+            )
+            .await?;
+
+        Ok(())
+    }
+
     /// Drops all of the files and their associated chunks in this bucket.
     pub async fn drop(&self) -> Result<()> {
         self.files().drop(None).await?;
@@ -383,4 +353,17 @@ impl GridFsBucket {
 
         Ok(())
     }
+
+    /// Drops all of the files and their associated chunks in this bucket using the provided
+    /// session.
+    pub async fn drop_with_session(&self, session: &mut ClientSession) -> Result<()> {
+        self.files().drop_with_session(None, session).await?;
+        self.chunks().drop_with_session(None, session).await?;
+
+        Ok(())
+    }
+}
+
+fn into_io_error(error: crate::error::Error) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::Other, error)
 }
@@ -0,0 +1,47 @@
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha512};
+
+use super::{options::GridFsChecksumAlgorithm, GridFsChecksum};
+
+/// Computes the checksum of a file's contents incrementally as its chunks are written or read.
+pub(super) enum Hasher {
+    Sha256(Sha256),
+    Sha512(Sha512),
+    Sha1(Sha1),
+    Md5(Md5),
+}
+
+impl Hasher {
+    pub(super) fn new(algorithm: GridFsChecksumAlgorithm) -> Self {
+        match algorithm {
+            GridFsChecksumAlgorithm::Sha256 => Self::Sha256(Sha256::new()),
+            GridFsChecksumAlgorithm::Sha512 => Self::Sha512(Sha512::new()),
+            GridFsChecksumAlgorithm::Sha1 => Self::Sha1(Sha1::new()),
+            GridFsChecksumAlgorithm::Md5 => Self::Md5(Md5::new()),
+        }
+    }
+
+    pub(super) fn update(&mut self, bytes: &[u8]) {
+        match self {
+            Self::Sha256(hasher) => hasher.update(bytes),
+            Self::Sha512(hasher) => hasher.update(bytes),
+            Self::Sha1(hasher) => hasher.update(bytes),
+            Self::Md5(hasher) => hasher.update(bytes),
+        }
+    }
+
+    pub(super) fn finish(self) -> GridFsChecksum {
+        let (algorithm, digest) = match self {
+            Self::Sha256(hasher) => (GridFsChecksumAlgorithm::Sha256, hasher.finalize().to_vec()),
+            Self::Sha512(hasher) => (GridFsChecksumAlgorithm::Sha512, hasher.finalize().to_vec()),
+            Self::Sha1(hasher) => (GridFsChecksumAlgorithm::Sha1, hasher.finalize().to_vec()),
+            Self::Md5(hasher) => (GridFsChecksumAlgorithm::Md5, hasher.finalize().to_vec()),
+        };
+
+        GridFsChecksum {
+            algorithm,
+            digest: hex::encode(digest),
+        }
+    }
+}
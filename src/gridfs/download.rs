@@ -1,97 +1,316 @@
-use std::marker::Unpin;
+use std::{
+    collections::VecDeque,
+    io::SeekFrom,
+    marker::Unpin,
+    ops::{Bound, RangeBounds},
+    pin::Pin,
+    task::{Context, Poll},
+};
 
-use futures_util::io::{AsyncWrite, AsyncWriteExt};
+use futures_core::future::BoxFuture;
+use futures_util::{
+    future::{poll_fn, FutureExt},
+    io::{AsyncWrite, AsyncWriteExt},
+    ready,
+};
 
-use super::{options::GridFsDownloadByNameOptions, FilesCollectionDocument, GridFsBucket};
+use super::{
+    checksum::Hasher,
+    into_io_error,
+    options::GridFsDownloadByNameOptions,
+    revisions::revision_sort,
+    Chunk,
+    FilesCollectionDocument,
+    GridFsBucket,
+};
 use crate::{
-    bson::{doc, Bson},
-    error::{ErrorKind, GridFsErrorKind, GridFsFileIdentifier, Result},
+    bson::{doc, Bson, Document, RawDocumentBuf},
+    cursor::{BatchValue, CursorStream},
+    error::{Error, ErrorKind, GridFsErrorKind, GridFsFileIdentifier, Result},
     options::{FindOneOptions, FindOptions},
+    ClientSession,
+    Cursor,
+    SessionCursor,
 };
 
 impl GridFsBucket {
-    /// Downloads the contents of the stored file specified by `id` and writes
-    /// the contents to the `destination`.
-    pub async fn download_to_futures_0_3_writer<T>(&self, id: Bson, destination: T) -> Result<()>
-    where
-        T: AsyncWrite + Unpin,
-    {
-        let options = FindOneOptions::builder()
-            .read_concern(self.read_concern().cloned())
-            .selection_criteria(self.selection_criteria().cloned())
-            .build();
+    /// Finds a single document in the files collection, using the session if one is provided.
+    async fn find_one_file(
+        &self,
+        filter: Document,
+        options: impl Into<Option<FindOneOptions>>,
+        session: Option<&mut ClientSession>,
+    ) -> Result<Option<FilesCollectionDocument>> {
+        match session {
+            Some(session) => {
+                self.files()
+                    .find_one_with_session(filter, options, session)
+                    .await
+            }
+            None => self.files().find_one(filter, options).await,
+        }
+    }
 
-        let file = match self
-            .inner
-            .files
-            .find_one(doc! { "_id": id.clone() }, options)
+    /// Finds the files collection document for the file with the given `id`.
+    async fn find_file_by_id(
+        &self,
+        id: Bson,
+        session: impl Into<Option<&mut ClientSession>>,
+    ) -> Result<FilesCollectionDocument> {
+        match self
+            .find_one_file(doc! { "_id": id.clone() }, None, session.into())
             .await?
         {
-            Some(fcd) => fcd,
-            None => {
-                return Err(ErrorKind::GridFs(GridFsErrorKind::FileNotFound {
-                    identifier: GridFsFileIdentifier::Id(id),
-                })
-                .into());
-            }
-        };
-        self.download_to_writer_common(file, destination).await
+            Some(fcd) => Ok(fcd),
+            None => Err(ErrorKind::GridFs(GridFsErrorKind::FileNotFound {
+                identifier: GridFsFileIdentifier::Id(id),
+            })
+            .into()),
+        }
     }
 
-    /// Downloads the contents of the stored file specified by `filename` and writes the contents to
-    /// the `destination`. If there are multiple files with the same filename, the `revision` in the
-    /// options provided is used to determine which one to download. If no `revision` is specified,
-    /// the most recent file with the given filename is chosen.
-    pub async fn download_to_futures_0_3_writer_by_name<T>(
+    /// Finds the files collection document for the given revision of the file with the given
+    /// `filename`.
+    async fn find_file_by_name(
         &self,
-        filename: impl AsRef<str>,
-        destination: T,
-        options: impl Into<Option<GridFsDownloadByNameOptions>>,
-    ) -> Result<()>
-    where
-        T: AsyncWrite + Unpin,
-    {
-        let revision = options.into().and_then(|opts| opts.revision).unwrap_or(-1);
+        filename: &str,
+        options: Option<GridFsDownloadByNameOptions>,
+        session: impl Into<Option<&mut ClientSession>>,
+    ) -> Result<FilesCollectionDocument> {
+        let mut session = session.into();
+        let revision = options.and_then(|opts| opts.revision).unwrap_or(-1);
         let (sort, skip) = if revision >= 0 {
             (1, revision)
         } else {
             (-1, -revision - 1)
         };
         let options = FindOneOptions::builder()
-            .sort(doc! { "uploadDate": sort })
+            .sort(revision_sort(sort))
             .skip(skip as u64)
-            .read_concern(self.read_concern().cloned())
-            .selection_criteria(self.selection_criteria().cloned())
             .build();
 
-        let file = match self
-            .files()
-            .find_one(doc! { "filename": filename.as_ref() }, options)
+        match self
+            .find_one_file(
+                doc! { "filename": filename },
+                options,
+                session.as_deref_mut(),
+            )
             .await?
         {
-            Some(fcd) => fcd,
+            Some(fcd) => Ok(fcd),
             None => {
                 if self
-                    .files()
-                    .find_one(doc! { "filename": filename.as_ref() }, None)
+                    .find_one_file(doc! { "filename": filename }, None, session)
                     .await?
                     .is_some()
                 {
-                    return Err(
-                        ErrorKind::GridFs(GridFsErrorKind::RevisionNotFound { revision }).into(),
-                    );
+                    Err(ErrorKind::GridFs(GridFsErrorKind::RevisionNotFound { revision }).into())
                 } else {
-                    return Err(ErrorKind::GridFs(GridFsErrorKind::FileNotFound {
-                        identifier: GridFsFileIdentifier::Filename(filename.as_ref().into()),
+                    Err(ErrorKind::GridFs(GridFsErrorKind::FileNotFound {
+                        identifier: GridFsFileIdentifier::Filename(filename.into()),
                     })
-                    .into());
+                    .into())
                 }
             }
-        };
+        }
+    }
 
+    /// Opens and returns a [`GridFsDownloadStream`] from which the application can read
+    /// the contents of the stored file specified by `id`.
+    pub async fn open_download_stream(&self, id: Bson) -> Result<GridFsDownloadStream> {
+        let file = self.find_file_by_id(id, None).await?;
+        GridFsDownloadStream::new(file, self, ..).await
+    }
+
+    /// Opens and returns a [`GridFsDownloadStream`] from which the application can read the bytes
+    /// within `range` of the stored file specified by `id`. Only the chunks containing the bytes
+    /// within the range are retrieved from the server.
+    ///
+    /// The end of the range is clamped to the length of the file. An error is returned if the
+    /// start of the range is past the end of the file.
+    pub async fn open_download_stream_range(
+        &self,
+        id: Bson,
+        range: impl RangeBounds<u64>,
+    ) -> Result<GridFsDownloadStream> {
+        let file = self.find_file_by_id(id, None).await?;
+        GridFsDownloadStream::new(file, self, range).await
+    }
+
+    /// Opens and returns a [`GridFsDownloadStream`] from which the application can read
+    /// the contents of the stored file specified by `filename` and the revision
+    /// in `options`.
+    pub async fn open_download_stream_by_name(
+        &self,
+        filename: String,
+        options: impl Into<Option<GridFsDownloadByNameOptions>>,
+    ) -> Result<GridFsDownloadStream> {
+        let file = self
+            .find_file_by_name(&filename, options.into(), None)
+            .await?;
+        GridFsDownloadStream::new(file, self, ..).await
+    }
+
+    /// Downloads the contents of the stored file specified by `id` and writes
+    /// the contents to the `destination`.
+    pub async fn download_to_futures_0_3_writer<T>(&self, id: Bson, destination: T) -> Result<()>
+    where
+        T: AsyncWrite + Unpin,
+    {
+        let file = self.find_file_by_id(id, None).await?;
+        self.download_to_writer_common(file, destination).await
+    }
+
+    /// Downloads the contents of the stored file specified by `filename` and writes the contents to
+    /// the `destination`. If there are multiple files with the same filename, the `revision` in the
+    /// options provided is used to determine which one to download. If no `revision` is specified,
+    /// the most recent file with the given filename is chosen.
+    pub async fn download_to_futures_0_3_writer_by_name<T>(
+        &self,
+        filename: impl AsRef<str>,
+        destination: T,
+        options: impl Into<Option<GridFsDownloadByNameOptions>>,
+    ) -> Result<()>
+    where
+        T: AsyncWrite + Unpin,
+    {
+        let file = self
+            .find_file_by_name(filename.as_ref(), options.into(), None)
+            .await?;
         self.download_to_writer_common(file, destination).await
     }
 
+    /// Downloads the contents of the stored file specified by `id` and writes
+    /// the contents to the `destination`. Uses the `tokio` crate's `AsyncWrite` trait for the
+    /// `destination`.
+    pub async fn download_to_tokio_writer<T>(&self, id: Bson, destination: T) -> Result<()>
+    where
+        T: tokio::io::AsyncWrite + Unpin,
+    {
+        let file = self.find_file_by_id(id, None).await?;
+        self.download_to_tokio_writer_common(file, destination)
+            .await
+    }
+
+    /// Downloads the contents of the stored file specified by `filename` and writes the contents to
+    /// the `destination`. If there are multiple files with the same filename, the `revision` in the
+    /// options provided is used to determine which one to download. If no `revision` is specified,
+    /// the most recent file with the given filename is chosen. Uses the `tokio` crate's
+    /// `AsyncWrite` trait for the `destination`.
+    pub async fn download_to_tokio_writer_by_name<T>(
+        &self,
+        filename: impl AsRef<str>,
+        destination: T,
+        options: impl Into<Option<GridFsDownloadByNameOptions>>,
+    ) -> Result<()>
+    where
+        T: tokio::io::AsyncWrite + Unpin,
+    {
+        let file = self
+            .find_file_by_name(filename.as_ref(), options.into(), None)
+            .await?;
+        self.download_to_tokio_writer_common(file, destination)
+            .await
+    }
+
+    /// Downloads the contents of the stored file specified by `id` and writes the contents to the
+    /// `destination`, reading the file and its chunks using the provided session.
+    pub async fn download_to_futures_0_3_writer_with_session<T>(
+        &self,
+        id: Bson,
+        mut destination: T,
+        session: &mut ClientSession,
+    ) -> Result<()>
+    where
+        T: AsyncWrite + Unpin,
+    {
+        let file = self.find_file_by_id(id, &mut *session).await?;
+        let mut chunks = SessionChunks::new(file, self, session).await?;
+        while let Some(bytes) = chunks.next(session).await? {
+            destination.write_all(&bytes).await?;
+        }
+        destination.flush().await?;
+
+        Ok(())
+    }
+
+    /// Downloads the contents of the stored file specified by `filename` and writes the contents to
+    /// the `destination`, reading the file and its chunks using the provided session. See
+    /// [`GridFsBucket::download_to_futures_0_3_writer_by_name`] for how the revision is chosen.
+    pub async fn download_to_futures_0_3_writer_by_name_with_session<T>(
+        &self,
+        filename: impl AsRef<str>,
+        mut destination: T,
+        options: impl Into<Option<GridFsDownloadByNameOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<()>
+    where
+        T: AsyncWrite + Unpin,
+    {
+        let file = self
+            .find_file_by_name(filename.as_ref(), options.into(), &mut *session)
+            .await?;
+        let mut chunks = SessionChunks::new(file, self, session).await?;
+        while let Some(bytes) = chunks.next(session).await? {
+            destination.write_all(&bytes).await?;
+        }
+        destination.flush().await?;
+
+        Ok(())
+    }
+
+    /// Downloads the contents of the stored file specified by `id` and writes the contents to the
+    /// `destination`, reading the file and its chunks using the provided session. Uses the `tokio`
+    /// crate's `AsyncWrite` trait for the `destination`.
+    pub async fn download_to_tokio_writer_with_session<T>(
+        &self,
+        id: Bson,
+        mut destination: T,
+        session: &mut ClientSession,
+    ) -> Result<()>
+    where
+        T: tokio::io::AsyncWrite + Unpin,
+    {
+        use tokio::io::AsyncWriteExt;
+
+        let file = self.find_file_by_id(id, &mut *session).await?;
+        let mut chunks = SessionChunks::new(file, self, session).await?;
+        while let Some(bytes) = chunks.next(session).await? {
+            destination.write_all(&bytes).await?;
+        }
+        destination.flush().await?;
+
+        Ok(())
+    }
+
+    /// Downloads the contents of the stored file specified by `filename` and writes the contents to
+    /// the `destination`, reading the file and its chunks using the provided session. Uses the
+    /// `tokio` crate's `AsyncWrite` trait for the `destination`. See
+    /// [`GridFsBucket::download_to_tokio_writer_by_name`] for how the revision is chosen.
+    pub async fn download_to_tokio_writer_by_name_with_session<T>(
+        &self,
+        filename: impl AsRef<str>,
+        mut destination: T,
+        options: impl Into<Option<GridFsDownloadByNameOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<()>
+    where
+        T: tokio::io::AsyncWrite + Unpin,
+    {
+        use tokio::io::AsyncWriteExt;
+
+        let file = self
+            .find_file_by_name(filename.as_ref(), options.into(), &mut *session)
+            .await?;
+        let mut chunks = SessionChunks::new(file, self, session).await?;
+        while let Some(bytes) = chunks.next(session).await? {
+            destination.write_all(&bytes).await?;
+        }
+        destination.flush().await?;
+
+        Ok(())
+    }
+
     async fn download_to_writer_common<T>(
         &self,
         file: FilesCollectionDocument,
@@ -100,55 +319,576 @@ impl GridFsBucket {
     where
         T: AsyncWrite + Unpin,
     {
-        let total_bytes_expected = file.length;
-        let chunk_size = file.chunk_size as u64;
+        let mut stream = GridFsDownloadStream::new(file, self, ..).await?;
+        while let Some(bytes) = poll_fn(|cx| stream.poll_next_chunk(cx)).await? {
+            destination.write_all(&bytes).await?;
+        }
+        destination.flush().await?;
+
+        Ok(())
+    }
+
+    async fn download_to_tokio_writer_common<T>(
+        &self,
+        file: FilesCollectionDocument,
+        mut destination: T,
+    ) -> Result<()>
+    where
+        T: tokio::io::AsyncWrite + Unpin,
+    {
+        use tokio::io::AsyncWriteExt;
 
-        if total_bytes_expected == 0 {
-            return Ok(());
+        let mut stream = GridFsDownloadStream::new(file, self, ..).await?;
+        while let Some(bytes) = poll_fn(|cx| stream.poll_next_chunk(cx)).await? {
+            destination.write_all(&bytes).await?;
         }
+        destination.flush().await?;
+
+        Ok(())
+    }
+}
+
+/// A stream from which a file stored in a GridFS bucket can be read. Instances of this type can
+/// be created using [`GridFsBucket::open_download_stream`],
+/// [`GridFsBucket::open_download_stream_by_name`], or
+/// [`GridFsBucket::open_download_stream_range`].
+///
+/// The file's chunks are read from the bucket's chunks collection in order and are checked
+/// against the length and chunk size recorded in the file's [`FilesCollectionDocument`]. While
+/// the chunks in the current batch are being read, the next batch is requested from the server
+/// so that large files can be streamed without waiting on a round trip for each batch.
+///
+/// If the file was uploaded with a checksum and the whole file is read in order, the checksum of
+/// the chunks is verified before the contents of the final chunk are returned. A
+/// [`GridFsErrorKind::ChecksumMismatch`] error is returned if it does not match. The checksum is
+/// not verified for range reads that do not cover the whole file or after seeking outside of the
+/// current chunk.
+///
+/// The stream implements the `AsyncSeek` traits from both `tokio` and `futures-0.3`. Positions
+/// are relative to the start of the range of the file being read. Seeking outside of the chunk
+/// currently being read discards any retrieved chunks, and a new query for the chunks at the
+/// new position is performed on the next read.
+///
+/// Download streams do not use a session. To download a file within a session, use
+/// [`GridFsBucket::download_to_tokio_writer_with_session`] or
+/// [`GridFsBucket::download_to_futures_0_3_writer_with_session`].
+pub struct GridFsDownloadStream {
+    bucket: GridFsBucket,
+    file: FilesCollectionDocument,
+
+    /// The bounds of the range of the file being read, as offsets into the file.
+    range_start: u64,
+    range_end: u64,
+
+    /// The offset into the file of the next byte to be read.
+    position: u64,
+
+    /// The cursor over the file's chunks.
+    cursor: ChunkCursor,
+
+    /// The chunks in the batch currently being read.
+    batch: VecDeque<RawDocumentBuf>,
+
+    /// The chunks in the next batch, if it has already been retrieved from the server.
+    prefetched: VecDeque<RawDocumentBuf>,
+
+    /// An error encountered while retrieving chunks from the server. This is returned once the
+    /// chunks retrieved before the error occurred have been read.
+    error: Option<Error>,
+
+    /// The contents of the most recently read chunk and its offset into the file.
+    buffer: Vec<u8>,
+    buffer_start: u64,
+
+    /// The number of the next chunk to be read from the cursor.
+    n: u32,
+
+    /// The number of the chunk following the last chunk in the range.
+    end_n: u32,
 
+    /// Computes the checksum of the file's contents as its chunks are read, if the file has a
+    /// stored checksum and the stream reads the whole file in order.
+    hasher: Option<Hasher>,
+}
+
+enum ChunkCursor {
+    /// The query for the chunks starting at the current position is in progress.
+    Opening(BoxFuture<'static, Result<Cursor<RawDocumentBuf>>>),
+
+    /// The chunks are being read from the cursor.
+    Open(Box<Cursor<RawDocumentBuf>>),
+
+    /// The cursor was exhausted, or no more chunks need to be read.
+    Closed,
+}
+
+impl GridFsDownloadStream {
+    async fn new(
+        file: FilesCollectionDocument,
+        bucket: &GridFsBucket,
+        range: impl RangeBounds<u64>,
+    ) -> Result<Self> {
+        if file.length > 0 && file.chunk_size == 0 {
+            return Err(ErrorKind::InvalidResponse {
+                message: format!(
+                    "files collection document for file {} has a chunk size of 0",
+                    file.id
+                ),
+            }
+            .into());
+        }
+
+        let range_start = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let range_end = match range.end_bound() {
+            Bound::Included(end) => end.saturating_add(1),
+            Bound::Excluded(end) => *end,
+            Bound::Unbounded => file.length,
+        };
+        if range_start > range_end || range_start > file.length {
+            return Err(Error::invalid_argument(format!(
+                "invalid range {}..{} for file {} of length {}",
+                range_start, range_end, file.id, file.length
+            )));
+        }
+        let range_end = std::cmp::min(range_end, file.length);
+
+        let mut stream = Self {
+            bucket: bucket.clone(),
+            file,
+            range_start,
+            range_end,
+            position: range_start,
+            cursor: ChunkCursor::Closed,
+            batch: VecDeque::new(),
+            prefetched: VecDeque::new(),
+            error: None,
+            buffer: Vec::new(),
+            buffer_start: 0,
+            n: 0,
+            end_n: 0,
+            hasher: None,
+        };
+        stream.end_n = stream.chunk_number(range_end.saturating_sub(1)) + 1;
+        stream.reset_to_position();
+        if range_start == 0 && range_end == stream.file.length {
+            stream.hasher = stream
+                .file
+                .checksum
+                .as_ref()
+                .map(|checksum| Hasher::new(checksum.algorithm));
+            // No chunks will be read for an empty file.
+            if stream.file.length == 0 {
+                check_checksum(&stream.file, stream.hasher.take())?;
+            }
+        }
+        if let ChunkCursor::Opening(future) = stream.cursor {
+            stream.cursor = ChunkCursor::Open(Box::new(future.await?));
+        }
+
+        Ok(stream)
+    }
+
+    /// Gets the file `id` for the stream.
+    pub fn files_id(&self) -> &Bson {
+        &self.file.id
+    }
+
+    /// Gets the [`FilesCollectionDocument`] for the file being read.
+    pub fn file(&self) -> &FilesCollectionDocument {
+        &self.file
+    }
+
+    /// The number of the chunk that contains the byte at the given offset into the file.
+    fn chunk_number(&self, offset: u64) -> u32 {
+        match self.file.chunk_size {
+            0 => 0,
+            chunk_size => (offset / chunk_size as u64) as u32,
+        }
+    }
+
+    /// Discards all retrieved chunks and starts a query for the chunks from the current position
+    /// to the end of the range.
+    fn reset_to_position(&mut self) {
+        // The checksum can only be computed when the chunks are read in order from the start.
+        self.hasher = None;
+        self.batch.clear();
+        self.prefetched.clear();
+        self.error = None;
+        self.buffer.clear();
+        self.buffer_start = 0;
+
+        if self.position >= self.range_end {
+            self.n = self.end_n;
+            self.cursor = ChunkCursor::Closed;
+            return;
+        }
+
+        self.n = self.chunk_number(self.position);
         let options = FindOptions::builder()
             .sort(doc! { "n": 1 })
-            .read_concern(self.read_concern().cloned())
-            .selection_criteria(self.selection_criteria().cloned())
+            .read_concern(self.bucket.read_concern().cloned())
+            .selection_criteria(self.bucket.selection_criteria().cloned())
             .build();
-        let mut cursor = self
-            .chunks()
-            .find(doc! { "files_id": &file.id }, options)
-            .await?;
+        let filter = doc! {
+            "files_id": self.file.id.clone(),
+            "n": { "$gte": self.n, "$lt": self.end_n },
+        };
+        let chunks = self.bucket.chunks().clone_with_type::<RawDocumentBuf>();
+        self.cursor =
+            ChunkCursor::Opening(async move { chunks.find(filter, options).await }.boxed());
+    }
+
+    /// Retrieves the next batch of chunks from the cursor into `prefetched`. Returns immediately
+    /// if the next batch has already been retrieved or if the cursor has been exhausted.
+    fn poll_prefetch(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        if let Some(ref error) = self.error {
+            return Poll::Ready(Err(error.clone()));
+        }
+        if !self.prefetched.is_empty() {
+            return Poll::Ready(Ok(()));
+        }
+
+        loop {
+            let result = match self.cursor {
+                ChunkCursor::Opening(ref mut future) => match ready!(future.poll_unpin(cx)) {
+                    Ok(cursor) => {
+                        self.cursor = ChunkCursor::Open(Box::new(cursor));
+                        continue;
+                    }
+                    Err(error) => Err(error),
+                },
+                ChunkCursor::Open(ref mut cursor) => ready!(cursor.poll_next_in_batch(cx)),
+                ChunkCursor::Closed => return Poll::Ready(Ok(())),
+            };
+
+            match result {
+                Ok(BatchValue::Some { doc, is_last }) => {
+                    self.prefetched.push_back(doc);
+                    if is_last {
+                        return Poll::Ready(Ok(()));
+                    }
+                }
+                // A getMore has been started and will be polled on the next iteration.
+                Ok(BatchValue::Empty) => continue,
+                Ok(BatchValue::Exhausted) => {
+                    self.cursor = ChunkCursor::Closed;
+                    return Poll::Ready(Ok(()));
+                }
+                Err(error) => {
+                    self.cursor = ChunkCursor::Closed;
+                    self.error = Some(error.clone());
+                    return Poll::Ready(Err(error));
+                }
+            }
+        }
+    }
+
+    /// Reads and validates the next chunk in the range, returning its contents. Returns `None`
+    /// once all of the chunks in the range have been read.
+    pub(super) fn poll_next_chunk(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<Vec<u8>>>> {
+        if self.n == self.end_n {
+            self.cursor = ChunkCursor::Closed;
+            return Poll::Ready(Ok(None));
+        }
+
+        if self.batch.is_empty() {
+            ready!(self.poll_prefetch(cx))?;
+            std::mem::swap(&mut self.batch, &mut self.prefetched);
+        }
+        // Start retrieving the next batch while the chunks in this one are read. Any error will
+        // be returned once this batch has been read.
+        let _ = self.poll_prefetch(cx);
+
+        let doc = self.batch.pop_front();
+        let bytes = check_chunk(&self.file, self.n, self.end_n, doc)?;
+        if let Some(ref mut hasher) = self.hasher {
+            hasher.update(&bytes);
+        }
+        self.n += 1;
+        // The contents of the final chunk are only returned if the checksum matches.
+        if self.n == self.end_n {
+            check_checksum(&self.file, self.hasher.take())?;
+        }
+        Poll::Ready(Ok(Some(bytes)))
+    }
+
+    /// Returns the unread bytes in the range from the current chunk, reading the next chunk if the
+    /// current one has been fully consumed. An empty slice is returned once the end of the range
+    /// has been reached.
+    fn poll_fill_buf_inner(&mut self, cx: &mut Context<'_>) -> Poll<Result<&[u8]>> {
+        if self.position >= self.range_end {
+            return Poll::Ready(Ok(&[]));
+        }
 
-        let mut n = 0;
-        while cursor.advance().await? {
-            let chunk = cursor.deserialize_current()?;
-            if chunk.n != n {
-                return Err(ErrorKind::GridFs(GridFsErrorKind::MissingChunk { n }).into());
+        while self.position >= self.buffer_start + self.buffer.len() as u64 {
+            let chunk_start = self.n as u64 * self.file.chunk_size as u64;
+            match ready!(self.poll_next_chunk(cx))? {
+                Some(bytes) => {
+                    self.buffer = bytes;
+                    self.buffer_start = chunk_start;
+                }
+                None => return Poll::Ready(Ok(&[])),
             }
+        }
+        let _ = self.poll_prefetch(cx);
 
-            let chunk_length = chunk.data.bytes.len();
-            let expected_length =
-                std::cmp::min(total_bytes_expected - chunk_size * n as u64, chunk_size);
-            if chunk_length as u64 != expected_length {
-                return Err(ErrorKind::GridFs(GridFsErrorKind::WrongSizeChunk {
-                    actual_size: chunk_length as u32,
-                    expected_size: expected_length as u32,
-                })
-                .into());
+        let start = (self.position - self.buffer_start) as usize;
+        let end = std::cmp::min(self.range_end - self.buffer_start, self.buffer.len() as u64);
+        Poll::Ready(Ok(&self.buffer[start..end as usize]))
+    }
+
+    fn consume_inner(&mut self, amt: usize) {
+        self.position = std::cmp::min(self.position + amt as u64, self.range_end);
+    }
+
+    /// Moves the position of the stream. If the new position is outside of the chunk currently
+    /// being read, the retrieved chunks are discarded and the chunks starting at the new position
+    /// will be queried for on the next read.
+    fn seek_inner(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let base = match pos {
+            SeekFrom::Start(offset) => Some(self.range_start.saturating_add(offset)),
+            SeekFrom::End(offset) => offset_position(self.range_end, offset),
+            SeekFrom::Current(offset) => offset_position(self.position, offset),
+        };
+        let position = match base {
+            Some(position) if position >= self.range_start => position,
+            _ => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "invalid seek to a position before the start of the stream",
+                ))
             }
+        };
 
-            destination.write_all(chunk.data.bytes).await?;
-            n += 1;
+        let in_buffer = position >= self.buffer_start
+            && position < self.buffer_start + self.buffer.len() as u64;
+        self.position = position;
+        if !in_buffer {
+            self.reset_to_position();
         }
 
-        let expected_n =
-            total_bytes_expected / chunk_size + u64::from(total_bytes_expected % chunk_size != 0);
-        if (n as u64) != expected_n {
+        Ok(self.position - self.range_start)
+    }
+}
+
+/// Deserializes and validates chunk `n` of the given file, returning its contents. `end_n` is the
+/// number of chunks expected to be read.
+fn check_chunk(
+    file: &FilesCollectionDocument,
+    n: u32,
+    end_n: u32,
+    doc: Option<RawDocumentBuf>,
+) -> Result<Vec<u8>> {
+    let doc = match doc {
+        Some(doc) => doc,
+        None => {
             return Err(ErrorKind::GridFs(GridFsErrorKind::WrongNumberOfChunks {
                 actual_number: n,
-                expected_number: expected_n as u32,
+                expected_number: end_n,
             })
+            .into())
+        }
+    };
+    let chunk: Chunk = bson::from_slice(doc.as_bytes())?;
+
+    if chunk.n != n {
+        return Err(ErrorKind::GridFs(GridFsErrorKind::MissingChunk { n }).into());
+    }
+
+    let chunk_size = file.chunk_size as u64;
+    let expected_length = std::cmp::min(file.length - chunk_size * n as u64, chunk_size);
+    if chunk.data.bytes.len() as u64 != expected_length {
+        return Err(ErrorKind::GridFs(GridFsErrorKind::WrongSizeChunk {
+            actual_size: chunk.data.bytes.len() as u32,
+            expected_size: expected_length as u32,
+        })
+        .into());
+    }
+
+    Ok(chunk.data.bytes.to_vec())
+}
+
+/// Compares the checksum computed by `hasher` against the checksum stored in the files collection
+/// document, if it is being computed.
+fn check_checksum(file: &FilesCollectionDocument, hasher: Option<Hasher>) -> Result<()> {
+    let (hasher, expected) = match (hasher, file.checksum.as_ref()) {
+        (Some(hasher), Some(expected)) => (hasher, expected),
+        _ => return Ok(()),
+    };
+
+    let actual = hasher.finish();
+    if !actual.digest.eq_ignore_ascii_case(&expected.digest) {
+        return Err(ErrorKind::GridFs(GridFsErrorKind::ChecksumMismatch {
+            expected: expected.digest.clone(),
+            actual: actual.digest,
+        })
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Reads and validates the chunks of a file in order using a session.
+struct SessionChunks {
+    file: FilesCollectionDocument,
+    cursor: SessionCursor<RawDocumentBuf>,
+    n: u32,
+    total_n: u32,
+    hasher: Option<Hasher>,
+}
+
+impl SessionChunks {
+    async fn new(
+        file: FilesCollectionDocument,
+        bucket: &GridFsBucket,
+        session: &mut ClientSession,
+    ) -> Result<Self> {
+        if file.length > 0 && file.chunk_size == 0 {
+            return Err(ErrorKind::InvalidResponse {
+                message: format!(
+                    "files collection document for file {} has a chunk size of 0",
+                    file.id
+                ),
+            }
             .into());
         }
 
-        Ok(())
+        let total_n = match file.chunk_size {
+            0 => 0,
+            chunk_size => ((file.length + chunk_size as u64 - 1) / chunk_size as u64) as u32,
+        };
+        let options = FindOptions::builder().sort(doc! { "n": 1 }).build();
+        let cursor = bucket
+            .chunks()
+            .clone_with_type::<RawDocumentBuf>()
+            .find_with_session(doc! { "files_id": file.id.clone() }, options, session)
+            .await?;
+        let hasher = file
+            .checksum
+            .as_ref()
+            .map(|checksum| Hasher::new(checksum.algorithm));
+
+        let mut chunks = Self {
+            file,
+            cursor,
+            n: 0,
+            total_n,
+            hasher,
+        };
+        if chunks.total_n == 0 {
+            check_checksum(&chunks.file, chunks.hasher.take())?;
+        }
+
+        Ok(chunks)
+    }
+
+    async fn next(&mut self, session: &mut ClientSession) -> Result<Option<Vec<u8>>> {
+        if self.n == self.total_n {
+            return Ok(None);
+        }
+
+        let doc = self.cursor.next(session).await.transpose()?;
+        let bytes = check_chunk(&self.file, self.n, self.total_n, doc)?;
+        if let Some(ref mut hasher) = self.hasher {
+            hasher.update(&bytes);
+        }
+        self.n += 1;
+        if self.n == self.total_n {
+            check_checksum(&self.file, self.hasher.take())?;
+        }
+
+        Ok(Some(bytes))
+    }
+}
+
+fn offset_position(position: u64, offset: i64) -> Option<u64> {
+    if offset >= 0 {
+        position.checked_add(offset as u64)
+    } else {
+        position.checked_sub(offset.unsigned_abs())
+    }
+}
+
+impl tokio::io::AsyncRead for GridFsDownloadStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<tokio::io::Result<()>> {
+        let stream = self.get_mut();
+        let bytes = ready!(stream.poll_fill_buf_inner(cx)).map_err(into_io_error)?;
+        let amt = std::cmp::min(bytes.len(), buf.remaining());
+        buf.put_slice(&bytes[..amt]);
+        stream.consume_inner(amt);
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl tokio::io::AsyncBufRead for GridFsDownloadStream {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<tokio::io::Result<&[u8]>> {
+        self.get_mut()
+            .poll_fill_buf_inner(cx)
+            .map_err(into_io_error)
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.get_mut().consume_inner(amt)
+    }
+}
+
+impl tokio::io::AsyncSeek for GridFsDownloadStream {
+    fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> tokio::io::Result<()> {
+        self.get_mut().seek_inner(position).map(|_| ())
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<tokio::io::Result<u64>> {
+        Poll::Ready(Ok(self.position - self.range_start))
+    }
+}
+
+impl futures_util::AsyncRead for GridFsDownloadStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<core::result::Result<usize, futures_util::io::Error>> {
+        let stream = self.get_mut();
+        let bytes = ready!(stream.poll_fill_buf_inner(cx)).map_err(into_io_error)?;
+        let amt = std::cmp::min(bytes.len(), buf.len());
+        buf[..amt].copy_from_slice(&bytes[..amt]);
+        stream.consume_inner(amt);
+        Poll::Ready(Ok(amt))
+    }
+}
+
+impl futures_util::AsyncBufRead for GridFsDownloadStream {
+    fn poll_fill_buf(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<core::result::Result<&[u8], futures_util::io::Error>> {
+        self.get_mut()
+            .poll_fill_buf_inner(cx)
+            .map_err(into_io_error)
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.get_mut().consume_inner(amt)
+    }
+}
+
+impl futures_util::AsyncSeek for GridFsDownloadStream {
+    fn poll_seek(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        position: SeekFrom,
+    ) -> Poll<core::result::Result<u64, futures_util::io::Error>> {
+        Poll::Ready(self.get_mut().seek_inner(position))
     }
 }
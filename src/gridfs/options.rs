@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
 
 use crate::{
@@ -27,6 +27,11 @@ pub struct GridFsBucketOptions {
 
     /// The selection criteria. Defaults to the selection criteria of the database.
     pub selection_criteria: Option<SelectionCriteria>,
+
+    /// The algorithm used to compute a checksum of the contents of each file uploaded to the
+    /// bucket. The checksum is stored in the file's `FilesCollectionDocument` and is verified when
+    /// the file is downloaded. Defaults to not computing a checksum.
+    pub checksum: Option<GridFsChecksumAlgorithm>,
 }
 
 /// Contains the options for creating a [`GridFsUploadStream`] to upload a file to a
@@ -42,6 +47,30 @@ pub struct GridFsUploadOptions {
 
     /// User data for the 'metadata' field of the files collection document.
     pub metadata: Option<Document>,
+
+    /// The algorithm used to compute a checksum of the contents of this file. Defaults to the
+    /// `checksum` specified in the [`GridFsBucketOptions`].
+    #[builder(default)]
+    pub checksum: Option<GridFsChecksumAlgorithm>,
+}
+
+/// The hash algorithms that can be used to compute a checksum of the contents of a file stored in
+/// a [`GridFsBucket`].
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum GridFsChecksumAlgorithm {
+    /// SHA-256.
+    Sha256,
+
+    /// SHA-512.
+    Sha512,
+
+    /// SHA-1. Not recommended for detecting deliberate tampering.
+    Sha1,
+
+    /// MD5. Not recommended for detecting deliberate tampering.
+    Md5,
 }
 
 /// Contains the options for creating a [`GridFsDownloadStream`] to retrieve a stored file
@@ -102,3 +131,15 @@ impl From<GridFsFindOptions> for FindOptions {
         }
     }
 }
+
+/// Contains the options for collecting orphaned chunks via
+/// [`GridFsBucket::collect_garbage`](crate::gridfs::GridFsBucket::collect_garbage).
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct GridFsCollectGarbageOptions {
+    /// The minimum age of an orphaned chunk for it to be deleted, based on the timestamp of its
+    /// `_id`. Chunks written more recently than this may belong to an upload that is still in
+    /// progress and are left in place. Defaults to 24 hours.
+    pub grace_period: Option<Duration>,
+}
@@ -0,0 +1,191 @@
+use futures_util::TryStreamExt;
+
+use super::GridFsBucket;
+use crate::{
+    bson::{doc, Bson, Document},
+    error::{ErrorKind, GridFsErrorKind, GridFsFileIdentifier, Result},
+    options::FindOptions,
+    ClientSession,
+};
+
+impl GridFsBucket {
+    /// Deletes all revisions of the file with the given `filename` and their associated chunks
+    /// from this bucket. Returns the number of revisions that were deleted.
+    pub async fn delete_by_name(&self, filename: impl AsRef<str>) -> Result<u64> {
+        self.delete_by_name_common(filename.as_ref(), None).await
+    }
+
+    /// Deletes all revisions of the file with the given `filename` and their associated chunks
+    /// from this bucket using the provided session. Returns the number of revisions that were
+    /// deleted.
+    pub async fn delete_by_name_with_session(
+        &self,
+        filename: impl AsRef<str>,
+        session: &mut ClientSession,
+    ) -> Result<u64> {
+        self.delete_by_name_common(filename.as_ref(), session).await
+    }
+
+    async fn delete_by_name_common(
+        &self,
+        filename: &str,
+        session: impl Into<Option<&mut ClientSession>>,
+    ) -> Result<u64> {
+        let deleted = self
+            .delete_revisions(filename, FindOptions::default(), session.into())
+            .await?;
+        if deleted == 0 {
+            return Err(ErrorKind::GridFs(GridFsErrorKind::FileNotFound {
+                identifier: GridFsFileIdentifier::Filename(filename.into()),
+            })
+            .into());
+        }
+
+        Ok(deleted)
+    }
+
+    /// Renames all revisions of the file with the given `filename` to `new_filename`.
+    pub async fn rename_all_revisions(
+        &self,
+        filename: impl AsRef<str>,
+        new_filename: impl AsRef<str>,
+    ) -> Result<()> {
+        self.rename_all_revisions_common(filename.as_ref(), new_filename.as_ref(), None)
+            .await
+    }
+
+    /// Renames all revisions of the file with the given `filename` to `new_filename` using the
+    /// provided session.
+    pub async fn rename_all_revisions_with_session(
+        &self,
+        filename: impl AsRef<str>,
+        new_filename: impl AsRef<str>,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        self.rename_all_revisions_common(filename.as_ref(), new_filename.as_ref(), session)
+            .await
+    }
+
+    async fn rename_all_revisions_common(
+        &self,
+        filename: &str,
+        new_filename: &str,
+        session: impl Into<Option<&mut ClientSession>>,
+    ) -> Result<()> {
+        let filter = doc! { "filename": filename };
+        let update = doc! { "$set": { "filename": new_filename } };
+        let update_result = match session.into() {
+            Some(session) => {
+                self.files()
+                    .update_many_with_session(filter, update, None, session)
+                    .await?
+            }
+            None => self.files().update_many(filter, update, None).await?,
+        };
+
+        if update_result.matched_count == 0 {
+            return Err(ErrorKind::GridFs(GridFsErrorKind::FileNotFound {
+                identifier: GridFsFileIdentifier::Filename(filename.into()),
+            })
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Deletes all but the `keep_latest` most recent revisions of the file with the given
+    /// `filename`, along with their associated chunks. Revisions are ordered by their
+    /// `upload_date`. Returns the number of revisions that were deleted.
+    pub async fn prune_revisions(
+        &self,
+        filename: impl AsRef<str>,
+        keep_latest: u32,
+    ) -> Result<u64> {
+        self.prune_revisions_common(filename.as_ref(), keep_latest, None)
+            .await
+    }
+
+    /// Deletes all but the `keep_latest` most recent revisions of the file with the given
+    /// `filename`, along with their associated chunks, using the provided session. Returns the
+    /// number of revisions that were deleted.
+    pub async fn prune_revisions_with_session(
+        &self,
+        filename: impl AsRef<str>,
+        keep_latest: u32,
+        session: &mut ClientSession,
+    ) -> Result<u64> {
+        self.prune_revisions_common(filename.as_ref(), keep_latest, session)
+            .await
+    }
+
+    async fn prune_revisions_common(
+        &self,
+        filename: &str,
+        keep_latest: u32,
+        session: impl Into<Option<&mut ClientSession>>,
+    ) -> Result<u64> {
+        let options = FindOptions::builder()
+            .sort(revision_sort(-1))
+            .skip(keep_latest as u64)
+            .build();
+        self.delete_revisions(filename, options, session.into())
+            .await
+    }
+
+    /// Deletes the revisions of the file with the given `filename` that are returned by a find
+    /// with the given `options`, followed by their chunks. Returns the number of revisions that
+    /// were deleted.
+    async fn delete_revisions(
+        &self,
+        filename: &str,
+        mut options: FindOptions,
+        mut session: Option<&mut ClientSession>,
+    ) -> Result<u64> {
+        options.projection = Some(doc! { "_id": 1 });
+        let filter = doc! { "filename": filename };
+        let files = self.files().clone_with_type::<Document>();
+        let revisions: Vec<Document> = match session.as_deref_mut() {
+            Some(session) => {
+                let mut cursor = files.find_with_session(filter, options, session).await?;
+                let revisions = cursor.stream(session).try_collect().await?;
+                revisions
+            }
+            None => files.find(filter, options).await?.try_collect().await?,
+        };
+        let ids: Vec<Bson> = revisions
+            .into_iter()
+            .filter_map(|mut revision| revision.remove("_id"))
+            .collect();
+        if ids.is_empty() {
+            return Ok(0);
+        }
+
+        let files_filter = doc! { "_id": { "$in": ids.clone() } };
+        let chunks_filter = doc! { "files_id": { "$in": ids } };
+        let delete_result = match session {
+            Some(session) => {
+                let delete_result = files
+                    .delete_many_with_session(files_filter, None, &mut *session)
+                    .await?;
+                self.chunks()
+                    .delete_many_with_session(chunks_filter, None, session)
+                    .await?;
+                delete_result
+            }
+            None => {
+                let delete_result = files.delete_many(files_filter, None).await?;
+                self.chunks().delete_many(chunks_filter, None).await?;
+                delete_result
+            }
+        };
+
+        Ok(delete_result.deleted_count)
+    }
+}
+
+/// The sort that orders the revisions of a file from oldest to newest if `direction` is 1, or from
+/// newest to oldest if it is -1. Revisions uploaded in the same millisecond are ordered by `_id`,
+/// so that downloads and pruning agree on which revision is the latest.
+pub(super) fn revision_sort(direction: i32) -> Document {
+    doc! { "uploadDate": direction, "_id": direction }
+}
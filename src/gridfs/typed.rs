@@ -0,0 +1,312 @@
+use std::marker::PhantomData;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{
+    options::{GridFsFindOptions, GridFsUploadOptions},
+    FilesCollectionDocument,
+    GridFsBucket,
+    GridFsUploadStream,
+};
+use crate::{
+    bson::{oid::ObjectId, Bson, Document},
+    error::Result,
+    options::FindOptions,
+    ClientSession,
+    Collection,
+    Cursor,
+    SessionCursor,
+};
+
+/// A view of a [`GridFsBucket`] in which the `metadata` field of each file is serialized from and
+/// deserialized to the type `M`. Instances of this type can be created using
+/// [`GridFsBucket::typed_metadata`].
+///
+/// Uploads performed through this view take the metadata for the file as an `M`, and files found
+/// through it are returned as [`FilesCollectionDocument<M>`]s. All other operations can be
+/// performed on the underlying bucket returned by [`TypedGridFsBucket::bucket`].
+#[derive(Debug)]
+pub struct TypedGridFsBucket<M> {
+    bucket: GridFsBucket,
+    _phantom: PhantomData<fn() -> M>,
+}
+
+impl<M> Clone for TypedGridFsBucket<M> {
+    fn clone(&self) -> Self {
+        Self {
+            bucket: self.bucket.clone(),
+            _phantom: Default::default(),
+        }
+    }
+}
+
+impl<M> TypedGridFsBucket<M>
+where
+    M: Serialize + DeserializeOwned + Unpin + Send + Sync,
+{
+    pub(super) fn new(bucket: GridFsBucket) -> Self {
+        Self {
+            bucket,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Gets the underlying [`GridFsBucket`].
+    pub fn bucket(&self) -> &GridFsBucket {
+        &self.bucket
+    }
+
+    /// Gets a handle to the files collection with the typed `metadata` field.
+    fn files(&self) -> Collection<FilesCollectionDocument<M>> {
+        self.bucket.files().clone_with_type()
+    }
+
+    /// Finds and returns the [`FilesCollectionDocument`]s within this bucket that match the given
+    /// filter, deserializing the `metadata` field of each as an `M`.
+    pub async fn find(
+        &self,
+        filter: Document,
+        options: impl Into<Option<GridFsFindOptions>>,
+    ) -> Result<Cursor<FilesCollectionDocument<M>>> {
+        let find_options = options.into().map(FindOptions::from);
+        self.files().find(filter, find_options).await
+    }
+
+    /// Finds and returns the [`FilesCollectionDocument`]s within this bucket that match the given
+    /// filter using the provided session, deserializing the `metadata` field of each as an `M`.
+    pub async fn find_with_session(
+        &self,
+        filter: Document,
+        options: impl Into<Option<GridFsFindOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<SessionCursor<FilesCollectionDocument<M>>> {
+        let find_options = options.into().map(FindOptions::from);
+        self.files()
+            .find_with_session(filter, find_options, session)
+            .await
+    }
+
+    /// Opens a [`GridFsUploadStream`] that the application can write the contents of the file to,
+    /// storing `metadata` in the file's `metadata` field. The driver generates a unique
+    /// [`Bson::ObjectId`] for the file id.
+    ///
+    /// Any `metadata` set in `options` is overwritten by the provided `metadata`.
+    pub async fn open_upload_stream(
+        &self,
+        filename: String,
+        metadata: &M,
+        options: impl Into<Option<GridFsUploadOptions>>,
+    ) -> Result<GridFsUploadStream> {
+        self.open_upload_stream_with_id(
+            Bson::ObjectId(ObjectId::new()),
+            filename,
+            metadata,
+            options,
+        )
+        .await
+    }
+
+    /// Opens a [`GridFsUploadStream`] that the application can write the contents of the file to,
+    /// storing `metadata` in the file's `metadata` field. The application provides a custom file
+    /// id.
+    ///
+    /// Any `metadata` set in `options` is overwritten by the provided `metadata`.
+    pub async fn open_upload_stream_with_id(
+        &self,
+        id: Bson,
+        filename: String,
+        metadata: &M,
+        options: impl Into<Option<GridFsUploadOptions>>,
+    ) -> Result<GridFsUploadStream> {
+        let options = with_metadata(metadata, options.into())?;
+        self.bucket
+            .open_upload_stream_with_id(id, filename, options)
+            .await
+    }
+
+    /// Uploads a user file to a GridFS bucket, storing `metadata` in the file's `metadata` field.
+    /// The driver generates a unique [`Bson::ObjectId`] for the file id. Uses the `tokio` crate's
+    /// `AsyncRead` trait for the `source`. See [`GridFsBucket::upload_from_tokio_reader`] for more
+    /// details.
+    ///
+    /// Returns the id of the uploaded file.
+    pub async fn upload_from_tokio_reader(
+        &self,
+        filename: String,
+        source: impl tokio::io::AsyncRead,
+        metadata: &M,
+        options: impl Into<Option<GridFsUploadOptions>>,
+    ) -> Result<Bson> {
+        self.upload_from_tokio_reader_with_id(
+            Bson::ObjectId(ObjectId::new()),
+            filename,
+            source,
+            metadata,
+            options,
+        )
+        .await
+    }
+
+    /// Uploads a user file to a GridFS bucket, storing `metadata` in the file's `metadata` field.
+    /// The application supplies a custom file id. Uses the `tokio` crate's `AsyncRead` trait for
+    /// the `source`.
+    ///
+    /// Returns the id of the uploaded file.
+    pub async fn upload_from_tokio_reader_with_id(
+        &self,
+        id: Bson,
+        filename: String,
+        source: impl tokio::io::AsyncRead,
+        metadata: &M,
+        options: impl Into<Option<GridFsUploadOptions>>,
+    ) -> Result<Bson> {
+        let options = with_metadata(metadata, options.into())?;
+        self.bucket
+            .upload_from_tokio_reader_with_id(id, filename, source, options)
+            .await
+    }
+
+    /// Uploads a user file to a GridFS bucket, storing `metadata` in the file's `metadata` field.
+    /// The driver generates a unique [`Bson::ObjectId`] for the file id. Uses the `futures-0.3`
+    /// crate's `AsyncRead` trait for the `source`. See
+    /// [`GridFsBucket::upload_from_futures_0_3_reader`] for more details.
+    ///
+    /// Returns the id of the uploaded file.
+    pub async fn upload_from_futures_0_3_reader(
+        &self,
+        filename: String,
+        source: impl futures_util::AsyncRead,
+        metadata: &M,
+        options: impl Into<Option<GridFsUploadOptions>>,
+    ) -> Result<Bson> {
+        self.upload_from_futures_0_3_reader_with_id(
+            Bson::ObjectId(ObjectId::new()),
+            filename,
+            source,
+            metadata,
+            options,
+        )
+        .await
+    }
+
+    /// Uploads a user file to a GridFS bucket, storing `metadata` in the file's `metadata` field.
+    /// The application supplies a custom file id. Uses the `futures-0.3` crate's `AsyncRead` trait
+    /// for the `source`.
+    ///
+    /// Returns the id of the uploaded file.
+    pub async fn upload_from_futures_0_3_reader_with_id(
+        &self,
+        id: Bson,
+        filename: String,
+        source: impl futures_util::AsyncRead,
+        metadata: &M,
+        options: impl Into<Option<GridFsUploadOptions>>,
+    ) -> Result<Bson> {
+        let options = with_metadata(metadata, options.into())?;
+        self.bucket
+            .upload_from_futures_0_3_reader_with_id(id, filename, source, options)
+            .await
+    }
+
+    /// Uploads a user file to a GridFS bucket using the provided session, storing `metadata` in the
+    /// file's `metadata` field. The driver generates a unique [`Bson::ObjectId`] for the file id.
+    /// Uses the `tokio` crate's `AsyncRead` trait for the `source`. See
+    /// [`GridFsBucket::upload_from_tokio_reader_with_id_and_session`] for more details.
+    ///
+    /// Returns the id of the uploaded file.
+    pub async fn upload_from_tokio_reader_with_session(
+        &self,
+        filename: String,
+        source: impl tokio::io::AsyncRead,
+        metadata: &M,
+        options: impl Into<Option<GridFsUploadOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<Bson> {
+        self.upload_from_tokio_reader_with_id_and_session(
+            Bson::ObjectId(ObjectId::new()),
+            filename,
+            source,
+            metadata,
+            options,
+            session,
+        )
+        .await
+    }
+
+    /// Uploads a user file to a GridFS bucket using the provided session, storing `metadata` in the
+    /// file's `metadata` field. The application supplies a custom file id. Uses the `tokio`
+    /// crate's `AsyncRead` trait for the `source`.
+    ///
+    /// Returns the id of the uploaded file.
+    pub async fn upload_from_tokio_reader_with_id_and_session(
+        &self,
+        id: Bson,
+        filename: String,
+        source: impl tokio::io::AsyncRead,
+        metadata: &M,
+        options: impl Into<Option<GridFsUploadOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<Bson> {
+        let options = with_metadata(metadata, options.into())?;
+        self.bucket
+            .upload_from_tokio_reader_with_id_and_session(id, filename, source, options, session)
+            .await
+    }
+
+    /// Uploads a user file to a GridFS bucket using the provided session, storing `metadata` in the
+    /// file's `metadata` field. The driver generates a unique [`Bson::ObjectId`] for the file id.
+    /// Uses the `futures-0.3` crate's `AsyncRead` trait for the `source`. See
+    /// [`GridFsBucket::upload_from_futures_0_3_reader_with_id_and_session`] for more details.
+    ///
+    /// Returns the id of the uploaded file.
+    pub async fn upload_from_futures_0_3_reader_with_session(
+        &self,
+        filename: String,
+        source: impl futures_util::AsyncRead,
+        metadata: &M,
+        options: impl Into<Option<GridFsUploadOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<Bson> {
+        self.upload_from_futures_0_3_reader_with_id_and_session(
+            Bson::ObjectId(ObjectId::new()),
+            filename,
+            source,
+            metadata,
+            options,
+            session,
+        )
+        .await
+    }
+
+    /// Uploads a user file to a GridFS bucket using the provided session, storing `metadata` in the
+    /// file's `metadata` field. The application supplies a custom file id. Uses the `futures-0.3`
+    /// crate's `AsyncRead` trait for the `source`.
+    ///
+    /// Returns the id of the uploaded file.
+    pub async fn upload_from_futures_0_3_reader_with_id_and_session(
+        &self,
+        id: Bson,
+        filename: String,
+        source: impl futures_util::AsyncRead,
+        metadata: &M,
+        options: impl Into<Option<GridFsUploadOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<Bson> {
+        let options = with_metadata(metadata, options.into())?;
+        self.bucket
+            .upload_from_futures_0_3_reader_with_id_and_session(
+                id, filename, source, options, session,
+            )
+            .await
+    }
+}
+
+/// Returns the given upload options with their `metadata` set to the serialized `metadata`.
+fn with_metadata<M: Serialize>(
+    metadata: &M,
+    options: Option<GridFsUploadOptions>,
+) -> Result<GridFsUploadOptions> {
+    let mut options = options.unwrap_or_default();
+    options.metadata = Some(crate::bson::to_document(metadata)?);
+    Ok(options)
+}
@@ -0,0 +1,813 @@
+use std::{
+    pin::Pin,
+    sync::atomic::Ordering,
+    task::{Context, Poll},
+};
+
+use futures_core::future::BoxFuture;
+use futures_util::{
+    future::{poll_fn, FutureExt},
+    pin_mut,
+    ready,
+    stream::TryStreamExt,
+};
+
+use super::{
+    checksum::Hasher,
+    into_io_error,
+    options::GridFsUploadOptions,
+    Chunk,
+    FilesCollectionDocument,
+    GridFsBucket,
+};
+use crate::{
+    bson::{doc, oid::ObjectId, spec::BinarySubtype, Bson, DateTime, Document, RawBinaryRef},
+    bson_util,
+    error::{Error, ErrorKind, GridFsErrorKind, Result},
+    index::IndexModel,
+    options::{FindOneOptions, IndexOptions, ReadPreference, SelectionCriteria},
+    runtime,
+    ClientSession,
+    Collection,
+};
+
+impl GridFsBucket {
+    /// Uploads a user file to a GridFS bucket. The application supplies a custom file id. Uses the
+    /// `tokio` crate's `AsyncRead` trait for the `source`.
+    ///
+    /// The `source` is read until it is exhausted, and its contents are inserted into the chunks
+    /// collection as each chunk fills. If reading from the `source` or writing to the bucket fails,
+    /// any chunks that have already been written are deleted before the error is returned.
+    ///
+    /// Returns the id of the uploaded file.
+    pub async fn upload_from_tokio_reader_with_id(
+        &self,
+        id: Bson,
+        filename: String,
+        source: impl tokio::io::AsyncRead,
+        options: impl Into<Option<GridFsUploadOptions>>,
+    ) -> Result<Bson> {
+        use tokio::io::AsyncReadExt;
+
+        pin_mut!(source);
+        let mut stream = self
+            .open_upload_stream_with_id(id, filename, options)
+            .await?;
+        let mut buf = vec![0u8; stream.chunk_size_bytes as usize];
+        let result = async {
+            loop {
+                let bytes_read = source.read(&mut buf).await?;
+                if bytes_read == 0 {
+                    return Ok(());
+                }
+                stream.write_inner(&buf[..bytes_read]).await?;
+            }
+        }
+        .await;
+
+        stream.finish_or_abort(result).await
+    }
+
+    /// Uploads a user file to a GridFS bucket. The application supplies a custom file id. Uses the
+    /// `futures-0.3` crate's `AsyncRead` trait for the `source`.
+    ///
+    /// The `source` is read until it is exhausted, and its contents are inserted into the chunks
+    /// collection as each chunk fills. If reading from the `source` or writing to the bucket fails,
+    /// any chunks that have already been written are deleted before the error is returned.
+    ///
+    /// Returns the id of the uploaded file.
+    pub async fn upload_from_futures_0_3_reader_with_id(
+        &self,
+        id: Bson,
+        filename: String,
+        source: impl futures_util::AsyncRead,
+        options: impl Into<Option<GridFsUploadOptions>>,
+    ) -> Result<Bson> {
+        use futures_util::AsyncReadExt;
+
+        pin_mut!(source);
+        let mut stream = self
+            .open_upload_stream_with_id(id, filename, options)
+            .await?;
+        let mut buf = vec![0u8; stream.chunk_size_bytes as usize];
+        let result = async {
+            loop {
+                let bytes_read = source.read(&mut buf).await?;
+                if bytes_read == 0 {
+                    return Ok(());
+                }
+                stream.write_inner(&buf[..bytes_read]).await?;
+            }
+        }
+        .await;
+
+        stream.finish_or_abort(result).await
+    }
+
+    /// Uploads a user file to a GridFS bucket using the provided session. The application supplies
+    /// a custom file id. Uses the `tokio` crate's `AsyncRead` trait for the `source`.
+    ///
+    /// All of the file's chunks and its files collection document are written using the session,
+    /// so an upload performed within a transaction is committed or aborted along with it. See
+    /// [`GridFsBucket::upload_from_tokio_reader_with_id`] for more details.
+    ///
+    /// Returns the id of the uploaded file.
+    pub async fn upload_from_tokio_reader_with_id_and_session(
+        &self,
+        id: Bson,
+        filename: String,
+        source: impl tokio::io::AsyncRead,
+        options: impl Into<Option<GridFsUploadOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<Bson> {
+        use tokio::io::AsyncReadExt;
+
+        pin_mut!(source);
+        let mut stream = self
+            .open_upload_stream_with_id(id, filename, options)
+            .await?;
+        let mut buf = vec![0u8; stream.chunk_size_bytes as usize];
+        let result = async {
+            loop {
+                let bytes_read = source.read(&mut buf).await?;
+                if bytes_read == 0 {
+                    return Ok(());
+                }
+                stream
+                    .write_with_session(&buf[..bytes_read], session)
+                    .await?;
+            }
+        }
+        .await;
+
+        stream.finish_or_abort_with_session(result, session).await
+    }
+
+    /// Uploads a user file to a GridFS bucket using the provided session. The application supplies
+    /// a custom file id. Uses the `futures-0.3` crate's `AsyncRead` trait for the `source`.
+    ///
+    /// All of the file's chunks and its files collection document are written using the session,
+    /// so an upload performed within a transaction is committed or aborted along with it. See
+    /// [`GridFsBucket::upload_from_futures_0_3_reader_with_id`] for more details.
+    ///
+    /// Returns the id of the uploaded file.
+    pub async fn upload_from_futures_0_3_reader_with_id_and_session(
+        &self,
+        id: Bson,
+        filename: String,
+        source: impl futures_util::AsyncRead,
+        options: impl Into<Option<GridFsUploadOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<Bson> {
+        use futures_util::AsyncReadExt;
+
+        pin_mut!(source);
+        let mut stream = self
+            .open_upload_stream_with_id(id, filename, options)
+            .await?;
+        let mut buf = vec![0u8; stream.chunk_size_bytes as usize];
+        let result = async {
+            loop {
+                let bytes_read = source.read(&mut buf).await?;
+                if bytes_read == 0 {
+                    return Ok(());
+                }
+                stream
+                    .write_with_session(&buf[..bytes_read], session)
+                    .await?;
+            }
+        }
+        .await;
+
+        stream.finish_or_abort_with_session(result, session).await
+    }
+
+    /// Uploads a user file to a GridFS bucket. The driver generates a unique [`Bson::ObjectId`] for
+    /// the file id. Uses the `tokio` crate's `AsyncRead` trait for the `source`.
+    ///
+    /// Returns the id of the uploaded file.
+    pub async fn upload_from_tokio_reader(
+        &self,
+        filename: String,
+        source: impl tokio::io::AsyncRead,
+        options: impl Into<Option<GridFsUploadOptions>>,
+    ) -> Result<Bson> {
+        self.upload_from_tokio_reader_with_id(
+            Bson::ObjectId(ObjectId::new()),
+            filename,
+            source,
+            options,
+        )
+        .await
+    }
+
+    /// Uploads a user file to a GridFS bucket. The driver generates a unique [`Bson::ObjectId`] for
+    /// the file id. Uses the `futures-0.3` crate's `AsyncRead` trait for the `source`.
+    ///
+    /// Returns the id of the uploaded file.
+    pub async fn upload_from_futures_0_3_reader(
+        &self,
+        filename: String,
+        source: impl futures_util::AsyncRead,
+        options: impl Into<Option<GridFsUploadOptions>>,
+    ) -> Result<Bson> {
+        self.upload_from_futures_0_3_reader_with_id(
+            Bson::ObjectId(ObjectId::new()),
+            filename,
+            source,
+            options,
+        )
+        .await
+    }
+
+    /// Uploads a user file to a GridFS bucket using the provided session. The driver generates a
+    /// unique [`Bson::ObjectId`] for the file id. Uses the `tokio` crate's `AsyncRead` trait for
+    /// the `source`.
+    ///
+    /// Returns the id of the uploaded file.
+    pub async fn upload_from_tokio_reader_with_session(
+        &self,
+        filename: String,
+        source: impl tokio::io::AsyncRead,
+        options: impl Into<Option<GridFsUploadOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<Bson> {
+        self.upload_from_tokio_reader_with_id_and_session(
+            Bson::ObjectId(ObjectId::new()),
+            filename,
+            source,
+            options,
+            session,
+        )
+        .await
+    }
+
+    /// Uploads a user file to a GridFS bucket using the provided session. The driver generates a
+    /// unique [`Bson::ObjectId`] for the file id. Uses the `futures-0.3` crate's `AsyncRead` trait
+    /// for the `source`.
+    ///
+    /// Returns the id of the uploaded file.
+    pub async fn upload_from_futures_0_3_reader_with_session(
+        &self,
+        filename: String,
+        source: impl futures_util::AsyncRead,
+        options: impl Into<Option<GridFsUploadOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<Bson> {
+        self.upload_from_futures_0_3_reader_with_id_and_session(
+            Bson::ObjectId(ObjectId::new()),
+            filename,
+            source,
+            options,
+            session,
+        )
+        .await
+    }
+
+    /// Opens a [`GridFsUploadStream`] that the application can write the contents of the file to.
+    /// The application provides a custom file id.
+    ///
+    /// Returns a [`GridFsUploadStream`] to which the application will write the contents.
+    pub async fn open_upload_stream_with_id(
+        &self,
+        id: Bson,
+        filename: String,
+        options: impl Into<Option<GridFsUploadOptions>>,
+    ) -> Result<GridFsUploadStream> {
+        let options = options.into();
+        let chunk_size_bytes = options
+            .as_ref()
+            .and_then(|opts| opts.chunk_size_bytes)
+            .unwrap_or_else(|| self.chunk_size_bytes());
+        if chunk_size_bytes == 0 {
+            return Err(Error::invalid_argument(
+                "chunk_size_bytes must be greater than 0",
+            ));
+        }
+        let hasher = options
+            .as_ref()
+            .and_then(|opts| opts.checksum)
+            .or(self.inner.options.checksum)
+            .map(Hasher::new);
+        let metadata = options.and_then(|opts| opts.metadata);
+
+        self.create_indexes().await?;
+
+        Ok(GridFsUploadStream {
+            bucket: self.clone(),
+            files_id: id,
+            filename,
+            chunk_size_bytes,
+            metadata,
+            hasher,
+            n: 0,
+            state: State::Idle(Vec::new()),
+        })
+    }
+
+    /// Creates the indexes on the files and chunks collections required by the GridFS
+    /// specification if they do not already exist. This only needs to be done once per bucket
+    /// and is skipped if the files collection already contains documents.
+    async fn create_indexes(&self) -> Result<()> {
+        if self.inner.created_indexes.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let find_options = FindOneOptions::builder()
+            .projection(doc! { "_id": 1 })
+            .selection_criteria(SelectionCriteria::ReadPreference(ReadPreference::Primary))
+            .build();
+        if self
+            .files()
+            .clone_with_type::<Document>()
+            .find_one(None, find_options)
+            .await?
+            .is_none()
+        {
+            let files_keys = doc! { "filename": 1, "uploadDate": 1 };
+            create_index_if_missing(self.files(), files_keys, None).await?;
+
+            let chunks_keys = doc! { "files_id": 1, "n": 1 };
+            let chunks_options = IndexOptions::builder().unique(true).build();
+            create_index_if_missing(self.chunks(), chunks_keys, chunks_options).await?;
+        }
+
+        self.inner.created_indexes.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+async fn create_index_if_missing<T>(
+    coll: &Collection<T>,
+    keys: Document,
+    options: impl Into<Option<IndexOptions>>,
+) -> Result<()> {
+    let indexes: Vec<IndexModel> = match coll.list_indexes(None).await {
+        Ok(cursor) => cursor.try_collect().await?,
+        Err(error) if error.is_ns_not_found() => Vec::new(),
+        Err(error) => return Err(error),
+    };
+
+    // Index keys may be stored with a different numeric type (e.g. 1.0 rather than 1), so they
+    // are compared numerically rather than with document equality.
+    let exists = indexes.iter().any(|index| {
+        index.keys.len() == keys.len()
+            && index
+                .keys
+                .iter()
+                .zip(keys.iter())
+                .all(|((k1, v1), (k2, v2))| {
+                    k1 == k2
+                        && bson_util::get_int(v1).is_some()
+                        && bson_util::get_int(v1) == bson_util::get_int(v2)
+                })
+    });
+
+    if !exists {
+        let model = IndexModel::builder()
+            .keys(keys)
+            .options(options.into())
+            .build();
+        coll.create_index(model, None).await?;
+    }
+
+    Ok(())
+}
+
+/// A stream to which bytes can be written to be uploaded to a GridFS bucket. Instances of this
+/// type can be created using [`GridFsBucket::open_upload_stream`] or
+/// [`GridFsBucket::open_upload_stream_with_id`].
+///
+/// Written bytes are buffered and inserted into the bucket's chunks collection as each chunk
+/// fills. The files collection document for the file is inserted when the stream is closed, at
+/// which point any remaining buffered bytes are also written as the final chunk. The stream must
+/// be closed via [`GridFsUploadStream::finish`] or the `close`/`shutdown` methods of the
+/// `AsyncWrite` traits for the upload to complete.
+///
+/// If the stream is dropped before it has been closed, any chunks that have already been written
+/// will be deleted via a background task.
+///
+/// Upload streams do not use a session. To upload a file within a session or transaction, use
+/// [`GridFsBucket::upload_from_tokio_reader_with_session`] or
+/// [`GridFsBucket::upload_from_futures_0_3_reader_with_session`].
+pub struct GridFsUploadStream {
+    bucket: GridFsBucket,
+    files_id: Bson,
+    filename: String,
+    chunk_size_bytes: u32,
+    metadata: Option<Document>,
+    hasher: Option<Hasher>,
+    n: u32,
+    state: State,
+}
+
+enum State {
+    /// The stream is buffering bytes until a full chunk is available.
+    Idle(Vec<u8>),
+
+    /// Full chunks are being inserted. Resolves to the next chunk number and the remaining bytes
+    /// that did not fill a chunk.
+    Writing(BoxFuture<'static, Result<(u32, Vec<u8>)>>),
+
+    /// The final chunk and the files collection document are being inserted.
+    Closing(BoxFuture<'static, Result<()>>),
+
+    /// A previous write or close failed. The upload can only be aborted.
+    Failed(Error),
+
+    /// The upload was completed or aborted.
+    Closed,
+}
+
+impl GridFsUploadStream {
+    /// Gets the file `id` for the stream.
+    pub fn files_id(&self) -> &Bson {
+        &self.files_id
+    }
+
+    /// Consumes the stream and uploads data in the stream to the server.
+    pub async fn finish(mut self) -> Result<()> {
+        poll_fn(|cx| self.poll_close_inner(cx)).await
+    }
+
+    /// Aborts the upload and discards the upload stream. Any chunks that have already been
+    /// written to the chunks collection will be deleted.
+    pub async fn abort(mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Closed) {
+            State::Closing(_) | State::Closed => {
+                return Err(ErrorKind::GridFs(GridFsErrorKind::UploadStreamClosed).into());
+            }
+            // Wait for any in-progress write so that its chunks are not inserted after the
+            // delete below.
+            State::Writing(future) => {
+                let _ = future.await;
+            }
+            State::Idle(_) | State::Failed(_) => {}
+        }
+
+        self.bucket
+            .chunks()
+            .delete_many(doc! { "files_id": self.files_id.clone() }, None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Writes all of `bytes` to the stream.
+    async fn write_inner(&mut self, bytes: &[u8]) -> Result<()> {
+        poll_fn(|cx| self.poll_write_inner(cx, bytes)).await?;
+        Ok(())
+    }
+
+    /// Closes the stream if `result` is `Ok`, returning the id of the uploaded file. If `result`
+    /// is an error or closing the stream fails, the upload is aborted and the error is returned.
+    async fn finish_or_abort(mut self, result: Result<()>) -> Result<Bson> {
+        let result = match result {
+            Ok(()) => poll_fn(|cx| self.poll_close_inner(cx)).await,
+            Err(error) => Err(error),
+        };
+
+        match result {
+            Ok(()) => Ok(self.files_id.clone()),
+            Err(error) => {
+                let _ = self.abort().await;
+                Err(error)
+            }
+        }
+    }
+
+    /// Polls the in-progress chunk write, if any, to completion.
+    fn poll_write_in_progress(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let result = match self.state {
+            State::Writing(ref mut future) => ready!(future.poll_unpin(cx)),
+            State::Failed(ref error) => return Poll::Ready(Err(error.clone())),
+            _ => return Poll::Ready(Ok(())),
+        };
+
+        match result {
+            Ok((n, buffer)) => {
+                self.n = n;
+                self.state = State::Idle(buffer);
+                Poll::Ready(Ok(()))
+            }
+            Err(error) => {
+                self.state = State::Failed(error.clone());
+                Poll::Ready(Err(error))
+            }
+        }
+    }
+
+    fn poll_write_inner(&mut self, cx: &mut Context<'_>, buf: &[u8]) -> Poll<Result<usize>> {
+        ready!(self.poll_write_in_progress(cx))?;
+
+        let buffer = match self.state {
+            State::Idle(ref mut buffer) => buffer,
+            _ => {
+                return Poll::Ready(Err(
+                    ErrorKind::GridFs(GridFsErrorKind::UploadStreamClosed).into()
+                ))
+            }
+        };
+        buffer.extend_from_slice(buf);
+        if let Some(ref mut hasher) = self.hasher {
+            hasher.update(buf);
+        }
+
+        if buffer.len() >= self.chunk_size_bytes as usize {
+            let buffer = std::mem::take(buffer);
+            self.state = State::Writing(
+                write_chunks(
+                    self.bucket.clone(),
+                    self.files_id.clone(),
+                    self.n,
+                    self.chunk_size_bytes,
+                    buffer,
+                    None,
+                )
+                .boxed(),
+            );
+            // Start the write so that it can make progress while the caller prepares more bytes.
+            // Any error will be returned from the next call on this stream.
+            let _ = self.poll_write_in_progress(cx);
+        }
+
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    /// Builds the files collection document for the file, given the number of bytes that remain
+    /// to be written in the final chunk.
+    fn files_collection_document(&mut self, remaining: usize) -> FilesCollectionDocument {
+        FilesCollectionDocument {
+            id: self.files_id.clone(),
+            length: self.n as u64 * self.chunk_size_bytes as u64 + remaining as u64,
+            chunk_size: self.chunk_size_bytes,
+            upload_date: DateTime::now(),
+            filename: Some(self.filename.clone()),
+            metadata: self.metadata.take(),
+            checksum: self.hasher.take().map(Hasher::finish),
+        }
+    }
+
+    /// Buffers `bytes` and inserts any full chunks using the provided session. The stream must not
+    /// be polled via the `AsyncWrite` traits while it is being used with a session.
+    async fn write_with_session(
+        &mut self,
+        bytes: &[u8],
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        let mut buffer = match std::mem::replace(&mut self.state, State::Closed) {
+            State::Idle(buffer) => buffer,
+            State::Failed(error) => {
+                self.state = State::Failed(error.clone());
+                return Err(error);
+            }
+            state => {
+                self.state = state;
+                return Err(ErrorKind::GridFs(GridFsErrorKind::UploadStreamClosed).into());
+            }
+        };
+        buffer.extend_from_slice(bytes);
+        if let Some(ref mut hasher) = self.hasher {
+            hasher.update(bytes);
+        }
+
+        let result = write_chunks(
+            self.bucket.clone(),
+            self.files_id.clone(),
+            self.n,
+            self.chunk_size_bytes,
+            buffer,
+            Some(session),
+        )
+        .await;
+        match result {
+            Ok((n, buffer)) => {
+                self.n = n;
+                self.state = State::Idle(buffer);
+                Ok(())
+            }
+            Err(error) => {
+                self.state = State::Failed(error.clone());
+                Err(error)
+            }
+        }
+    }
+
+    /// Closes the stream if `result` is `Ok`, inserting the final chunk and the files collection
+    /// document using the provided session and returning the id of the uploaded file. If `result`
+    /// is an error or closing the stream fails, any chunks that have been written are deleted
+    /// using the session and the error is returned.
+    async fn finish_or_abort_with_session(
+        mut self,
+        result: Result<()>,
+        session: &mut ClientSession,
+    ) -> Result<Bson> {
+        let result = match (result, std::mem::replace(&mut self.state, State::Closed)) {
+            (Ok(()), State::Idle(buffer)) => {
+                let file = self.files_collection_document(buffer.len());
+                close(
+                    self.bucket.clone(),
+                    file,
+                    self.n,
+                    buffer,
+                    Some(&mut *session),
+                )
+                .await
+            }
+            (Ok(()), State::Failed(error)) | (Err(error), _) => Err(error),
+            (Ok(()), _) => Err(ErrorKind::GridFs(GridFsErrorKind::UploadStreamClosed).into()),
+        };
+
+        match result {
+            Ok(()) => Ok(self.files_id.clone()),
+            Err(error) => {
+                // Any error from the delete is ignored in favor of the original error. If the
+                // session is in a transaction, aborting the transaction also removes the chunks.
+                let _ = self
+                    .bucket
+                    .chunks()
+                    .delete_many_with_session(
+                        doc! { "files_id": self.files_id.clone() },
+                        None,
+                        session,
+                    )
+                    .await;
+                Err(error)
+            }
+        }
+    }
+
+    fn poll_flush_inner(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.poll_write_in_progress(cx)
+    }
+
+    fn poll_close_inner(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        ready!(self.poll_write_in_progress(cx))?;
+
+        if let State::Idle(ref mut buffer) = self.state {
+            let buffer = std::mem::take(buffer);
+            let file = self.files_collection_document(buffer.len());
+            self.state =
+                State::Closing(close(self.bucket.clone(), file, self.n, buffer, None).boxed());
+        }
+
+        let result = match self.state {
+            State::Closing(ref mut future) => ready!(future.poll_unpin(cx)),
+            _ => return Poll::Ready(Ok(())),
+        };
+        self.state = match result {
+            Ok(()) => State::Closed,
+            Err(ref error) => State::Failed(error.clone()),
+        };
+        Poll::Ready(result)
+    }
+}
+
+/// Inserts all of the full chunks contained in `buffer`, starting with chunk number `n`. Returns
+/// the next chunk number and the bytes remaining in the buffer.
+async fn write_chunks(
+    bucket: GridFsBucket,
+    files_id: Bson,
+    mut n: u32,
+    chunk_size_bytes: u32,
+    mut buffer: Vec<u8>,
+    session: Option<&mut ClientSession>,
+) -> Result<(u32, Vec<u8>)> {
+    let chunk_size_bytes = chunk_size_bytes as usize;
+    let full_length = buffer.len() - buffer.len() % chunk_size_bytes;
+
+    let chunks: Vec<Chunk> = buffer[..full_length]
+        .chunks_exact(chunk_size_bytes)
+        .map(|data| {
+            let chunk = Chunk::new(files_id.clone(), n, data);
+            n += 1;
+            chunk
+        })
+        .collect();
+    if !chunks.is_empty() {
+        let coll = bucket.chunks().clone_with_type::<Chunk>();
+        match session {
+            Some(session) => coll.insert_many_with_session(chunks, None, session).await?,
+            None => coll.insert_many(chunks, None).await?,
+        };
+    }
+
+    buffer.drain(..full_length);
+    Ok((n, buffer))
+}
+
+/// Inserts the remaining bytes in `buffer` as the final chunk of the file followed by the files
+/// collection document.
+async fn close(
+    bucket: GridFsBucket,
+    file: FilesCollectionDocument,
+    n: u32,
+    buffer: Vec<u8>,
+    mut session: Option<&mut ClientSession>,
+) -> Result<()> {
+    if !buffer.is_empty() {
+        let chunk = Chunk::new(file.id.clone(), n, &buffer);
+        let coll = bucket.chunks().clone_with_type::<Chunk>();
+        match session.as_deref_mut() {
+            Some(session) => coll.insert_one_with_session(chunk, None, session).await?,
+            None => coll.insert_one(chunk, None).await?,
+        };
+    }
+    match session {
+        Some(session) => {
+            bucket
+                .files()
+                .insert_one_with_session(file, None, session)
+                .await?
+        }
+        None => bucket.files().insert_one(file, None).await?,
+    };
+
+    Ok(())
+}
+
+impl<'a> Chunk<'a> {
+    fn new(files_id: Bson, n: u32, bytes: &'a [u8]) -> Self {
+        Self {
+            id: ObjectId::new(),
+            files_id,
+            n,
+            data: RawBinaryRef {
+                subtype: BinarySubtype::Generic,
+                bytes,
+            },
+        }
+    }
+}
+
+impl Drop for GridFsUploadStream {
+    fn drop(&mut self) {
+        // Nothing needs to be cleaned up if no chunks have been written, the upload was completed
+        // or aborted, or the upload is in the process of being closed. A failed write or close may
+        // have inserted some chunks before failing without advancing `n`, so chunks are always
+        // deleted after a failure.
+        let in_progress_write = match std::mem::replace(&mut self.state, State::Closed) {
+            State::Idle(_) if self.n == 0 => return,
+            State::Idle(_) | State::Failed(_) => None,
+            State::Writing(future) => Some(future),
+            State::Closing(_) | State::Closed => return,
+        };
+
+        let chunks = self.bucket.chunks().clone();
+        let files_id = self.files_id.clone();
+        runtime::execute(async move {
+            if let Some(future) = in_progress_write {
+                let _ = future.await;
+            }
+            let _ = chunks
+                .delete_many(doc! { "files_id": files_id }, None)
+                .await;
+        });
+    }
+}
+
+impl tokio::io::AsyncWrite for GridFsUploadStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<tokio::io::Result<usize>> {
+        self.get_mut()
+            .poll_write_inner(cx, buf)
+            .map_err(into_io_error)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<tokio::io::Result<()>> {
+        self.get_mut().poll_flush_inner(cx).map_err(into_io_error)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<tokio::io::Result<()>> {
+        self.get_mut().poll_close_inner(cx).map_err(into_io_error)
+    }
+}
+
+impl futures_util::AsyncWrite for GridFsUploadStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<core::result::Result<usize, futures_util::io::Error>> {
+        self.get_mut()
+            .poll_write_inner(cx, buf)
+            .map_err(into_io_error)
+    }
+
+    fn poll_flush(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<core::result::Result<(), futures_util::io::Error>> {
+        self.get_mut().poll_flush_inner(cx).map_err(into_io_error)
+    }
+
+    fn poll_close(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<core::result::Result<(), futures_util::io::Error>> {
+        self.get_mut().poll_close_inner(cx).map_err(into_io_error)
+    }
+}
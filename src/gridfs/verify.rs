@@ -0,0 +1,621 @@
+#[cfg(test)]
+mod test;
+
+use std::{
+    cmp::Ordering,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use futures_util::TryStreamExt;
+
+use super::{
+    options::GridFsCollectGarbageOptions,
+    GridFsBucket,
+    GridFsCollectGarbageResult,
+    GridFsFileError,
+    GridFsOrphanedChunks,
+    GridFsVerifyReport,
+};
+use crate::{
+    bson::{doc, oid::ObjectId, Bson, Document},
+    bson_util,
+    error::{GridFsErrorKind, Result},
+    options::{AggregateOptions, FindOneOptions, FindOptions},
+    Cursor,
+};
+
+/// The default minimum age of an orphaned chunk for it to be deleted by
+/// [`GridFsBucket::collect_garbage`].
+const DEFAULT_GRACE_PERIOD: Duration = Duration::from_secs(24 * 60 * 60);
+
+impl GridFsBucket {
+    /// Checks the consistency of the files and chunks collections of this bucket. Both
+    /// collections are streamed once in order of file id, and the returned
+    /// [`GridFsVerifyReport`] lists any chunks that have no corresponding files collection
+    /// document as well as any files whose chunks are missing, duplicated, of the wrong size, or
+    /// of the wrong number for the file's length.
+    ///
+    /// Chunks belong to a file if their `files_id` is equal to the file's id as compared by the
+    /// server, so a numeric `files_id` matches a numerically equal file id of a different type.
+    /// Chunks are only reported as orphaned after the server has found no file with their
+    /// `files_id`.
+    ///
+    /// The contents of the chunks are not retrieved; their sizes are computed by the server. This
+    /// requires MongoDB 4.4 or later.
+    pub async fn verify(&self) -> Result<GridFsVerifyReport> {
+        let mut files = FileEntries::new(self).await?;
+        let mut report = GridFsVerifyReport::default();
+
+        // The chunks are sorted by files_id, in the same order as the files are sorted by _id, so
+        // that both collections can be read once in step and all of the chunks for a file are
+        // read consecutively.
+        let pipeline = vec![
+            doc! { "$sort": { "files_id": 1, "n": 1 } },
+            chunk_projection(),
+        ];
+        let options = AggregateOptions::builder().allow_disk_use(true).build();
+        let mut chunks = self
+            .chunks()
+            .clone_with_type::<Document>()
+            .aggregate(pipeline, options)
+            .await?;
+
+        let mut unmatched = Vec::new();
+        let mut group: Option<ChunkGroup> = None;
+        while let Some(chunk) = chunks.try_next().await? {
+            let files_id = chunk.get("files_id").cloned().unwrap_or(Bson::Null);
+            let n = chunk.get("n").and_then(bson_util::get_int).unwrap_or(-1);
+            let size = chunk.get("size").and_then(bson_util::get_int).unwrap_or(-1);
+
+            match group {
+                Some(ref mut group)
+                    if compare_ids(&group.files_id, &files_id) == Ordering::Equal =>
+                {
+                    group.chunks.push((n, size))
+                }
+                _ => {
+                    if let Some(group) = group.take() {
+                        let file = files.find(Some(&group.files_id), &mut report).await?;
+                        unmatched.extend(group.check(file, &mut report));
+                    }
+                    group = Some(ChunkGroup {
+                        files_id,
+                        chunks: vec![(n, size)],
+                    });
+                }
+            }
+        }
+        if let Some(group) = group.take() {
+            let file = files.find(Some(&group.files_id), &mut report).await?;
+            unmatched.extend(group.check(file, &mut report));
+        }
+
+        // The remaining files have no chunks.
+        files.find(None, &mut report).await?;
+
+        // The chunks that were not matched with a file while reading both collections in step are
+        // only orphaned if the server finds no file for them. Otherwise, the file's chunks are
+        // checked again as found by the server.
+        for group in unmatched {
+            match self.find_file_entry(&group.files_id).await? {
+                Some(file) => self.recheck_file(file, &mut report).await?,
+                None => report.orphaned_chunks.push(GridFsOrphanedChunks {
+                    files_id: group.files_id,
+                    count: group.chunks.len() as u64,
+                }),
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Deletes the chunks in this bucket that have no corresponding files collection document
+    /// and were written longer ago than the grace period specified in `options`. The age of a
+    /// chunk is determined by the timestamp of its `_id`.
+    ///
+    /// Chunks without a files collection document are also present while an upload is in
+    /// progress, so the grace period should be longer than the longest expected upload. The
+    /// orphaned chunks are found using [`GridFsBucket::verify`], which requires MongoDB 4.4 or
+    /// later.
+    pub async fn collect_garbage(
+        &self,
+        options: impl Into<Option<GridFsCollectGarbageOptions>>,
+    ) -> Result<GridFsCollectGarbageResult> {
+        let grace_period = options
+            .into()
+            .and_then(|opts| opts.grace_period)
+            .unwrap_or(DEFAULT_GRACE_PERIOD);
+        let cutoff = SystemTime::now()
+            .checked_sub(grace_period)
+            .and_then(|cutoff| cutoff.duration_since(UNIX_EPOCH).ok())
+            // ObjectId timestamps have a resolution of one second, so the cutoff is rounded up to
+            // include chunks written earlier within the same second.
+            .map(|cutoff| cutoff.as_secs() + u64::from(cutoff.subsec_nanos() > 0))
+            .unwrap_or(0);
+        // An ObjectId with the cutoff timestamp and all other bytes zeroed sorts before every
+        // ObjectId generated at or after the cutoff.
+        let mut cutoff_bytes = [0u8; 12];
+        cutoff_bytes[..4].copy_from_slice(&(cutoff.min(u32::MAX as u64) as u32).to_be_bytes());
+        let cutoff_id = ObjectId::from_bytes(cutoff_bytes);
+
+        let report = self.verify().await?;
+        let mut result = GridFsCollectGarbageResult::default();
+        for orphan in report.orphaned_chunks {
+            // The file may have been inserted since the bucket was verified.
+            if self.find_file_entry(&orphan.files_id).await?.is_some() {
+                continue;
+            }
+            let delete_result = self
+                .chunks()
+                .delete_many(
+                    doc! { "files_id": orphan.files_id, "_id": { "$lt": cutoff_id } },
+                    None,
+                )
+                .await?;
+            result.deleted_count += delete_result.deleted_count;
+        }
+
+        Ok(result)
+    }
+
+    /// Finds the file with the given id as compared by the server, if any.
+    async fn find_file_entry(&self, id: &Bson) -> Result<Option<FileEntry>> {
+        let options = FindOneOptions::builder()
+            .projection(file_projection())
+            .build();
+        let file = self
+            .files()
+            .clone_with_type::<Document>()
+            .find_one(doc! { "_id": id.clone() }, options)
+            .await?;
+        Ok(file.map(FileEntry::new))
+    }
+
+    /// Replaces the errors reported for `file` with those found by checking all of the chunks
+    /// that the server finds for it.
+    async fn recheck_file(&self, file: FileEntry, report: &mut GridFsVerifyReport) -> Result<()> {
+        let pipeline = vec![
+            doc! { "$match": { "files_id": file.id.clone() } },
+            doc! { "$sort": { "n": 1 } },
+            chunk_projection(),
+        ];
+        let chunks: Vec<Document> = self
+            .chunks()
+            .clone_with_type::<Document>()
+            .aggregate(pipeline, None)
+            .await?
+            .try_collect()
+            .await?;
+        let chunks: Vec<(i64, i64)> = chunks
+            .iter()
+            .map(|chunk| {
+                (
+                    chunk.get("n").and_then(bson_util::get_int).unwrap_or(-1),
+                    chunk.get("size").and_then(bson_util::get_int).unwrap_or(-1),
+                )
+            })
+            .collect();
+
+        report.file_errors.retain(|error| error.files_id != file.id);
+        file.report(&chunks, report);
+        Ok(())
+    }
+}
+
+/// The projection of a files collection document to the fields of a [`FileEntry`].
+fn file_projection() -> Document {
+    doc! { "_id": 1, "length": 1, "chunkSize": 1 }
+}
+
+/// The projection of a chunk to its `files_id`, its `n` and the size of its data.
+fn chunk_projection() -> Document {
+    doc! {
+        "$project": {
+            "_id": 0,
+            "files_id": 1,
+            "n": 1,
+            "size": { "$binarySize": "$data" },
+        }
+    }
+}
+
+/// The fields of a files collection document needed to check its chunks.
+struct FileEntry {
+    id: Bson,
+    length: u64,
+    chunk_size: u64,
+}
+
+impl FileEntry {
+    fn new(file: Document) -> Self {
+        let length = file.get("length").and_then(bson_util::get_int).unwrap_or(0);
+        let chunk_size = file
+            .get("chunkSize")
+            .and_then(bson_util::get_int)
+            .unwrap_or(0);
+        Self {
+            id: file.get("_id").cloned().unwrap_or(Bson::Null),
+            length: length.max(0) as u64,
+            chunk_size: chunk_size.max(0) as u64,
+        }
+    }
+
+    /// Checks the `(n, size)` pairs of the file's chunks, sorted by `n`, against the file's
+    /// length and chunk size.
+    fn check_chunks(&self, chunks: &[(i64, i64)]) -> Vec<GridFsErrorKind> {
+        let total_n = match self.chunk_size {
+            0 => 0,
+            chunk_size => ((self.length + chunk_size - 1) / chunk_size) as i64,
+        };
+
+        let mut errors = Vec::new();
+        let mut next = 0;
+        let mut count = 0;
+        for &(n, size) in chunks {
+            count += 1;
+            if n < 0 {
+                continue;
+            }
+            if n < next {
+                errors.push(GridFsErrorKind::DuplicateChunk { n: n as u32 });
+                continue;
+            }
+            // Only the first chunk in each run of missing chunks is reported.
+            if n > next && next < total_n {
+                errors.push(GridFsErrorKind::MissingChunk { n: next as u32 });
+            }
+            if n < total_n {
+                let expected_size =
+                    std::cmp::min(self.length - self.chunk_size * n as u64, self.chunk_size) as i64;
+                if size != expected_size {
+                    errors.push(GridFsErrorKind::WrongSizeChunk {
+                        actual_size: size.max(0) as u32,
+                        expected_size: expected_size as u32,
+                    });
+                }
+            }
+            next = n + 1;
+        }
+        if next < total_n {
+            errors.push(GridFsErrorKind::MissingChunk { n: next as u32 });
+        }
+        if count != total_n {
+            errors.push(GridFsErrorKind::WrongNumberOfChunks {
+                actual_number: count as u32,
+                expected_number: total_n as u32,
+            });
+        }
+
+        errors
+    }
+
+    /// Adds the errors found by [`FileEntry::check_chunks`] to `report`.
+    fn report(&self, chunks: &[(i64, i64)], report: &mut GridFsVerifyReport) {
+        for kind in self.check_chunks(chunks) {
+            report.file_errors.push(GridFsFileError {
+                files_id: self.id.clone(),
+                kind,
+            });
+        }
+    }
+}
+
+/// The files in the files collection, read in order of their ids.
+struct FileEntries {
+    cursor: Cursor<Document>,
+
+    /// The file with the smallest id that has not been matched with its chunks yet.
+    next: Option<FileEntry>,
+}
+
+impl FileEntries {
+    async fn new(bucket: &GridFsBucket) -> Result<Self> {
+        let options = FindOptions::builder()
+            .projection(file_projection())
+            .sort(doc! { "_id": 1 })
+            .build();
+        let cursor = bucket
+            .files()
+            .clone_with_type::<Document>()
+            .find(None, options)
+            .await?;
+
+        let mut files = Self { cursor, next: None };
+        files.advance().await?;
+        Ok(files)
+    }
+
+    /// Reads the next file from the cursor.
+    async fn advance(&mut self) -> Result<()> {
+        self.next = self.cursor.try_next().await?.map(FileEntry::new);
+        Ok(())
+    }
+
+    /// Returns the file whose id is equal to `id`, if any. The files with smaller ids have no
+    /// chunks, since the chunks are read in the same order, and are reported as such. If `id` is
+    /// `None`, all remaining files are reported.
+    async fn find(
+        &mut self,
+        id: Option<&Bson>,
+        report: &mut GridFsVerifyReport,
+    ) -> Result<Option<FileEntry>> {
+        while let Some(file) = self.next.take() {
+            match id.map_or(Ordering::Less, |id| compare_ids(&file.id, id)) {
+                Ordering::Less => {
+                    file.report(&[], report);
+                    self.advance().await?;
+                }
+                Ordering::Equal => {
+                    self.advance().await?;
+                    return Ok(Some(file));
+                }
+                Ordering::Greater => {
+                    self.next = Some(file);
+                    return Ok(None);
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// The `(n, size)` pairs of consecutive chunks with the same `files_id`.
+struct ChunkGroup {
+    files_id: Bson,
+    chunks: Vec<(i64, i64)>,
+}
+
+impl ChunkGroup {
+    /// Checks the chunks against their `file`, or returns them if there is none.
+    fn check(self, file: Option<FileEntry>, report: &mut GridFsVerifyReport) -> Option<Self> {
+        match file {
+            Some(file) => {
+                file.report(&self.chunks, report);
+                None
+            }
+            None => Some(self),
+        }
+    }
+}
+
+/// Compares two ids in the order in which the server sorts them, so that the files and chunks
+/// collections can be read in step. As in the server's comparisons, numbers of different types
+/// that are numerically equal (e.g. an `Int32` and an `Int64` id) are equal, so the chunks of a
+/// file whose `files_id` has a different numeric type than the file's `_id` belong to the file
+/// rather than being orphaned, just as they are found when the file is downloaded.
+fn compare_ids(a: &Bson, b: &Bson) -> Ordering {
+    let by_type = type_order(a).cmp(&type_order(b));
+    if by_type != Ordering::Equal {
+        return by_type;
+    }
+
+    match (a, b) {
+        (Bson::Int32(_) | Bson::Int64(_) | Bson::Double(_) | Bson::Decimal128(_), _) => {
+            compare_numbers(a, b)
+        }
+        (Bson::String(a) | Bson::Symbol(a), Bson::String(b) | Bson::Symbol(b)) => a.cmp(b),
+        (Bson::Document(a), Bson::Document(b)) => compare_elements(a.iter(), b.iter()),
+        (Bson::Array(a), Bson::Array(b)) => {
+            let keys: Vec<String> = (0..a.len().max(b.len())).map(|i| i.to_string()).collect();
+            compare_elements(keys.iter().zip(a), keys.iter().zip(b))
+        }
+        (Bson::Binary(a), Bson::Binary(b)) => a
+            .bytes
+            .len()
+            .cmp(&b.bytes.len())
+            .then_with(|| u8::from(a.subtype).cmp(&u8::from(b.subtype)))
+            .then_with(|| a.bytes.cmp(&b.bytes)),
+        (Bson::ObjectId(a), Bson::ObjectId(b)) => a.bytes().cmp(&b.bytes()),
+        (Bson::Boolean(a), Bson::Boolean(b)) => a.cmp(b),
+        (Bson::DateTime(a), Bson::DateTime(b)) => a.cmp(b),
+        (Bson::Timestamp(a), Bson::Timestamp(b)) => {
+            (a.time, a.increment).cmp(&(b.time, b.increment))
+        }
+        (Bson::RegularExpression(a), Bson::RegularExpression(b)) => a
+            .pattern
+            .cmp(&b.pattern)
+            .then_with(|| a.options.cmp(&b.options)),
+        (Bson::JavaScriptCode(a), Bson::JavaScriptCode(b)) => a.cmp(b),
+        (Bson::JavaScriptCodeWithScope(a), Bson::JavaScriptCodeWithScope(b)) => a
+            .code
+            .cmp(&b.code)
+            .then_with(|| compare_elements(a.scope.iter(), b.scope.iter())),
+        // The remaining types only have a single value or are deprecated.
+        _ => Ordering::Equal,
+    }
+}
+
+/// The rank of the type of `value` in the server's sort order. Types with the same rank are
+/// compared by value.
+fn type_order(value: &Bson) -> u8 {
+    match value {
+        Bson::MinKey => 0,
+        Bson::Undefined => 1,
+        Bson::Null => 2,
+        Bson::Int32(_) | Bson::Int64(_) | Bson::Double(_) | Bson::Decimal128(_) => 3,
+        Bson::String(_) | Bson::Symbol(_) => 4,
+        Bson::Document(_) => 5,
+        Bson::Array(_) => 6,
+        Bson::Binary(_) => 7,
+        Bson::ObjectId(_) => 8,
+        Bson::Boolean(_) => 9,
+        Bson::DateTime(_) => 10,
+        Bson::Timestamp(_) => 11,
+        Bson::RegularExpression(_) => 12,
+        Bson::DbPointer(_) => 13,
+        Bson::JavaScriptCode(_) => 14,
+        Bson::JavaScriptCodeWithScope(_) => 15,
+        Bson::MaxKey => 16,
+    }
+}
+
+/// Compares two `Int32`, `Int64`, `Double` or `Decimal128` values by their exact values. NaN
+/// sorts before all other numbers.
+fn compare_numbers(a: &Bson, b: &Bson) -> Ordering {
+    match (a, b) {
+        (Bson::Int32(_) | Bson::Int64(_), Bson::Int32(_) | Bson::Int64(_)) => {
+            bson_util::get_int(a).cmp(&bson_util::get_int(b))
+        }
+        (Bson::Double(a), Bson::Double(b)) => compare_doubles(*a, *b),
+        (Bson::Int32(_) | Bson::Int64(_), Bson::Double(b)) => {
+            compare_int_to_double(bson_util::get_int(a).unwrap_or(0), *b)
+        }
+        (Bson::Double(_), Bson::Int32(_) | Bson::Int64(_)) => compare_numbers(b, a).reverse(),
+        _ => match (DecimalValue::new(a), DecimalValue::new(b)) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ => Ordering::Equal,
+        },
+    }
+}
+
+fn compare_doubles(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+    }
+}
+
+/// Compares an integer to a double without rounding the integer to the nearest double.
+fn compare_int_to_double(i: i64, d: f64) -> Ordering {
+    // 2^63, which is exactly representable as a double.
+    const TWO_TO_63: f64 = 9_223_372_036_854_775_808.0;
+
+    if d.is_nan() {
+        Ordering::Greater
+    } else if d >= TWO_TO_63 {
+        Ordering::Less
+    } else if d < -TWO_TO_63 {
+        Ordering::Greater
+    } else {
+        let truncated = d.trunc();
+        i.cmp(&(truncated as i64))
+            .then_with(|| 0.0.partial_cmp(&(d - truncated)).unwrap_or(Ordering::Equal))
+    }
+}
+
+/// The exact value of a number of any type, used to compare `Decimal128` values with other
+/// numbers.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum DecimalValue {
+    NaN,
+    NegativeInfinity,
+    /// A negative number, ordered by its negated magnitude.
+    Negative(std::cmp::Reverse<Magnitude>),
+    Zero,
+    Positive(Magnitude),
+    Infinity,
+}
+
+/// The magnitude of a nonzero number, `0.digits * 10^exponent`, where `digits` has no leading or
+/// trailing zeros. Comparing the exponents and then the digits compares the magnitudes.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Magnitude {
+    exponent: i64,
+    digits: String,
+}
+
+impl DecimalValue {
+    fn new(value: &Bson) -> Option<Self> {
+        match *value {
+            Bson::Int32(i) => Some(Self::from_parts(i < 0, &i.unsigned_abs().to_string(), 0)),
+            Bson::Int64(i) => Some(Self::from_parts(i < 0, &i.unsigned_abs().to_string(), 0)),
+            Bson::Double(d) => Some(Self::from_double(d)),
+            Bson::Decimal128(d) => Some(Self::from_decimal128(u128::from_le_bytes(d.bytes()))),
+            _ => None,
+        }
+    }
+
+    /// The number `(-1)^negative * coefficient * 10^exponent`, where `coefficient` is a string of
+    /// decimal digits.
+    fn from_parts(negative: bool, coefficient: &str, exponent: i64) -> Self {
+        let significant = coefficient.trim_start_matches('0');
+        let digits = significant.trim_end_matches('0');
+        if digits.is_empty() {
+            return Self::Zero;
+        }
+        let magnitude = Magnitude {
+            exponent: exponent + significant.len() as i64,
+            digits: digits.to_string(),
+        };
+        if negative {
+            Self::Negative(std::cmp::Reverse(magnitude))
+        } else {
+            Self::Positive(magnitude)
+        }
+    }
+
+    fn from_double(d: f64) -> Self {
+        if d.is_nan() {
+            return Self::NaN;
+        }
+        if d.is_infinite() {
+            return if d > 0.0 {
+                Self::Infinity
+            } else {
+                Self::NegativeInfinity
+            };
+        }
+        // Every double has a finite decimal expansion of at most 767 significant digits, which
+        // scientific notation with this precision writes out exactly.
+        let formatted = format!("{:.800e}", d.abs());
+        let (mantissa, exponent) = formatted.split_once('e').unwrap_or((&formatted, "0"));
+        let exponent: i64 = exponent.parse().unwrap_or(0);
+        // The mantissa has a single digit before the decimal point.
+        Self::from_parts(d < 0.0, &mantissa.replace('.', ""), exponent - 800)
+    }
+
+    /// Decodes the little-endian bits of a `Decimal128` in the binary integer decimal encoding.
+    fn from_decimal128(bits: u128) -> Self {
+        const EXPONENT_BIAS: i64 = 6176;
+        const MAX_COEFFICIENT: u128 = 9_999_999_999_999_999_999_999_999_999_999;
+
+        let negative = bits >> 127 == 1;
+        let (exponent, coefficient) = if (bits >> 125) & 0b11 == 0b11 {
+            match (bits >> 122) & 0b1_1111 {
+                0b1_1111 => return Self::NaN,
+                0b1_1110 if negative => return Self::NegativeInfinity,
+                0b1_1110 => return Self::Infinity,
+                // The coefficient of this form is larger than the maximum, so the value is zero.
+                _ => ((bits >> 111) & 0x3fff, 0),
+            }
+        } else {
+            ((bits >> 113) & 0x3fff, bits & ((1 << 113) - 1))
+        };
+        let coefficient = if coefficient > MAX_COEFFICIENT {
+            0
+        } else {
+            coefficient
+        };
+        Self::from_parts(
+            negative,
+            &coefficient.to_string(),
+            exponent as i64 - EXPONENT_BIAS,
+        )
+    }
+}
+
+/// Compares the elements of two documents in order: first by the type of their values, then by
+/// their keys, then by their values. A document that is a prefix of another sorts first.
+fn compare_elements<'a>(
+    mut a: impl Iterator<Item = (&'a String, &'a Bson)>,
+    mut b: impl Iterator<Item = (&'a String, &'a Bson)>,
+) -> Ordering {
+    loop {
+        match (a.next(), b.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some((a_key, a_value)), Some((b_key, b_value))) => {
+                let ordering = type_order(a_value)
+                    .cmp(&type_order(b_value))
+                    .then_with(|| a_key.cmp(b_key))
+                    .then_with(|| compare_ids(a_value, b_value));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use crate::{
+    bson::{doc, oid::ObjectId, Bson, DateTime, Decimal128},
+    gridfs::verify::compare_ids,
+};
+
+fn decimal(value: &str) -> Bson {
+    // The bits of a Decimal128 in the binary integer decimal encoding, for a coefficient that fits
+    // in 113 bits.
+    let (coefficient, exponent) = match value.split_once('E') {
+        Some((coefficient, exponent)) => (coefficient, exponent.parse::<i64>().unwrap()),
+        None => (value, 0),
+    };
+    let negative = coefficient.starts_with('-');
+    let coefficient = u128::from_str(coefficient.trim_start_matches('-')).unwrap();
+    let bits = (u128::from(negative) << 127) | (((exponent + 6176) as u128) << 113) | coefficient;
+    Bson::Decimal128(Decimal128::from_bytes(bits.to_le_bytes()))
+}
+
+#[test]
+fn numbers_compare_numerically() {
+    assert_eq!(
+        compare_ids(&Bson::Int32(7), &Bson::Int64(7)),
+        Ordering::Equal
+    );
+    assert_eq!(
+        compare_ids(&Bson::Int64(7), &Bson::Double(7.0)),
+        Ordering::Equal
+    );
+    assert_eq!(
+        compare_ids(&Bson::Int32(7), &Bson::Int64(8)),
+        Ordering::Less
+    );
+    assert_eq!(
+        compare_ids(&Bson::Double(7.5), &Bson::Int32(7)),
+        Ordering::Greater
+    );
+    assert_eq!(
+        compare_ids(&Bson::Double(f64::NAN), &Bson::Int64(i64::MIN)),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn types_compare_in_server_order() {
+    let ordered = vec![
+        Bson::MinKey,
+        Bson::Null,
+        Bson::Int32(100),
+        Bson::String("a".to_string()),
+        Bson::Document(doc! { "a": 1 }),
+        Bson::Array(vec![Bson::Int32(1)]),
+        Bson::ObjectId(ObjectId::new()),
+        Bson::Boolean(false),
+        Bson::DateTime(DateTime::from_millis(0)),
+        Bson::MaxKey,
+    ];
+    for (i, a) in ordered.iter().enumerate() {
+        for (j, b) in ordered.iter().enumerate() {
+            assert_eq!(compare_ids(a, b), i.cmp(&j), "{:?} and {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn values_compare_within_types() {
+    assert_eq!(
+        compare_ids(
+            &Bson::String("ab".to_string()),
+            &Bson::String("b".to_string())
+        ),
+        Ordering::Less
+    );
+    assert_eq!(
+        compare_ids(
+            &Bson::Document(doc! { "a": 1, "b": 2 }),
+            &Bson::Document(doc! { "a": 1_i64, "b": 2.0 })
+        ),
+        Ordering::Equal
+    );
+    assert_eq!(
+        compare_ids(
+            &Bson::Document(doc! { "a": 1 }),
+            &Bson::Document(doc! { "a": 1, "b": 2 })
+        ),
+        Ordering::Less
+    );
+    assert_eq!(
+        compare_ids(
+            &Bson::Document(doc! { "a": 2 }),
+            &Bson::Document(doc! { "b": 1 })
+        ),
+        Ordering::Less
+    );
+
+    let earlier = ObjectId::from_bytes([0; 12]);
+    let later = ObjectId::from_bytes([1; 12]);
+    assert_eq!(
+        compare_ids(&Bson::ObjectId(earlier), &Bson::ObjectId(later)),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn large_integers_compare_exactly_with_doubles() {
+    let two_to_53 = 9_007_199_254_740_992_i64;
+    assert_eq!(
+        compare_ids(&Bson::Int64(two_to_53 + 1), &Bson::Double(two_to_53 as f64)),
+        Ordering::Greater
+    );
+    assert_eq!(
+        compare_ids(&Bson::Double(two_to_53 as f64), &Bson::Int64(two_to_53 + 1)),
+        Ordering::Less
+    );
+    assert_eq!(
+        compare_ids(&Bson::Int64(two_to_53), &Bson::Double(two_to_53 as f64)),
+        Ordering::Equal
+    );
+    assert_eq!(
+        compare_ids(&Bson::Int64(i64::MAX), &Bson::Double(9.3e18)),
+        Ordering::Less
+    );
+    assert_eq!(
+        compare_ids(&Bson::Int64(-3), &Bson::Double(-2.5)),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn decimals_compare_by_value() {
+    assert_eq!(compare_ids(&decimal("7"), &Bson::Int32(7)), Ordering::Equal);
+    assert_eq!(
+        compare_ids(&decimal("70E-1"), &Bson::Int64(7)),
+        Ordering::Equal
+    );
+    assert_eq!(
+        compare_ids(&decimal("75E-1"), &Bson::Double(7.5)),
+        Ordering::Equal
+    );
+    assert_eq!(
+        compare_ids(&decimal("1E-1"), &Bson::Double(0.1)),
+        Ordering::Less
+    );
+    assert_eq!(compare_ids(&decimal("6"), &Bson::Int32(7)), Ordering::Less);
+    assert_eq!(
+        compare_ids(&Bson::Int64(i64::MAX), &decimal("1E19")),
+        Ordering::Less
+    );
+    assert_eq!(compare_ids(&decimal("-2"), &decimal("1")), Ordering::Less);
+    assert_eq!(compare_ids(&decimal("-2"), &decimal("-1")), Ordering::Less);
+    assert_eq!(compare_ids(&decimal("2"), &decimal("10")), Ordering::Less);
+    assert_eq!(compare_ids(&decimal("0"), &decimal("-0")), Ordering::Equal);
+    assert_eq!(
+        compare_ids(&decimal("1"), &Bson::String("a".to_string())),
+        Ordering::Less
+    );
+}
@@ -23,6 +23,40 @@ pub struct IndexModel {
     pub options: Option<IndexOptions>,
 }
 
+/// Specifies the definition and name of an Atlas Search or Atlas Vector Search index. For more
+/// information, see the [documentation](https://www.mongodb.com/docs/atlas/atlas-search/manage-indexes/).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[builder(field_defaults(default, setter(into)))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SearchIndexModel {
+    /// The definition of the index, e.g. its field mappings and analyzers. See the documentation
+    /// for [search indexes](https://www.mongodb.com/docs/atlas/atlas-search/index-definitions/)
+    /// and [vector search indexes](https://www.mongodb.com/docs/atlas/atlas-vector-search/vector-search-type/)
+    /// for the available fields.
+    pub definition: Document,
+
+    /// The name of the index. The server names the index `default` if none is specified.
+    pub name: Option<String>,
+
+    /// The type of the index. The server creates a search index if none is specified.
+    #[serde(rename = "type")]
+    pub index_type: Option<SearchIndexType>,
+}
+
+/// The type of a [`SearchIndexModel`].
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum SearchIndexType {
+    /// An Atlas Search index, queried with the `$search` aggregation stage.
+    Search,
+
+    /// An Atlas Vector Search index, queried with the `$vectorSearch` aggregation stage.
+    VectorSearch,
+}
+
 impl IndexModel {
     /// If the client did not specify a name, generate and set it. Otherwise, do nothing.
     pub(crate) fn update_name(&mut self) {
@@ -314,6 +314,7 @@ pub mod options;
 
 pub use ::bson;
 
+pub mod aggregation;
 mod bson_util;
 pub mod change_stream;
 mod client;
@@ -340,6 +341,7 @@ mod srv;
 pub mod sync;
 #[cfg(test)]
 mod test;
+pub mod update;
 
 #[cfg(test)]
 #[macro_use]
@@ -347,7 +349,7 @@ extern crate derive_more;
 
 pub use crate::{
     client::{session::ClientSession, Client},
-    coll::Collection,
+    coll::{Collection, ContinuationToken, Page, Paginator, TailableCursor},
     cursor::{
         session::{SessionCursor, SessionCursorStream},
         Cursor,
@@ -357,7 +359,12 @@ pub use crate::{
 #[cfg(feature = "csfle")]
 pub use crate::client::csfle::client_encryption;
 
-pub use {client::session::ClusterTime, coll::Namespace, index::IndexModel, sdam::public::*};
+pub use {
+    client::session::ClusterTime,
+    coll::Namespace,
+    index::{IndexModel, SearchIndexModel, SearchIndexType},
+    sdam::public::*,
+};
 
 #[cfg(all(feature = "tokio-runtime", feature = "async-std-runtime",))]
 compile_error!(
@@ -0,0 +1,387 @@
+#[cfg(test)]
+mod test;
+
+use bson::{Bson, RawArrayBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bson::{doc, Document},
+    bson_util,
+    cmap::{Command, RawCommandResponse, StreamDescription},
+    error::{BulkWriteFailure, Error, ErrorKind, Result},
+    operation::{OperationWithDefaults, Retryability, WriteResponseBody},
+    options::{
+        BulkWriteOptions,
+        DeleteModel,
+        ReplaceOneModel,
+        UpdateModel,
+        WriteConcern,
+        WriteModel,
+    },
+    results::BulkWriteResult,
+    Namespace,
+};
+
+use super::CommandBody;
+
+/// The number of bytes reserved in each message for the parts of a write command other than its
+/// entries.
+const COMMAND_OVERHEAD_SIZE: i64 = 16 * 1024;
+
+/// A single `insert`, `update`, or `delete` command sent as part of a bulk write. The command
+/// contains as many of the provided models as fit within the server's limits, all of which must
+/// use the same command.
+#[derive(Debug)]
+pub(crate) struct BulkWrite<'a, T> {
+    ns: Namespace,
+    models: &'a [WriteModel<T>],
+
+    /// The index of the first of `models` in the list of models passed to the bulk write.
+    offset: usize,
+
+    options: Option<BulkWriteOptions>,
+    encrypted: bool,
+    inserted_ids: Vec<Bson>,
+
+    /// The number of models included in the most recently built command. This is written through
+    /// a reference so that the caller can determine where the next command should begin even
+    /// when this one fails.
+    n_attempted: &'a mut usize,
+}
+
+impl<'a, T> BulkWrite<'a, T> {
+    pub(crate) fn new(
+        ns: Namespace,
+        models: &'a [WriteModel<T>],
+        offset: usize,
+        options: Option<BulkWriteOptions>,
+        encrypted: bool,
+        n_attempted: &'a mut usize,
+    ) -> Self {
+        Self {
+            ns,
+            models,
+            offset,
+            options,
+            encrypted,
+            inserted_ids: vec![],
+            n_attempted,
+        }
+    }
+
+    fn is_ordered(&self) -> bool {
+        self.options
+            .as_ref()
+            .and_then(|o| o.ordered)
+            .unwrap_or(true)
+    }
+
+    /// Whether the command can be retried. Retryability is determined before the command is
+    /// built, so the batch boundaries are chosen to match: a batch that starts with a
+    /// single-document write only contains single-document writes, while a batch that starts with
+    /// a multi-document write is not retryable.
+    fn is_retryable_batch(&self) -> bool {
+        !matches!(self.models.first(), Some(model) if model.is_multi())
+    }
+
+    fn command_name(&self) -> &'static str {
+        self.models
+            .first()
+            .map(WriteModel::command_name)
+            .unwrap_or("insert")
+    }
+
+    /// The name of the field of the command that contains its entries.
+    fn entries_key(&self) -> &'static str {
+        match self.command_name() {
+            "insert" => "documents",
+            "update" => "updates",
+            _ => "deletes",
+        }
+    }
+}
+
+impl<'a, T: Serialize> BulkWrite<'a, T> {
+    /// Builds the command entry for the model at index `i` of `models`.
+    fn entry(&mut self, i: usize) -> Result<bson::RawDocumentBuf> {
+        let document = match &self.models[i] {
+            WriteModel::InsertOne(model) => {
+                let mut doc = bson::to_raw_document_buf(&model.document)?;
+                let id = bson_util::get_or_prepend_id_field(&mut doc)?;
+                if self.inserted_ids.len() <= i {
+                    self.inserted_ids.push(id);
+                }
+                return Ok(doc);
+            }
+            WriteModel::UpdateOne(model) => update_entry(model, false)?,
+            WriteModel::UpdateMany(model) => update_entry(model, true)?,
+            WriteModel::ReplaceOne(model) => replace_entry(model)?,
+            WriteModel::DeleteOne(model) => delete_entry(model, 1)?,
+            WriteModel::DeleteMany(model) => delete_entry(model, 0)?,
+        };
+
+        Ok(bson::to_raw_document_buf(&document)?)
+    }
+}
+
+fn update_entry(model: &UpdateModel, multi: bool) -> Result<Document> {
+    let mut entry = doc! {
+        "q": model.filter.clone(),
+        "u": model.update.to_bson(),
+    };
+    if multi {
+        entry.insert("multi", true);
+    }
+    if let Some(upsert) = model.upsert {
+        entry.insert("upsert", upsert);
+    }
+    if let Some(ref array_filters) = model.array_filters {
+        entry.insert("arrayFilters", bson_util::to_bson_array(array_filters));
+    }
+    if let Some(ref hint) = model.hint {
+        entry.insert("hint", hint.to_bson());
+    }
+    if let Some(ref collation) = model.collation {
+        entry.insert("collation", bson::to_bson(collation)?);
+    }
+
+    Ok(entry)
+}
+
+fn replace_entry<T: Serialize>(model: &ReplaceOneModel<T>) -> Result<Document> {
+    let replacement = bson::to_document(&model.replacement)?;
+    bson_util::replacement_document_check(&replacement)?;
+
+    let mut entry = doc! {
+        "q": model.filter.clone(),
+        "u": replacement,
+    };
+    if let Some(upsert) = model.upsert {
+        entry.insert("upsert", upsert);
+    }
+    if let Some(ref hint) = model.hint {
+        entry.insert("hint", hint.to_bson());
+    }
+    if let Some(ref collation) = model.collation {
+        entry.insert("collation", bson::to_bson(collation)?);
+    }
+
+    Ok(entry)
+}
+
+fn delete_entry(model: &DeleteModel, limit: u32) -> Result<Document> {
+    let mut entry = doc! {
+        "q": model.filter.clone(),
+        "limit": limit,
+    };
+    if let Some(ref hint) = model.hint {
+        entry.insert("hint", bson::to_bson(hint)?);
+    }
+    if let Some(ref collation) = model.collation {
+        entry.insert("collation", bson::to_bson(collation)?);
+    }
+
+    Ok(entry)
+}
+
+impl<'a, T: Serialize> OperationWithDefaults for BulkWrite<'a, T> {
+    type O = BulkWriteResult;
+    type Command = BulkWriteCommand;
+
+    const NAME: &'static str = "bulkWrite";
+
+    fn build(&mut self, description: &StreamDescription) -> Result<Command<BulkWriteCommand>> {
+        let mut entries = RawArrayBuf::new();
+        let mut size = 0;
+        // Commands are sent as a single document, which the server allows to exceed
+        // maxBsonObjectSize by enough to fit the fields other than the entries.
+        let batch_size_limit = if self.encrypted {
+            2_097_152
+        } else {
+            std::cmp::min(
+                description.max_bson_object_size,
+                description.max_message_size_bytes as i64 - COMMAND_OVERHEAD_SIZE,
+            )
+            .max(0) as u64
+        };
+
+        let mut max_batch_len = std::cmp::min(
+            self.models.len(),
+            description.max_write_batch_size.max(0) as usize,
+        );
+        // A batch that starts with a single-document write is retryable, so it ends before the
+        // first multi-document write, which cannot be retried.
+        if self.is_retryable_batch() {
+            if let Some(multi) = self.models[..max_batch_len]
+                .iter()
+                .position(WriteModel::is_multi)
+            {
+                max_batch_len = multi;
+            }
+        }
+        let mut n_attempted = 0;
+        for i in 0..max_batch_len {
+            let entry = self.entry(i)?;
+            let entry_size = bson_util::array_entry_size_bytes(i, entry.as_bytes().len());
+            if size + entry_size > batch_size_limit {
+                break;
+            }
+            entries.push(entry);
+            size += entry_size;
+            n_attempted += 1;
+        }
+        *self.n_attempted = n_attempted;
+
+        if n_attempted == 0 {
+            return Err(ErrorKind::InvalidArgument {
+                message: format!(
+                    "write model at index {} exceeds maxBsonObjectSize",
+                    self.offset
+                ),
+            }
+            .into());
+        }
+
+        let command_name = self.command_name();
+        let mut body = doc! {
+            command_name: self.ns.coll.clone(),
+            "ordered": self.is_ordered(),
+        };
+        if let Some(ref options) = self.options {
+            if command_name != "delete" {
+                if let Some(bypass_doc_validation) = options.bypass_document_validation {
+                    body.insert("bypassDocumentValidation", bypass_doc_validation);
+                }
+            }
+            if let Some(ref write_concern) = options.write_concern {
+                if !write_concern.is_empty() {
+                    body.insert("writeConcern", bson::to_bson(write_concern)?);
+                }
+            }
+            if command_name != "insert" {
+                if let Some(ref let_vars) = options.let_vars {
+                    body.insert("let", let_vars);
+                }
+            }
+            if let Some(ref comment) = options.comment {
+                body.insert("comment", comment);
+            }
+        }
+
+        Ok(Command::new(
+            command_name.to_string(),
+            self.ns.db.clone(),
+            BulkWriteCommand { body, entries },
+        ))
+    }
+
+    fn serialize_command(&mut self, cmd: Command<Self::Command>) -> Result<Vec<u8>> {
+        let mut doc = bson::to_raw_document_buf(&cmd)?;
+        // need to append entries separately because #[serde(flatten)] breaks the custom
+        // serialization logic. See https://github.com/serde-rs/serde/issues/2106.
+        doc.append(self.entries_key(), cmd.body.entries);
+        Ok(doc.into_bytes())
+    }
+
+    fn handle_response(
+        &self,
+        raw_response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        let response: WriteResponseBody<BulkWriteBody> = raw_response.body_utf8_lossy()?;
+        let n_attempted = *self.n_attempted;
+
+        let mut write_errors = response.write_errors.clone();
+        if let Some(ref mut write_errors) = write_errors {
+            for error in write_errors.iter_mut() {
+                error.index += self.offset;
+            }
+        }
+
+        let mut result = BulkWriteResult::new();
+        match self.command_name() {
+            "insert" => {
+                result.inserted_count = response.n;
+                let attempted_ids = self.inserted_ids.iter().take(n_attempted).enumerate();
+                if self.is_ordered() {
+                    // in ordered inserts, only the first n were attempted.
+                    for (i, id) in attempted_ids.take(response.n as usize) {
+                        result.inserted_ids.insert(i + self.offset, id.clone());
+                    }
+                } else {
+                    for (i, id) in attempted_ids {
+                        result.inserted_ids.insert(i + self.offset, id.clone());
+                    }
+                    for error in write_errors.iter().flatten() {
+                        result.inserted_ids.remove(&error.index);
+                    }
+                }
+            }
+            "update" => {
+                let upserted = response.upserted.as_deref().unwrap_or_default();
+                result.upserted_count = upserted.len() as u64;
+                result.matched_count = response.n.saturating_sub(result.upserted_count);
+                result.modified_count = response.n_modified.unwrap_or(0);
+                for upserted in upserted {
+                    result
+                        .upserted_ids
+                        .insert(upserted.index + self.offset, upserted.id.clone());
+                }
+            }
+            _ => result.deleted_count = response.n,
+        }
+
+        if write_errors.is_some() || response.write_concern_error.is_some() {
+            return Err(Error::new(
+                ErrorKind::BulkWrite(BulkWriteFailure {
+                    write_errors,
+                    write_concern_error: response.write_concern_error.clone(),
+                    inserted_ids: result.inserted_ids.clone(),
+                    partial_result: Some(result),
+                }),
+                response.labels.clone(),
+            ));
+        }
+
+        Ok(result)
+    }
+
+    fn write_concern(&self) -> Option<&WriteConcern> {
+        self.options.as_ref().and_then(|o| o.write_concern.as_ref())
+    }
+
+    fn retryability(&self) -> Retryability {
+        if self.is_retryable_batch() {
+            Retryability::Write
+        } else {
+            Retryability::None
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub(crate) struct BulkWriteCommand {
+    #[serde(flatten)]
+    body: Document,
+
+    /// will be serialized in `serialize_command`
+    #[serde(skip)]
+    entries: RawArrayBuf,
+}
+
+impl CommandBody for BulkWriteCommand {}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BulkWriteBody {
+    n_modified: Option<u64>,
+    upserted: Option<Vec<Upserted>>,
+}
+
+#[derive(Deserialize)]
+struct Upserted {
+    index: usize,
+
+    #[serde(rename = "_id")]
+    id: Bson,
+}
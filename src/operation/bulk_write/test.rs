@@ -0,0 +1,300 @@
+use crate::{
+    bson::{doc, Bson, Document},
+    cmap::StreamDescription,
+    error::ErrorKind,
+    operation::{test::handle_response_test, BulkWrite, Operation, Retryability},
+    options::{
+        BulkWriteOptions,
+        DeleteModel,
+        InsertOneModel,
+        ReplaceOneModel,
+        UpdateModel,
+        WriteModel,
+    },
+    Namespace,
+};
+
+fn description() -> StreamDescription {
+    let mut description = StreamDescription::new_testing();
+    description.max_message_size_bytes = 48_000_000;
+    description
+}
+
+fn insert_model(document: Document) -> WriteModel<Document> {
+    WriteModel::InsertOne(InsertOneModel::builder().document(document).build())
+}
+
+fn build_command(op: &mut BulkWrite<Document>, description: &StreamDescription) -> Document {
+    let cmd = op.build(description).unwrap();
+    let serialized = op.serialize_command(cmd).unwrap();
+    Document::from_reader(serialized.as_slice()).unwrap()
+}
+
+#[test]
+fn build_update() {
+    let models = vec![
+        WriteModel::UpdateMany(
+            UpdateModel::builder()
+                .filter(doc! { "x": 2 })
+                .update(doc! { "$inc": { "y": 1 } })
+                .build(),
+        ),
+        WriteModel::UpdateOne(
+            UpdateModel::builder()
+                .filter(doc! { "x": 1 })
+                .update(doc! { "$set": { "y": 1 } })
+                .upsert(true)
+                .build(),
+        ),
+        WriteModel::ReplaceOne(
+            ReplaceOneModel::builder()
+                .filter(doc! { "x": 3 })
+                .replacement(doc! { "x": 3, "y": 3 })
+                .build(),
+        ),
+    ];
+    let options = BulkWriteOptions::builder()
+        .ordered(false)
+        .let_vars(doc! { "a": 1 })
+        .build();
+
+    let mut n_attempted = 0;
+    let mut op = BulkWrite::new(
+        Namespace::empty(),
+        &models,
+        0,
+        Some(options),
+        false,
+        &mut n_attempted,
+    );
+    let cmd_doc = build_command(&mut op, &description());
+
+    assert_eq!(cmd_doc.get_bool("ordered"), Ok(false));
+    assert_eq!(cmd_doc.get_document("let"), Ok(&doc! { "a": 1 }));
+    assert_eq!(
+        cmd_doc.get_array("updates").unwrap(),
+        &vec![
+            Bson::Document(doc! { "q": { "x": 2 }, "u": { "$inc": { "y": 1 } }, "multi": true }),
+            Bson::Document(doc! { "q": { "x": 1 }, "u": { "$set": { "y": 1 } }, "upsert": true }),
+            Bson::Document(doc! { "q": { "x": 3 }, "u": { "x": 3, "y": 3 } }),
+        ]
+    );
+    assert_eq!(op.retryability(), Retryability::None);
+    drop(op);
+    assert_eq!(n_attempted, 3);
+}
+
+#[test]
+fn build_delete() {
+    let models: Vec<WriteModel<Document>> = vec![
+        WriteModel::DeleteOne(DeleteModel::builder().filter(doc! { "x": 1 }).build()),
+        WriteModel::DeleteOne(DeleteModel::builder().filter(doc! { "x": 2 }).build()),
+    ];
+    let options = BulkWriteOptions::builder()
+        .bypass_document_validation(true)
+        .build();
+
+    let mut n_attempted = 0;
+    let mut op = BulkWrite::new(
+        Namespace::empty(),
+        &models,
+        0,
+        Some(options),
+        false,
+        &mut n_attempted,
+    );
+    let cmd_doc = build_command(&mut op, &description());
+
+    assert_eq!(cmd_doc.get_bool("ordered"), Ok(true));
+    assert!(cmd_doc.get("bypassDocumentValidation").is_none());
+    assert_eq!(
+        cmd_doc.get_array("deletes").unwrap(),
+        &vec![
+            Bson::Document(doc! { "q": { "x": 1 }, "limit": 1 }),
+            Bson::Document(doc! { "q": { "x": 2 }, "limit": 1 }),
+        ]
+    );
+    assert_eq!(op.retryability(), Retryability::Write);
+}
+
+#[test]
+fn retryable_batches_end_before_multi_writes() {
+    let models: Vec<WriteModel<Document>> = vec![
+        WriteModel::DeleteOne(DeleteModel::builder().filter(doc! { "x": 1 }).build()),
+        WriteModel::DeleteMany(DeleteModel::builder().filter(doc! { "x": 2 }).build()),
+        WriteModel::DeleteOne(DeleteModel::builder().filter(doc! { "x": 3 }).build()),
+    ];
+
+    let mut n_attempted = 0;
+    let mut op = BulkWrite::new(
+        Namespace::empty(),
+        &models,
+        0,
+        None,
+        false,
+        &mut n_attempted,
+    );
+    assert_eq!(op.retryability(), Retryability::Write);
+    let cmd_doc = build_command(&mut op, &description());
+    assert_eq!(cmd_doc.get_array("deletes").unwrap().len(), 1);
+    drop(op);
+    assert_eq!(n_attempted, 1);
+
+    let mut op = BulkWrite::new(
+        Namespace::empty(),
+        &models[1..],
+        1,
+        None,
+        false,
+        &mut n_attempted,
+    );
+    assert_eq!(op.retryability(), Retryability::None);
+    let cmd_doc = build_command(&mut op, &description());
+    assert_eq!(cmd_doc.get_array("deletes").unwrap().len(), 2);
+    drop(op);
+    assert_eq!(n_attempted, 2);
+}
+
+#[test]
+fn build_splits_batches() {
+    let models: Vec<_> = (0..5).map(|i| insert_model(doc! { "_id": i })).collect();
+
+    let mut description = description();
+    description.max_write_batch_size = 2;
+    let mut n_attempted = 0;
+    let mut op = BulkWrite::new(
+        Namespace::empty(),
+        &models,
+        0,
+        None,
+        false,
+        &mut n_attempted,
+    );
+    let cmd_doc = build_command(&mut op, &description);
+    assert_eq!(cmd_doc.get_array("documents").unwrap().len(), 2);
+    drop(op);
+    assert_eq!(n_attempted, 2);
+
+    // each document is 14 bytes, and its array entry is 17 bytes.
+    let mut description = self::description();
+    description.max_message_size_bytes = 16 * 1024 + 3 * 17;
+    let mut op = BulkWrite::new(
+        Namespace::empty(),
+        &models,
+        0,
+        None,
+        false,
+        &mut n_attempted,
+    );
+    let cmd_doc = build_command(&mut op, &description);
+    assert_eq!(cmd_doc.get_array("documents").unwrap().len(), 3);
+    drop(op);
+    assert_eq!(n_attempted, 3);
+}
+
+#[test]
+fn handle_response_offsets_indexes() {
+    let models: Vec<WriteModel<Document>> = vec![
+        WriteModel::UpdateOne(
+            UpdateModel::builder()
+                .filter(doc! { "x": 1 })
+                .update(doc! { "$set": { "y": 1 } })
+                .upsert(true)
+                .build(),
+        ),
+        WriteModel::UpdateOne(
+            UpdateModel::builder()
+                .filter(doc! { "x": 2 })
+                .update(doc! { "$set": { "y": 1 } })
+                .build(),
+        ),
+    ];
+
+    let mut n_attempted = 0;
+    let mut op = BulkWrite::new(
+        Namespace::empty(),
+        &models,
+        3,
+        None,
+        false,
+        &mut n_attempted,
+    );
+    op.build(&description()).unwrap();
+
+    let result = handle_response_test(
+        &op,
+        doc! {
+            "ok": 1.0,
+            "n": 2,
+            "nModified": 1,
+            "upserted": [{ "index": 0, "_id": 12 }],
+        },
+    )
+    .unwrap();
+    assert_eq!(result.matched_count, 1);
+    assert_eq!(result.modified_count, 1);
+    assert_eq!(result.upserted_count, 1);
+    assert_eq!(result.upserted_ids.get(&3), Some(&Bson::Int32(12)));
+
+    let error = handle_response_test(
+        &op,
+        doc! {
+            "ok": 1.0,
+            "n": 1,
+            "nModified": 0,
+            "writeErrors": [{ "index": 1, "code": 11000, "errmsg": "duplicate key" }],
+        },
+    )
+    .unwrap_err();
+    match *error.kind {
+        ErrorKind::BulkWrite(ref failure) => {
+            let write_errors = failure.write_errors.as_ref().unwrap();
+            assert_eq!(write_errors.len(), 1);
+            assert_eq!(write_errors[0].index, 4);
+        }
+        ref e => panic!("expected bulk write error, got {:?}", e),
+    }
+}
+
+#[test]
+fn handle_response_inserted_ids() {
+    let models = vec![
+        insert_model(doc! { "_id": 1 }),
+        insert_model(doc! { "_id": 1 }),
+        insert_model(doc! { "_id": 2 }),
+    ];
+
+    let options = BulkWriteOptions::builder().ordered(false).build();
+    let mut n_attempted = 0;
+    let mut op = BulkWrite::new(
+        Namespace::empty(),
+        &models,
+        1,
+        Some(options),
+        false,
+        &mut n_attempted,
+    );
+    op.build(&description()).unwrap();
+
+    let error = handle_response_test(
+        &op,
+        doc! {
+            "ok": 1.0,
+            "n": 2,
+            "writeErrors": [{ "index": 1, "code": 11000, "errmsg": "duplicate key" }],
+        },
+    )
+    .unwrap_err();
+    match *error.kind {
+        ErrorKind::BulkWrite(ref failure) => {
+            assert_eq!(failure.write_errors.as_ref().unwrap()[0].index, 2);
+            assert_eq!(failure.inserted_ids.len(), 2);
+            assert_eq!(failure.inserted_ids.get(&1), Some(&Bson::Int32(1)));
+            assert_eq!(failure.inserted_ids.get(&3), Some(&Bson::Int32(2)));
+            let partial_result = failure.partial_result.as_ref().unwrap();
+            assert_eq!(partial_result.inserted_count, 2);
+            assert_eq!(partial_result.inserted_ids, failure.inserted_ids);
+        }
+        ref e => panic!("expected bulk write error, got {:?}", e),
+    }
+}
@@ -0,0 +1,399 @@
+#[cfg(test)]
+mod test;
+
+use std::collections::HashMap;
+
+use bson::{Bson, RawDocumentBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bson::{doc, Document},
+    bson_util,
+    cmap::{Command, RawCommandResponse, StreamDescription},
+    cursor::CursorSpecification,
+    error::{ErrorKind, Result, WriteConcernError},
+    operation::{CursorInfo, OperationWithDefaults, Retryability},
+    options::{
+        ClientBulkWriteOptions,
+        DeleteModel,
+        ReplaceOneModel,
+        UpdateModel,
+        WriteConcern,
+        WriteModel,
+    },
+    Namespace,
+};
+
+/// The number of bytes reserved in each message for the parts of the message other than the
+/// command body and its document sequences.
+const MESSAGE_OVERHEAD_SIZE: usize = 1000;
+
+/// The number of bytes taken up by the `ops` and `nsInfo` document sequences other than their
+/// documents: a payload type, a length, and a null-terminated identifier for each.
+const DOCUMENT_SEQUENCES_OVERHEAD_SIZE: usize = 2 * (1 + 4 + 1) + "ops".len() + "nsInfo".len();
+
+/// A single `bulkWrite` command sent as part of a
+/// [`Client::bulk_write`](crate::Client::bulk_write). The command contains as many of the provided
+/// models as fit within the server's limits, which may target any number of namespaces.
+#[derive(Debug)]
+pub(crate) struct ClientBulkWrite<'a, T> {
+    models: &'a [(Namespace, WriteModel<T>)],
+
+    /// The index of the first of `models` in the list of models passed to the bulk write.
+    offset: usize,
+
+    options: Option<ClientBulkWriteOptions>,
+
+    /// The `_id` fields of the documents inserted by the command, keyed by the index of the
+    /// corresponding model in `models`. These are recorded so that a retried command inserts the
+    /// same documents as the original attempt.
+    inserted_ids: HashMap<usize, Bson>,
+
+    /// The number of models included in the most recently built command. This is written through
+    /// a reference so that the caller can determine where the next command should begin even
+    /// when this one fails.
+    n_attempted: &'a mut usize,
+}
+
+impl<'a, T> ClientBulkWrite<'a, T> {
+    pub(crate) fn new(
+        models: &'a [(Namespace, WriteModel<T>)],
+        offset: usize,
+        options: Option<ClientBulkWriteOptions>,
+        n_attempted: &'a mut usize,
+    ) -> Self {
+        Self {
+            models,
+            offset,
+            options,
+            inserted_ids: HashMap::new(),
+            n_attempted,
+        }
+    }
+
+    fn is_ordered(&self) -> bool {
+        self.options
+            .as_ref()
+            .and_then(|o| o.ordered)
+            .unwrap_or(true)
+    }
+
+    /// Whether the command can be retried. Retryability is determined before the command is
+    /// built, so the batch boundaries are chosen to match: a batch that starts with a
+    /// single-document write only contains single-document writes and is retryable, while a batch
+    /// that starts with a multi-document write is not.
+    fn is_retryable_batch(&self) -> bool {
+        !matches!(self.models.first(), Some((_, model)) if model.is_multi())
+    }
+
+    fn is_verbose(&self) -> bool {
+        self.options
+            .as_ref()
+            .and_then(|o| o.verbose_results)
+            .unwrap_or(false)
+    }
+}
+
+impl<'a, T: Serialize> ClientBulkWrite<'a, T> {
+    /// Builds the entry in the `ops` document sequence for the model at index `i` of `models`,
+    /// which targets the namespace at index `ns_index` of the `nsInfo` document sequence.
+    fn op(&mut self, i: usize, ns_index: usize) -> Result<RawDocumentBuf> {
+        let ns_index = ns_index as i32;
+        let op = match &self.models[i].1 {
+            WriteModel::InsertOne(model) => {
+                let mut document = bson::to_raw_document_buf(&model.document)?;
+                match self.inserted_ids.get(&i) {
+                    Some(id) => {
+                        if document.get("_id")?.is_none() {
+                            bson_util::prepend_id_field(&mut document, id)?;
+                        }
+                    }
+                    None => {
+                        let id = bson_util::get_or_prepend_id_field(&mut document)?;
+                        self.inserted_ids.insert(i, id);
+                    }
+                }
+
+                let mut op = RawDocumentBuf::new();
+                op.append("insert", ns_index);
+                op.append("document", document);
+                return Ok(op);
+            }
+            WriteModel::UpdateOne(model) => update_op(ns_index, model, false)?,
+            WriteModel::UpdateMany(model) => update_op(ns_index, model, true)?,
+            WriteModel::ReplaceOne(model) => replace_op(ns_index, model)?,
+            WriteModel::DeleteOne(model) => delete_op(ns_index, model, false)?,
+            WriteModel::DeleteMany(model) => delete_op(ns_index, model, true)?,
+        };
+
+        Ok(bson::to_raw_document_buf(&op)?)
+    }
+}
+
+fn update_op(ns_index: i32, model: &UpdateModel, multi: bool) -> Result<Document> {
+    let mut op = doc! {
+        "update": ns_index,
+        "filter": model.filter.clone(),
+        "updateMods": model.update.to_bson(),
+        "multi": multi,
+    };
+    if let Some(upsert) = model.upsert {
+        op.insert("upsert", upsert);
+    }
+    if let Some(ref array_filters) = model.array_filters {
+        op.insert("arrayFilters", bson_util::to_bson_array(array_filters));
+    }
+    if let Some(ref hint) = model.hint {
+        op.insert("hint", hint.to_bson());
+    }
+    if let Some(ref collation) = model.collation {
+        op.insert("collation", bson::to_bson(collation)?);
+    }
+
+    Ok(op)
+}
+
+fn replace_op<T: Serialize>(ns_index: i32, model: &ReplaceOneModel<T>) -> Result<Document> {
+    let replacement = bson::to_document(&model.replacement)?;
+    bson_util::replacement_document_check(&replacement)?;
+
+    let mut op = doc! {
+        "update": ns_index,
+        "filter": model.filter.clone(),
+        "updateMods": replacement,
+        "multi": false,
+    };
+    if let Some(upsert) = model.upsert {
+        op.insert("upsert", upsert);
+    }
+    if let Some(ref hint) = model.hint {
+        op.insert("hint", hint.to_bson());
+    }
+    if let Some(ref collation) = model.collation {
+        op.insert("collation", bson::to_bson(collation)?);
+    }
+
+    Ok(op)
+}
+
+fn delete_op(ns_index: i32, model: &DeleteModel, multi: bool) -> Result<Document> {
+    let mut op = doc! {
+        "delete": ns_index,
+        "filter": model.filter.clone(),
+        "multi": multi,
+    };
+    if let Some(ref hint) = model.hint {
+        op.insert("hint", bson::to_bson(hint)?);
+    }
+    if let Some(ref collation) = model.collation {
+        op.insert("collation", bson::to_bson(collation)?);
+    }
+
+    Ok(op)
+}
+
+impl<'a, T: Serialize> OperationWithDefaults for ClientBulkWrite<'a, T> {
+    type O = (CursorSpecification, ClientBulkWriteSummary);
+    type Command = Document;
+
+    const NAME: &'static str = "bulkWrite";
+
+    fn build(&mut self, description: &StreamDescription) -> Result<Command> {
+        let mut body = doc! {
+            Self::NAME: 1,
+            "errorsOnly": !self.is_verbose(),
+            "ordered": self.is_ordered(),
+        };
+        if let Some(ref options) = self.options {
+            if let Some(bypass_doc_validation) = options.bypass_document_validation {
+                body.insert("bypassDocumentValidation", bypass_doc_validation);
+            }
+            if let Some(ref write_concern) = options.write_concern {
+                if !write_concern.is_empty() {
+                    body.insert("writeConcern", bson::to_bson(write_concern)?);
+                }
+            }
+            if let Some(ref let_vars) = options.let_vars {
+                body.insert("let", let_vars);
+            }
+            if let Some(ref comment) = options.comment {
+                body.insert("comment", comment);
+            }
+        }
+
+        // The ops and nsInfo documents are sent as document sequences, so their total size is
+        // limited by maxMessageSizeBytes rather than maxBsonObjectSize.
+        let body_size = bson::to_vec(&body)?.len();
+        let size_limit = (description.max_message_size_bytes.max(0) as usize)
+            .saturating_sub(MESSAGE_OVERHEAD_SIZE + body_size + DOCUMENT_SEQUENCES_OVERHEAD_SIZE);
+        let max_document_size = description.max_bson_object_size.max(0) as usize;
+
+        let mut max_batch_len = std::cmp::min(
+            self.models.len(),
+            description.max_write_batch_size.max(0) as usize,
+        );
+        // A batch that starts with a single-document write is retryable, so it ends before the
+        // first multi-document write, which cannot be retried.
+        if self.is_retryable_batch() {
+            if let Some(multi) = self.models[..max_batch_len]
+                .iter()
+                .position(|(_, model)| model.is_multi())
+            {
+                max_batch_len = multi;
+            }
+        }
+        let mut ops = Vec::new();
+        let mut ns_info = Vec::new();
+        let mut ns_indexes: HashMap<String, usize> = HashMap::new();
+        let mut size = 0;
+        for i in 0..max_batch_len {
+            let ns = self.models[i].0.to_string();
+            let (ns_index, ns_entry) = match ns_indexes.get(&ns) {
+                Some(ns_index) => (*ns_index, None),
+                None => {
+                    let ns_entry = bson::to_raw_document_buf(&doc! { "ns": ns.as_str() })?;
+                    (ns_indexes.len(), Some(ns_entry))
+                }
+            };
+
+            let op = self.op(i, ns_index)?;
+            if op.as_bytes().len() > max_document_size {
+                return Err(ErrorKind::InvalidArgument {
+                    message: format!(
+                        "write model at index {} exceeds maxBsonObjectSize",
+                        self.offset + i
+                    ),
+                }
+                .into());
+            }
+
+            let entry_size =
+                op.as_bytes().len() + ns_entry.as_ref().map_or(0, |e| e.as_bytes().len());
+            if size + entry_size > size_limit {
+                break;
+            }
+            size += entry_size;
+            ops.push(op);
+            if let Some(ns_entry) = ns_entry {
+                ns_indexes.insert(ns, ns_indexes.len());
+                ns_info.push(ns_entry);
+            }
+        }
+        *self.n_attempted = ops.len();
+
+        if ops.is_empty() {
+            return Err(ErrorKind::InvalidArgument {
+                message: format!(
+                    "write model at index {} exceeds maxMessageSizeBytes",
+                    self.offset
+                ),
+            }
+            .into());
+        }
+
+        let mut command = Command::new(Self::NAME.to_string(), "admin".to_string(), body);
+        command.add_document_sequence("ops", ops);
+        command.add_document_sequence("nsInfo", ns_info);
+        Ok(command)
+    }
+
+    fn handle_response(
+        &self,
+        raw_response: RawCommandResponse,
+        description: &StreamDescription,
+    ) -> Result<Self::O> {
+        let response: ClientBulkWriteResponse = raw_response.body_utf8_lossy()?;
+
+        let summary = ClientBulkWriteSummary {
+            n_errors: response.n_errors,
+            n_inserted: response.n_inserted,
+            n_upserted: response.n_upserted,
+            n_matched: response.n_matched,
+            n_modified: response.n_modified,
+            n_deleted: response.n_deleted,
+            write_concern_error: response.write_concern_error,
+            inserted_ids: self
+                .inserted_ids
+                .iter()
+                .filter(|(i, _)| **i < *self.n_attempted)
+                .map(|(i, id)| (i + self.offset, id.clone()))
+                .collect(),
+        };
+        let comment = self.options.as_ref().and_then(|o| o.comment.clone());
+        let spec = CursorSpecification::new(
+            response.cursor,
+            description.server_address.clone(),
+            None,
+            None,
+            comment,
+        );
+
+        Ok((spec, summary))
+    }
+
+    fn write_concern(&self) -> Option<&WriteConcern> {
+        self.options.as_ref().and_then(|o| o.write_concern.as_ref())
+    }
+
+    fn retryability(&self) -> Retryability {
+        if self.is_retryable_batch() {
+            Retryability::Write
+        } else {
+            Retryability::None
+        }
+    }
+}
+
+/// The summary fields of a `bulkWrite` command response. The results of the individual writes
+/// are returned through the response's cursor.
+#[derive(Debug)]
+pub(crate) struct ClientBulkWriteSummary {
+    pub(crate) n_errors: u64,
+    pub(crate) n_inserted: u64,
+    pub(crate) n_upserted: u64,
+    pub(crate) n_matched: u64,
+    pub(crate) n_modified: u64,
+    pub(crate) n_deleted: u64,
+    pub(crate) write_concern_error: Option<WriteConcernError>,
+
+    /// The `_id` fields of the documents included in the command's inserts, keyed by the index
+    /// of the corresponding model in the list of models passed to the bulk write.
+    pub(crate) inserted_ids: HashMap<usize, Bson>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ClientBulkWriteResponse {
+    cursor: CursorInfo,
+    n_errors: u64,
+    n_inserted: u64,
+    n_upserted: u64,
+    n_matched: u64,
+    n_modified: u64,
+    n_deleted: u64,
+    write_concern_error: Option<WriteConcernError>,
+}
+
+/// The result of a single write returned through the cursor of a `bulkWrite` command response.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SingleOperationResponse {
+    #[serde(deserialize_with = "bson_util::deserialize_u64_from_bson_number")]
+    pub(crate) ok: u64,
+
+    /// The index of the write within the command.
+    pub(crate) idx: usize,
+
+    #[serde(default)]
+    pub(crate) n: u64,
+
+    pub(crate) n_modified: Option<u64>,
+
+    pub(crate) upserted: Option<UpsertedId>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct UpsertedId {
+    #[serde(rename = "_id")]
+    pub(crate) id: Bson,
+}
@@ -0,0 +1,206 @@
+use crate::{
+    bson::{doc, Bson, Document},
+    cmap::{Command, StreamDescription},
+    operation::{test::handle_response_test, ClientBulkWrite, Operation, Retryability},
+    options::{ClientBulkWriteOptions, DeleteModel, InsertOneModel, UpdateModel, WriteModel},
+    Namespace,
+};
+
+fn description() -> StreamDescription {
+    let mut description = StreamDescription::new_testing();
+    description.max_message_size_bytes = 48_000_000;
+    description
+}
+
+fn ns(coll: &str) -> Namespace {
+    Namespace {
+        db: "db".to_string(),
+        coll: coll.to_string(),
+    }
+}
+
+fn insert_model(document: Document) -> WriteModel<Document> {
+    WriteModel::InsertOne(InsertOneModel::builder().document(document).build())
+}
+
+fn sequence(command: &Command, identifier: &str) -> Vec<Document> {
+    command
+        .document_sequences
+        .iter()
+        .find(|sequence| sequence.identifier == identifier)
+        .unwrap()
+        .documents
+        .iter()
+        .map(|doc| doc.to_document().unwrap())
+        .collect()
+}
+
+#[test]
+fn build() {
+    let models = vec![
+        (ns("a"), insert_model(doc! { "_id": 1 })),
+        (
+            ns("b"),
+            WriteModel::UpdateOne(
+                UpdateModel::builder()
+                    .filter(doc! { "x": 1 })
+                    .update(doc! { "$set": { "y": 1 } })
+                    .upsert(true)
+                    .build(),
+            ),
+        ),
+        (
+            ns("a"),
+            WriteModel::DeleteOne(DeleteModel::builder().filter(doc! { "x": 2 }).build()),
+        ),
+    ];
+    let options = ClientBulkWriteOptions::builder()
+        .ordered(false)
+        .verbose_results(true)
+        .let_vars(doc! { "a": 1 })
+        .build();
+
+    let mut n_attempted = 0;
+    let mut op = ClientBulkWrite::new(&models, 0, Some(options), &mut n_attempted);
+    let command = op.build(&description()).unwrap();
+
+    assert_eq!(command.name, "bulkWrite");
+    assert_eq!(command.target_db, "admin");
+    assert_eq!(
+        command.body,
+        doc! { "bulkWrite": 1, "errorsOnly": false, "ordered": false, "let": { "a": 1 } }
+    );
+    assert_eq!(
+        sequence(&command, "ops"),
+        vec![
+            doc! { "insert": 0, "document": { "_id": 1 } },
+            doc! {
+                "update": 1,
+                "filter": { "x": 1 },
+                "updateMods": { "$set": { "y": 1 } },
+                "multi": false,
+                "upsert": true,
+            },
+            doc! { "delete": 0, "filter": { "x": 2 }, "multi": false },
+        ]
+    );
+    assert_eq!(
+        sequence(&command, "nsInfo"),
+        vec![doc! { "ns": "db.a" }, doc! { "ns": "db.b" }]
+    );
+    assert_eq!(op.retryability(), Retryability::Write);
+    drop(op);
+    assert_eq!(n_attempted, 3);
+}
+
+#[test]
+fn build_retry_reuses_generated_ids() {
+    let models = vec![(ns("a"), insert_model(doc! { "x": 1 }))];
+
+    let mut n_attempted = 0;
+    let mut op = ClientBulkWrite::new(&models, 0, None, &mut n_attempted);
+    let first = sequence(&op.build(&description()).unwrap(), "ops");
+    let second = sequence(&op.build(&description()).unwrap(), "ops");
+
+    let id = first[0].get_document("document").unwrap().get("_id");
+    assert!(matches!(id, Some(Bson::ObjectId(_))));
+    assert_eq!(first, second);
+}
+
+#[test]
+fn build_splits_batches() {
+    let models: Vec<_> = (0..5)
+        .map(|i| (ns("a"), insert_model(doc! { "_id": i })))
+        .collect();
+
+    let mut description = description();
+    description.max_write_batch_size = 2;
+    let mut n_attempted = 0;
+    let mut op = ClientBulkWrite::new(&models, 0, None, &mut n_attempted);
+    let command = op.build(&description).unwrap();
+    assert_eq!(sequence(&command, "ops").len(), 2);
+    drop(op);
+    assert_eq!(n_attempted, 2);
+
+    // each op is 41 bytes, the nsInfo entry is 18 bytes, the command body is 43 bytes, and the
+    // sequence headers are 21 bytes.
+    let mut description = self::description();
+    description.max_message_size_bytes = 1000 + 43 + 21 + 18 + 3 * 41;
+    let mut op = ClientBulkWrite::new(&models, 0, None, &mut n_attempted);
+    let command = op.build(&description).unwrap();
+    assert_eq!(sequence(&command, "ops").len(), 3);
+    assert_eq!(sequence(&command, "nsInfo").len(), 1);
+    drop(op);
+    assert_eq!(n_attempted, 3);
+}
+
+#[test]
+fn retryability() {
+    let models: Vec<(Namespace, WriteModel<Document>)> = vec![
+        (ns("a"), insert_model(doc! { "_id": 1 })),
+        (
+            ns("b"),
+            WriteModel::DeleteMany(DeleteModel::builder().filter(doc! {}).build()),
+        ),
+        (ns("a"), insert_model(doc! { "_id": 2 })),
+    ];
+
+    let mut n_attempted = 0;
+
+    // The batch that starts with the insert ends before the delete so that it can be retried.
+    let mut op = ClientBulkWrite::new(&models, 0, None, &mut n_attempted);
+    assert_eq!(op.retryability(), Retryability::Write);
+    let command = op.build(&description()).unwrap();
+    assert_eq!(sequence(&command, "ops").len(), 1);
+    drop(op);
+    assert_eq!(n_attempted, 1);
+
+    // The batch that starts with the delete cannot be retried and includes the remaining models.
+    let mut op = ClientBulkWrite::new(&models[1..], 1, None, &mut n_attempted);
+    assert_eq!(op.retryability(), Retryability::None);
+    let command = op.build(&description()).unwrap();
+    assert_eq!(sequence(&command, "ops").len(), 2);
+    drop(op);
+    assert_eq!(n_attempted, 2);
+}
+
+#[test]
+fn handle_response() {
+    let models = vec![
+        (ns("a"), insert_model(doc! { "_id": 1 })),
+        (ns("b"), insert_model(doc! { "_id": 2 })),
+    ];
+
+    let mut n_attempted = 0;
+    let mut op = ClientBulkWrite::new(&models, 5, None, &mut n_attempted);
+    op.build(&description()).unwrap();
+
+    let (spec, summary) = handle_response_test(
+        &op,
+        doc! {
+            "ok": 1.0,
+            "cursor": {
+                "id": 0_i64,
+                "firstBatch": [{ "ok": 0.0, "idx": 1, "code": 11000, "errmsg": "duplicate key" }],
+                "ns": "admin.$cmd.bulkWrite",
+            },
+            "nErrors": 1,
+            "nInserted": 1,
+            "nUpserted": 0,
+            "nMatched": 0,
+            "nModified": 0,
+            "nDeleted": 0,
+            "writeConcernError": { "code": 64, "errmsg": "timed out" },
+        },
+    )
+    .unwrap();
+
+    assert_eq!(spec.id(), 0);
+    assert_eq!(spec.initial_buffer.len(), 1);
+    assert_eq!(summary.n_errors, 1);
+    assert_eq!(summary.n_inserted, 1);
+    assert_eq!(summary.write_concern_error.unwrap().code, 64);
+    assert_eq!(summary.inserted_ids.len(), 2);
+    assert_eq!(summary.inserted_ids.get(&5), Some(&Bson::Int32(1)));
+    assert_eq!(summary.inserted_ids.get(&6), Some(&Bson::Int32(2)));
+}
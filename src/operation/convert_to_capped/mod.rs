@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod test;
+
+use bson::Document;
+
+use crate::{
+    bson::doc,
+    cmap::{Command, RawCommandResponse, StreamDescription},
+    error::{Error, Result},
+    operation::{
+        append_options,
+        remove_empty_write_concern,
+        OperationWithDefaults,
+        WriteConcernOnlyBody,
+    },
+    options::{ConvertToCappedOptions, WriteConcern},
+    Namespace,
+};
+
+#[derive(Debug)]
+pub(crate) struct ConvertToCapped {
+    ns: Namespace,
+    size: u64,
+    options: Option<ConvertToCappedOptions>,
+}
+
+impl ConvertToCapped {
+    pub(crate) fn new(ns: Namespace, size: u64, options: Option<ConvertToCappedOptions>) -> Self {
+        Self { ns, size, options }
+    }
+
+    #[cfg(test)]
+    fn empty() -> Self {
+        Self::new(
+            Namespace {
+                db: String::new(),
+                coll: String::new(),
+            },
+            0,
+            None,
+        )
+    }
+}
+
+impl OperationWithDefaults for ConvertToCapped {
+    type O = ();
+    type Command = Document;
+
+    const NAME: &'static str = "convertToCapped";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        let size = i64::try_from(self.size).map_err(|_| {
+            Error::invalid_argument(format!("capped size {} is too large", self.size))
+        })?;
+        let mut body = doc! {
+            Self::NAME: self.ns.coll.clone(),
+            "size": size,
+        };
+
+        remove_empty_write_concern!(self.options);
+        append_options(&mut body, self.options.as_ref())?;
+
+        Ok(Command::new(
+            Self::NAME.to_string(),
+            self.ns.db.clone(),
+            body,
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        let response: WriteConcernOnlyBody = response.body()?;
+        response.validate()
+    }
+
+    fn write_concern(&self) -> Option<&WriteConcern> {
+        self.options
+            .as_ref()
+            .and_then(|opts| opts.write_concern.as_ref())
+    }
+}
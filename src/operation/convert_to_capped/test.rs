@@ -0,0 +1,84 @@
+use crate::{
+    bson::doc,
+    cmap::StreamDescription,
+    concern::{Acknowledgment, WriteConcern},
+    error::{ErrorKind, WriteFailure},
+    operation::{test::handle_response_test, ConvertToCapped, Operation},
+    options::ConvertToCappedOptions,
+    Namespace,
+};
+
+#[test]
+fn build() {
+    let mut op = ConvertToCapped::new(
+        Namespace {
+            db: "test_db".to_string(),
+            coll: "test_coll".to_string(),
+        },
+        4096,
+        Some(ConvertToCappedOptions {
+            write_concern: Some(WriteConcern {
+                w: Some(Acknowledgment::Majority),
+                ..Default::default()
+            }),
+            comment: Some("convert".into()),
+        }),
+    );
+
+    let description = StreamDescription::new_testing();
+    let cmd = op.build(&description).unwrap();
+
+    assert_eq!(cmd.name.as_str(), "convertToCapped");
+    assert_eq!(cmd.target_db.as_str(), "test_db");
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "convertToCapped": "test_coll",
+            "size": 4096_i64,
+            "writeConcern": { "w": "majority" },
+            "comment": "convert",
+        }
+    );
+}
+
+#[test]
+fn build_size_out_of_range() {
+    let mut op = ConvertToCapped::empty();
+    op.size = u64::MAX;
+
+    let description = StreamDescription::new_testing();
+    let error = op.build(&description).unwrap_err();
+    assert!(matches!(*error.kind, ErrorKind::InvalidArgument { .. }));
+}
+
+#[test]
+fn handle_success() {
+    let op = ConvertToCapped::empty();
+
+    let ok_response = doc! { "ok": 1.0 };
+    handle_response_test(&op, ok_response).unwrap();
+}
+
+#[test]
+fn handle_write_concern_error() {
+    let op = ConvertToCapped::empty();
+
+    let response = doc! {
+        "writeConcernError": {
+            "code": 100,
+            "codeName": "hello world",
+            "errmsg": "12345"
+        },
+        "ok": 1
+    };
+
+    let err = handle_response_test(&op, response).unwrap_err();
+    match *err.kind {
+        ErrorKind::Write(WriteFailure::WriteConcernError(ref wc_err)) => {
+            assert_eq!(wc_err.code, 100);
+            assert_eq!(wc_err.code_name, "hello world");
+            assert_eq!(wc_err.message, "12345");
+        }
+        ref e => panic!("expected write concern error, got {:?}", e),
+    }
+}
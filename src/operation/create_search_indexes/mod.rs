@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod test;
+
+use serde::Deserialize;
+
+use crate::{
+    bson::{doc, Document},
+    cmap::{Command, RawCommandResponse, StreamDescription},
+    error::Result,
+    index::SearchIndexModel,
+    operation::{append_options, OperationWithDefaults},
+    options::CreateSearchIndexOptions,
+    Namespace,
+};
+
+#[derive(Debug)]
+pub(crate) struct CreateSearchIndexes {
+    ns: Namespace,
+    indexes: Vec<SearchIndexModel>,
+    options: Option<CreateSearchIndexOptions>,
+}
+
+impl CreateSearchIndexes {
+    pub(crate) fn new(
+        ns: Namespace,
+        indexes: Vec<SearchIndexModel>,
+        options: Option<CreateSearchIndexOptions>,
+    ) -> Self {
+        Self {
+            ns,
+            indexes,
+            options,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn empty() -> Self {
+        Self {
+            ns: Namespace {
+                db: String::new(),
+                coll: String::new(),
+            },
+            indexes: Vec::new(),
+            options: None,
+        }
+    }
+}
+
+impl OperationWithDefaults for CreateSearchIndexes {
+    type O = Vec<String>;
+    type Command = Document;
+    const NAME: &'static str = "createSearchIndexes";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        let mut body = doc! {
+            Self::NAME: self.ns.coll.clone(),
+            "indexes": bson::to_bson(&self.indexes)?,
+        };
+
+        append_options(&mut body, self.options.as_ref())?;
+
+        Ok(Command::new(
+            Self::NAME.to_string(),
+            self.ns.db.clone(),
+            body,
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        let response: Response = response.body()?;
+        Ok(response
+            .indexes_created
+            .into_iter()
+            .map(|index| index.name)
+            .collect())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Response {
+    indexes_created: Vec<CreatedIndex>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CreatedIndex {
+    name: String,
+}
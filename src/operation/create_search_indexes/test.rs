@@ -0,0 +1,86 @@
+use crate::{
+    bson::{doc, Bson},
+    cmap::StreamDescription,
+    coll::{options::CreateSearchIndexOptions, Namespace},
+    index::{SearchIndexModel, SearchIndexType},
+    operation::{test::handle_response_test, CreateSearchIndexes, Operation},
+};
+
+#[test]
+fn build() {
+    let ns = Namespace {
+        db: "test_db".to_string(),
+        coll: "test_coll".to_string(),
+    };
+
+    let search = SearchIndexModel::builder()
+        .definition(doc! { "mappings": { "dynamic": true } })
+        .build();
+    let vector_search = SearchIndexModel::builder()
+        .definition(doc! {
+            "fields": [{
+                "type": "vector",
+                "path": "embedding",
+                "numDimensions": 3,
+                "similarity": "cosine",
+            }],
+        })
+        .name("vectors".to_string())
+        .index_type(SearchIndexType::VectorSearch)
+        .build();
+    let options = CreateSearchIndexOptions::builder()
+        .comment(Bson::from("hello"))
+        .build();
+    let mut create_search_indexes =
+        CreateSearchIndexes::new(ns, vec![search, vector_search], Some(options));
+
+    let cmd = create_search_indexes
+        .build(&StreamDescription::new_testing())
+        .expect("CreateSearchIndexes command failed to build when it should have succeeded.");
+
+    assert_eq!(cmd.target_db, "test_db");
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "createSearchIndexes": "test_coll",
+            "indexes": [
+                { "definition": { "mappings": { "dynamic": true } } },
+                {
+                    "definition": {
+                        "fields": [{
+                            "type": "vector",
+                            "path": "embedding",
+                            "numDimensions": 3,
+                            "similarity": "cosine",
+                        }],
+                    },
+                    "name": "vectors",
+                    "type": "vectorSearch",
+                },
+            ],
+            "comment": "hello",
+        }
+    )
+}
+
+#[test]
+fn handle_success() {
+    let op = CreateSearchIndexes::empty();
+
+    let response = doc! {
+        "ok": 1,
+        "indexesCreated": [
+            { "id": "6524096020da840844a4c4a7", "name": "default" },
+            { "id": "6524096020da840844a4c4a8", "name": "vectors" },
+        ],
+    };
+
+    let names = handle_response_test(&op, response).unwrap();
+    assert_eq!(names, vec!["default".to_string(), "vectors".to_string()]);
+}
+
+#[test]
+fn handle_invalid_response() {
+    let op = CreateSearchIndexes::empty();
+    handle_response_test(&op, doc! { "ok": 1 }).unwrap_err();
+}
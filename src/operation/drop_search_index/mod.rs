@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    bson::{doc, Document},
+    cmap::{Command, RawCommandResponse, StreamDescription},
+    error::{Error, Result},
+    operation::{append_options, OperationWithDefaults},
+    options::DropSearchIndexOptions,
+    Namespace,
+};
+
+#[derive(Debug)]
+pub(crate) struct DropSearchIndex {
+    ns: Namespace,
+    name: String,
+    options: Option<DropSearchIndexOptions>,
+}
+
+impl DropSearchIndex {
+    pub(crate) fn new(
+        ns: Namespace,
+        name: String,
+        options: Option<DropSearchIndexOptions>,
+    ) -> Self {
+        Self { ns, name, options }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn empty() -> Self {
+        Self {
+            ns: Namespace {
+                db: String::new(),
+                coll: String::new(),
+            },
+            name: String::new(),
+            options: None,
+        }
+    }
+}
+
+impl OperationWithDefaults for DropSearchIndex {
+    type O = ();
+    type Command = Document;
+    const NAME: &'static str = "dropSearchIndex";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        let mut body = doc! {
+            Self::NAME: self.ns.coll.clone(),
+            "name": self.name.clone(),
+        };
+
+        append_options(&mut body, self.options.as_ref())?;
+
+        Ok(Command::new(
+            Self::NAME.to_string(),
+            self.ns.db.clone(),
+            body,
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        _response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        Ok(())
+    }
+
+    fn handle_error(&self, error: Error) -> Result<Self::O> {
+        // The server reports a missing collection or index as NamespaceNotFound, which is treated
+        // as success to make dropping idempotent.
+        if error.is_ns_not_found() {
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
+}
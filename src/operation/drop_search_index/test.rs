@@ -0,0 +1,69 @@
+use crate::{
+    bson::{doc, Bson},
+    cmap::StreamDescription,
+    coll::{options::DropSearchIndexOptions, Namespace},
+    error::{CommandError, Error, ErrorKind},
+    operation::{test::handle_response_test, DropSearchIndex, Operation},
+};
+
+#[test]
+fn build() {
+    let ns = Namespace {
+        db: "test_db".to_string(),
+        coll: "test_coll".to_string(),
+    };
+
+    let mut drop_search_index = DropSearchIndex::new(
+        ns,
+        "default".to_string(),
+        Some(
+            DropSearchIndexOptions::builder()
+                .comment(Bson::from("hello"))
+                .build(),
+        ),
+    );
+
+    let cmd = drop_search_index
+        .build(&StreamDescription::new_testing())
+        .expect("DropSearchIndex command failed to build when it should have succeeded.");
+
+    assert_eq!(cmd.target_db, "test_db");
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "dropSearchIndex": "test_coll",
+            "name": "default",
+            "comment": "hello",
+        }
+    )
+}
+
+#[test]
+fn handle_success() {
+    let op = DropSearchIndex::empty();
+    let response = doc! { "ok": 1 };
+    handle_response_test(&op, response).unwrap();
+}
+
+#[test]
+fn handle_ns_not_found() {
+    let op = DropSearchIndex::empty();
+
+    let ns_not_found: Error = ErrorKind::Command(CommandError {
+        code: 26,
+        code_name: "NamespaceNotFound".to_string(),
+        message: "ns not found".to_string(),
+        topology_version: None,
+    })
+    .into();
+    op.handle_error(ns_not_found).unwrap();
+
+    let other: Error = ErrorKind::Command(CommandError {
+        code: 8,
+        code_name: "UnknownError".to_string(),
+        message: "error".to_string(),
+        topology_version: None,
+    })
+    .into();
+    op.handle_error(other).unwrap_err();
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    bson::{doc, Document},
+    cmap::{Command, RawCommandResponse, StreamDescription},
+    error::Result,
+    operation::{Operation, OperationWithDefaults, Retryability},
+    options::ExplainVerbosity,
+    results::ExplainResult,
+    selection_criteria::SelectionCriteria,
+};
+
+/// Wraps the command built by another operation in an `explain` command, returning the query plan
+/// for that command instead of executing it.
+#[derive(Debug)]
+pub(crate) struct Explain<Op> {
+    inner: Op,
+    verbosity: ExplainVerbosity,
+}
+
+impl<Op: Operation<Command = Document>> Explain<Op> {
+    pub(crate) fn new(inner: Op, verbosity: ExplainVerbosity) -> Self {
+        Self { inner, verbosity }
+    }
+}
+
+impl<Op: Operation<Command = Document>> OperationWithDefaults for Explain<Op> {
+    type O = ExplainResult;
+    type Command = Document;
+
+    const NAME: &'static str = "explain";
+
+    fn build(&mut self, description: &StreamDescription) -> Result<Command> {
+        let inner = self.inner.build(description)?;
+        let mut explained = inner.body;
+        // The explained command is never executed, so it cannot carry its own concerns.
+        explained.remove("readConcern");
+        explained.remove("writeConcern");
+
+        let body = doc! {
+            "explain": explained,
+            "verbosity": bson::to_bson(&self.verbosity)?,
+        };
+
+        Ok(Command::new("explain".to_string(), inner.target_db, body))
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        ExplainResult::from_document(response.body()?)
+    }
+
+    fn selection_criteria(&self) -> Option<&SelectionCriteria> {
+        self.inner.selection_criteria()
+    }
+
+    fn retryability(&self) -> Retryability {
+        Retryability::None
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    bson::{doc, Document},
+    cmap::StreamDescription,
+    concern::WriteConcern,
+    operation::{test::handle_response_test, Delete, Explain, Find, Operation, Retryability},
+    options::{DeleteOptions, ExplainVerbosity, FindOptions, ReadConcern},
+    Namespace,
+};
+
+fn ns() -> Namespace {
+    Namespace {
+        db: "test_db".to_string(),
+        coll: "test_coll".to_string(),
+    }
+}
+
+#[test]
+fn build_find() {
+    let options = FindOptions::builder()
+        .limit(2)
+        .read_concern(ReadConcern::majority())
+        .build();
+    let find = Find::new(ns(), Some(doc! { "x": 1 }), Some(options));
+    let mut op = Explain::new(find, ExplainVerbosity::ExecutionStats);
+
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(cmd.name, "explain");
+    assert_eq!(cmd.target_db, "test_db");
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "explain": { "find": "test_coll", "limit": 2_i64, "filter": { "x": 1 } },
+            "verbosity": "executionStats",
+        }
+    );
+    assert_eq!(op.retryability(), Retryability::None);
+}
+
+#[test]
+fn build_delete_strips_write_concern() {
+    let options = DeleteOptions::builder()
+        .write_concern(WriteConcern::builder().journal(true).build())
+        .build();
+    let delete = Delete::new(ns(), doc! { "x": 1 }, Some(1), Some(options));
+    let mut op = Explain::new(delete, ExplainVerbosity::QueryPlanner);
+
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    let explained = cmd.body.get_document("explain").unwrap();
+    assert_eq!(explained.get_str("delete"), Ok("test_coll"));
+    assert!(!explained.contains_key("writeConcern"));
+    assert_eq!(cmd.body.get_str("verbosity"), Ok("queryPlanner"));
+    assert!(op.write_concern().is_none());
+}
+
+#[test]
+fn handle_response() {
+    let op = Explain::new(
+        Find::new(ns(), None, None),
+        ExplainVerbosity::ExecutionStats,
+    );
+
+    let winning_plan = doc! {
+        "stage": "FETCH",
+        "inputStage": { "stage": "IXSCAN", "indexName": "x_1", "keyPattern": { "x": 1 } },
+    };
+    let result = handle_response_test(
+        &op,
+        doc! {
+            "ok": 1.0,
+            "queryPlanner": {
+                "namespace": "test_db.test_coll",
+                "winningPlan": winning_plan.clone(),
+                "rejectedPlans": [{ "stage": "COLLSCAN" }],
+            },
+            "executionStats": {
+                "nReturned": 3,
+                "executionTimeMillis": 1,
+                "totalKeysExamined": 3_i64,
+                "totalDocsExamined": 3,
+                "executionStages": { "stage": "FETCH" },
+            },
+        },
+    )
+    .unwrap();
+
+    let query_planner = result.query_planner.as_ref().unwrap();
+    assert_eq!(
+        query_planner.namespace.as_deref(),
+        Some("test_db.test_coll")
+    );
+    assert_eq!(query_planner.rejected_plans.len(), 1);
+    assert_eq!(result.winning_plan(), Some(&winning_plan));
+    assert_eq!(result.index_names(), vec!["x_1"]);
+    assert!(!result.is_collection_scan());
+
+    let execution_stats = result.execution_stats.unwrap();
+    assert_eq!(execution_stats.n_returned, 3);
+    assert_eq!(execution_stats.total_keys_examined, 3);
+    assert_eq!(execution_stats.total_docs_examined, 3);
+}
+
+#[test]
+fn handle_response_aggregate_cursor_stage() {
+    let op = Explain::new(Find::new(ns(), None, None), ExplainVerbosity::QueryPlanner);
+
+    let result = handle_response_test(
+        &op,
+        doc! {
+            "ok": 1.0,
+            "stages": [
+                {
+                    "$cursor": {
+                        "queryPlanner": {
+                            "winningPlan": { "stage": "COLLSCAN", "direction": "forward" },
+                        },
+                    },
+                },
+                { "$group": { "_id": "$x" } },
+            ],
+        },
+    )
+    .unwrap();
+
+    assert!(result.is_collection_scan());
+    assert!(result.index_names().is_empty());
+    assert!(result.execution_stats.is_none());
+    assert!(result.raw.contains_key("stages"));
+}
+
+#[test]
+fn handle_response_sharded() {
+    let op = Explain::new(Find::new(ns(), None, None), ExplainVerbosity::QueryPlanner);
+
+    let shard_plan = |index: &str| -> Document {
+        doc! {
+            "shardName": "shard",
+            "winningPlan": { "stage": "IXSCAN", "indexName": index },
+        }
+    };
+    let result = handle_response_test(
+        &op,
+        doc! {
+            "ok": 1.0,
+            "queryPlanner": {
+                "winningPlan": {
+                    "stage": "SHARD_MERGE",
+                    "shards": [shard_plan("x_1"), shard_plan("x_1"), shard_plan("y_1")],
+                },
+            },
+        },
+    )
+    .unwrap();
+
+    assert_eq!(result.index_names(), vec!["x_1", "y_1"]);
+}
@@ -1,9 +1,9 @@
 #[cfg(test)]
 mod test;
 
-use std::{collections::HashMap, convert::TryInto};
+use std::collections::HashMap;
 
-use bson::{oid::ObjectId, Bson, RawArrayBuf, RawDocumentBuf};
+use bson::{Bson, RawArrayBuf};
 use serde::Serialize;
 
 use crate::{
@@ -87,29 +87,7 @@ impl<'a, T: Serialize> OperationWithDefaults for Insert<'a, T> {
             .enumerate()
         {
             let mut doc = bson::to_raw_document_buf(d)?;
-            let id = match doc.get("_id")? {
-                Some(b) => b.try_into()?,
-                None => {
-                    let mut new_doc = RawDocumentBuf::new();
-                    let oid = ObjectId::new();
-                    new_doc.append("_id", oid);
-
-                    let mut new_bytes = new_doc.into_bytes();
-                    new_bytes.pop(); // remove trailing null byte
-
-                    let mut bytes = doc.into_bytes();
-                    let oid_slice = &new_bytes[4..];
-                    // insert oid at beginning of document
-                    bytes.splice(4..4, oid_slice.iter().cloned());
-
-                    // overwrite old length
-                    let new_length = (bytes.len() as i32).to_le_bytes();
-                    (&mut bytes[0..4]).copy_from_slice(&new_length);
-                    doc = RawDocumentBuf::from_bytes(bytes)?;
-
-                    Bson::ObjectId(oid)
-                }
-            };
+            let id = bson_util::get_or_prepend_id_field(&mut doc)?;
 
             let doc_size = bson_util::array_entry_size_bytes(i, doc.as_bytes().len());
 
@@ -188,6 +166,7 @@ impl<'a, T: Serialize> OperationWithDefaults for Insert<'a, T> {
                 ErrorKind::BulkWrite(BulkWriteFailure {
                     write_errors: response.write_errors,
                     write_concern_error: response.write_concern_error,
+                    partial_result: None,
                     inserted_ids: map,
                 }),
                 response.labels,
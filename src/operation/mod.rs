@@ -1,15 +1,21 @@
 mod abort_transaction;
 mod aggregate;
+mod bulk_write;
+mod client_bulk_write;
 mod commit_transaction;
+mod convert_to_capped;
 mod count;
 mod count_documents;
 mod create;
 mod create_indexes;
+mod create_search_indexes;
 mod delete;
 mod distinct;
 mod drop_collection;
 mod drop_database;
 mod drop_indexes;
+mod drop_search_index;
+mod explain;
 mod find;
 mod find_and_modify;
 mod get_more;
@@ -17,9 +23,12 @@ mod insert;
 mod list_collections;
 mod list_databases;
 mod list_indexes;
+mod modify_collection;
 mod raw_output;
+mod rename_collection;
 mod run_command;
 mod update;
+mod update_search_index;
 
 #[cfg(test)]
 mod test;
@@ -51,16 +60,26 @@ use crate::{
 
 pub(crate) use abort_transaction::AbortTransaction;
 pub(crate) use aggregate::{Aggregate, AggregateTarget, ChangeStreamAggregate};
+pub(crate) use bulk_write::BulkWrite;
+pub(crate) use client_bulk_write::{
+    ClientBulkWrite,
+    ClientBulkWriteSummary,
+    SingleOperationResponse,
+};
 pub(crate) use commit_transaction::CommitTransaction;
+pub(crate) use convert_to_capped::ConvertToCapped;
 pub(crate) use count::Count;
 pub(crate) use count_documents::CountDocuments;
 pub(crate) use create::Create;
 pub(crate) use create_indexes::CreateIndexes;
+pub(crate) use create_search_indexes::CreateSearchIndexes;
 pub(crate) use delete::Delete;
 pub(crate) use distinct::Distinct;
 pub(crate) use drop_collection::DropCollection;
 pub(crate) use drop_database::DropDatabase;
 pub(crate) use drop_indexes::DropIndexes;
+pub(crate) use drop_search_index::DropSearchIndex;
+pub(crate) use explain::Explain;
 pub(crate) use find::Find;
 pub(crate) use find_and_modify::FindAndModify;
 pub(crate) use get_more::GetMore;
@@ -68,10 +87,13 @@ pub(crate) use insert::Insert;
 pub(crate) use list_collections::ListCollections;
 pub(crate) use list_databases::ListDatabases;
 pub(crate) use list_indexes::ListIndexes;
+pub(crate) use modify_collection::ModifyCollection;
 #[cfg(feature = "csfle")]
 pub(crate) use raw_output::RawOutput;
+pub(crate) use rename_collection::RenameCollection;
 pub(crate) use run_command::RunCommand;
 pub(crate) use update::Update;
+pub(crate) use update_search_index::UpdateSearchIndex;
 
 const SERVER_4_2_0_WIRE_VERSION: i32 = 8;
 const SERVER_4_4_0_WIRE_VERSION: i32 = 9;
@@ -297,6 +319,7 @@ impl<T> WriteResponseBody<T> {
         let failure = BulkWriteFailure {
             write_errors: self.write_errors.clone(),
             write_concern_error: self.write_concern_error.clone(),
+            partial_result: None,
             inserted_ids: Default::default(),
         };
 
@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+use crate::{
+    bson::doc,
+    cmap::StreamDescription,
+    concern::WriteConcern,
+    error::{ErrorKind, WriteFailure},
+    operation::{test::handle_response_test, ModifyCollection, Operation},
+    options::{
+        ChangeStreamPreAndPostImages,
+        ModifyCollectionOptions,
+        ModifyIndex,
+        ValidationLevel,
+    },
+    Namespace,
+};
+
+#[test]
+fn build() {
+    let mut op = ModifyCollection::new(
+        Namespace {
+            db: "test_db".to_string(),
+            coll: "test_coll".to_string(),
+        },
+        Some(ModifyCollectionOptions {
+            validator: Some(doc! { "x": { "$gt": 1 } }),
+            validation_level: Some(ValidationLevel::Strict),
+            change_stream_pre_and_post_images: Some(ChangeStreamPreAndPostImages { enabled: true }),
+            capped_size: Some(4096),
+            write_concern: Some(WriteConcern {
+                journal: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+    );
+
+    let description = StreamDescription::new_testing();
+    let cmd = op.build(&description).unwrap();
+
+    assert_eq!(cmd.name.as_str(), "collMod");
+    assert_eq!(cmd.target_db.as_str(), "test_db");
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "collMod": "test_coll",
+            "validator": { "x": { "$gt": 1 } },
+            "validationLevel": "strict",
+            "changeStreamPreAndPostImages": { "enabled": true },
+            "cappedSize": 4096_i64,
+            "writeConcern": { "j": true },
+        }
+    );
+}
+
+#[test]
+fn build_index() {
+    let mut op = ModifyCollection::new(
+        Namespace {
+            db: "test_db".to_string(),
+            coll: "test_coll".to_string(),
+        },
+        Some(ModifyCollectionOptions {
+            index: Some(
+                ModifyIndex::builder()
+                    .key_pattern(doc! { "createdAt": 1 })
+                    .expire_after_seconds(Duration::from_secs(3600))
+                    .hidden(true)
+                    .build(),
+            ),
+            ..Default::default()
+        }),
+    );
+
+    let description = StreamDescription::new_testing();
+    let cmd = op.build(&description).unwrap();
+
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "collMod": "test_coll",
+            "index": {
+                "keyPattern": { "createdAt": 1 },
+                "expireAfterSeconds": 3600,
+                "hidden": true,
+            },
+        }
+    );
+}
+
+#[test]
+fn handle_success() {
+    let op = ModifyCollection::empty();
+
+    let ok_response = doc! { "ok": 1.0 };
+    handle_response_test(&op, ok_response).unwrap();
+    let ok_extra = doc! { "ok": 1.0, "expireAfterSeconds_old": 60, "expireAfterSeconds_new": 3600 };
+    handle_response_test(&op, ok_extra).unwrap();
+}
+
+#[test]
+fn handle_write_concern_error() {
+    let op = ModifyCollection::empty();
+
+    let response = doc! {
+        "writeConcernError": {
+            "code": 100,
+            "codeName": "hello world",
+            "errmsg": "12345"
+        },
+        "ok": 1
+    };
+
+    let err = handle_response_test(&op, response).unwrap_err();
+    match *err.kind {
+        ErrorKind::Write(WriteFailure::WriteConcernError(ref wc_err)) => {
+            assert_eq!(wc_err.code, 100);
+            assert_eq!(wc_err.code_name, "hello world");
+            assert_eq!(wc_err.message, "12345");
+        }
+        ref e => panic!("expected write concern error, got {:?}", e),
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod test;
+
+use bson::Document;
+
+use crate::{
+    bson::doc,
+    cmap::{Command, RawCommandResponse, StreamDescription},
+    error::Result,
+    operation::{
+        append_options,
+        remove_empty_write_concern,
+        OperationWithDefaults,
+        WriteConcernOnlyBody,
+    },
+    options::{RenameCollectionOptions, WriteConcern},
+    Namespace,
+};
+
+#[derive(Debug)]
+pub(crate) struct RenameCollection {
+    from: Namespace,
+    to: Namespace,
+    drop_target: bool,
+    options: Option<RenameCollectionOptions>,
+}
+
+impl RenameCollection {
+    pub(crate) fn new(
+        from: Namespace,
+        to: Namespace,
+        drop_target: bool,
+        options: Option<RenameCollectionOptions>,
+    ) -> Self {
+        Self {
+            from,
+            to,
+            drop_target,
+            options,
+        }
+    }
+
+    #[cfg(test)]
+    fn empty() -> Self {
+        let ns = Namespace {
+            db: String::new(),
+            coll: String::new(),
+        };
+        Self::new(ns.clone(), ns, false, None)
+    }
+}
+
+impl OperationWithDefaults for RenameCollection {
+    type O = ();
+    type Command = Document;
+
+    const NAME: &'static str = "renameCollection";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        let mut body = doc! {
+            Self::NAME: self.from.to_string(),
+            "to": self.to.to_string(),
+            "dropTarget": self.drop_target,
+        };
+
+        remove_empty_write_concern!(self.options);
+        append_options(&mut body, self.options.as_ref())?;
+
+        // renameCollection must be run against the admin database.
+        Ok(Command::new(
+            Self::NAME.to_string(),
+            "admin".to_string(),
+            body,
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        let response: WriteConcernOnlyBody = response.body()?;
+        response.validate()
+    }
+
+    fn write_concern(&self) -> Option<&WriteConcern> {
+        self.options
+            .as_ref()
+            .and_then(|opts| opts.write_concern.as_ref())
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    bson::doc,
+    cmap::StreamDescription,
+    concern::{Acknowledgment, WriteConcern},
+    error::{ErrorKind, WriteFailure},
+    operation::{test::handle_response_test, Operation, RenameCollection},
+    options::RenameCollectionOptions,
+    Namespace,
+};
+
+#[test]
+fn build() {
+    let mut op = RenameCollection::new(
+        Namespace {
+            db: "test_db".to_string(),
+            coll: "test_coll".to_string(),
+        },
+        Namespace {
+            db: "other_db".to_string(),
+            coll: "other_coll".to_string(),
+        },
+        true,
+        Some(RenameCollectionOptions {
+            write_concern: Some(WriteConcern {
+                w: Some(Acknowledgment::Majority),
+                ..Default::default()
+            }),
+            comment: Some("rename".into()),
+        }),
+    );
+
+    let description = StreamDescription::new_testing();
+    let cmd = op.build(&description).unwrap();
+
+    assert_eq!(cmd.name.as_str(), "renameCollection");
+    assert_eq!(cmd.target_db.as_str(), "admin");
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "renameCollection": "test_db.test_coll",
+            "to": "other_db.other_coll",
+            "dropTarget": true,
+            "writeConcern": { "w": "majority" },
+            "comment": "rename",
+        }
+    );
+}
+
+#[test]
+fn handle_success() {
+    let op = RenameCollection::empty();
+
+    let ok_response = doc! { "ok": 1.0 };
+    handle_response_test(&op, ok_response).unwrap();
+}
+
+#[test]
+fn handle_write_concern_error() {
+    let op = RenameCollection::empty();
+
+    let response = doc! {
+        "writeConcernError": {
+            "code": 100,
+            "codeName": "hello world",
+            "errmsg": "12345"
+        },
+        "ok": 1
+    };
+
+    let err = handle_response_test(&op, response).unwrap_err();
+    match *err.kind {
+        ErrorKind::Write(WriteFailure::WriteConcernError(ref wc_err)) => {
+            assert_eq!(wc_err.code, 100);
+            assert_eq!(wc_err.code_name, "hello world");
+            assert_eq!(wc_err.message, "12345");
+        }
+        ref e => panic!("expected write concern error, got {:?}", e),
+    }
+}
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    bson::{doc, Document},
+    cmap::{Command, RawCommandResponse, StreamDescription},
+    error::Result,
+    operation::{append_options, OperationWithDefaults},
+    options::UpdateSearchIndexOptions,
+    Namespace,
+};
+
+#[derive(Debug)]
+pub(crate) struct UpdateSearchIndex {
+    ns: Namespace,
+    name: String,
+    definition: Document,
+    options: Option<UpdateSearchIndexOptions>,
+}
+
+impl UpdateSearchIndex {
+    pub(crate) fn new(
+        ns: Namespace,
+        name: String,
+        definition: Document,
+        options: Option<UpdateSearchIndexOptions>,
+    ) -> Self {
+        Self {
+            ns,
+            name,
+            definition,
+            options,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn empty() -> Self {
+        Self {
+            ns: Namespace {
+                db: String::new(),
+                coll: String::new(),
+            },
+            name: String::new(),
+            definition: Document::new(),
+            options: None,
+        }
+    }
+}
+
+impl OperationWithDefaults for UpdateSearchIndex {
+    type O = ();
+    type Command = Document;
+    const NAME: &'static str = "updateSearchIndex";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        let mut body = doc! {
+            Self::NAME: self.ns.coll.clone(),
+            "name": self.name.clone(),
+            "definition": self.definition.clone(),
+        };
+
+        append_options(&mut body, self.options.as_ref())?;
+
+        Ok(Command::new(
+            Self::NAME.to_string(),
+            self.ns.db.clone(),
+            body,
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        _response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        Ok(())
+    }
+}
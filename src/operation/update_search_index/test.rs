@@ -0,0 +1,49 @@
+use crate::{
+    bson::{doc, Bson},
+    cmap::StreamDescription,
+    coll::{options::UpdateSearchIndexOptions, Namespace},
+    operation::{test::handle_response_test, Operation, UpdateSearchIndex},
+};
+
+#[test]
+fn build() {
+    let ns = Namespace {
+        db: "test_db".to_string(),
+        coll: "test_coll".to_string(),
+    };
+
+    let mut update_search_index = UpdateSearchIndex::new(
+        ns,
+        "default".to_string(),
+        doc! { "mappings": { "dynamic": false, "fields": { "title": { "type": "string" } } } },
+        Some(
+            UpdateSearchIndexOptions::builder()
+                .comment(Bson::from("hello"))
+                .build(),
+        ),
+    );
+
+    let cmd = update_search_index
+        .build(&StreamDescription::new_testing())
+        .expect("UpdateSearchIndex command failed to build when it should have succeeded.");
+
+    assert_eq!(cmd.target_db, "test_db");
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "updateSearchIndex": "test_coll",
+            "name": "default",
+            "definition": {
+                "mappings": { "dynamic": false, "fields": { "title": { "type": "string" } } },
+            },
+            "comment": "hello",
+        }
+    )
+}
+
+#[test]
+fn handle_success() {
+    let op = UpdateSearchIndex::empty();
+    let response = doc! { "ok": 1 };
+    handle_response_test(&op, response).unwrap();
+}
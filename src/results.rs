@@ -6,7 +6,9 @@ use crate::{
     bson::{Bson, Document},
     bson_util,
     change_stream::event::ResumeToken,
+    collation::Collation,
     db::options::CreateCollectionOptions,
+    index::IndexModel,
 };
 
 use bson::{Binary, RawDocumentBuf};
@@ -14,7 +16,7 @@ use serde::{Deserialize, Serialize};
 
 /// The result of a [`Collection::insert_one`](../struct.Collection.html#method.insert_one)
 /// operation.
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct InsertOneResult {
@@ -50,7 +52,7 @@ impl InsertManyResult {
 
 /// The result of a [`Collection::update_one`](../struct.Collection.html#method.update_one) or
 /// [`Collection::update_many`](../struct.Collection.html#method.update_many) operation.
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct UpdateResult {
@@ -68,7 +70,7 @@ pub struct UpdateResult {
 
 /// The result of a [`Collection::delete_one`](../struct.Collection.html#method.delete_one) or
 /// [`Collection::delete_many`](../struct.Collection.html#method.delete_many) operation.
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct DeleteResult {
@@ -77,6 +79,121 @@ pub struct DeleteResult {
     pub deleted_count: u64,
 }
 
+/// The result of a [`Collection::bulk_write`](../struct.Collection.html#method.bulk_write)
+/// operation.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BulkWriteResult {
+    /// The number of documents inserted.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub inserted_count: u64,
+
+    /// The number of documents that matched the filters of updates and replacements.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub matched_count: u64,
+
+    /// The number of documents that were modified by updates and replacements.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub modified_count: u64,
+
+    /// The number of documents deleted.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub deleted_count: u64,
+
+    /// The number of documents upserted.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub upserted_count: u64,
+
+    /// The `_id` field of the documents inserted, keyed by the index of the corresponding write
+    /// model.
+    pub inserted_ids: HashMap<usize, Bson>,
+
+    /// The `_id` field of the documents upserted, keyed by the index of the corresponding write
+    /// model.
+    pub upserted_ids: HashMap<usize, Bson>,
+}
+
+impl BulkWriteResult {
+    pub(crate) fn new() -> Self {
+        BulkWriteResult {
+            inserted_count: 0,
+            matched_count: 0,
+            modified_count: 0,
+            deleted_count: 0,
+            upserted_count: 0,
+            inserted_ids: HashMap::new(),
+            upserted_ids: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn merge(&mut self, other: BulkWriteResult) {
+        self.inserted_count += other.inserted_count;
+        self.matched_count += other.matched_count;
+        self.modified_count += other.modified_count;
+        self.deleted_count += other.deleted_count;
+        self.upserted_count += other.upserted_count;
+        self.inserted_ids.extend(other.inserted_ids);
+        self.upserted_ids.extend(other.upserted_ids);
+    }
+}
+
+/// The result of a [`Client::bulk_write`](../struct.Client.html#method.bulk_write) operation.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ClientBulkWriteResult {
+    /// The number of documents inserted.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub inserted_count: u64,
+
+    /// The number of documents upserted.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub upserted_count: u64,
+
+    /// The number of documents that matched the filters of updates and replacements.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub matched_count: u64,
+
+    /// The number of documents that were modified by updates and replacements.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub modified_count: u64,
+
+    /// The number of documents deleted.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub deleted_count: u64,
+
+    /// The results of the successful inserts, keyed by the index of the corresponding write
+    /// model. This is only populated if
+    /// [`verbose_results`](crate::options::ClientBulkWriteOptions::verbose_results) was set.
+    pub insert_results: HashMap<usize, InsertOneResult>,
+
+    /// The results of the successful updates and replacements, keyed by the index of the
+    /// corresponding write model. This is only populated if
+    /// [`verbose_results`](crate::options::ClientBulkWriteOptions::verbose_results) was set.
+    pub update_results: HashMap<usize, UpdateResult>,
+
+    /// The results of the successful deletes, keyed by the index of the corresponding write
+    /// model. This is only populated if
+    /// [`verbose_results`](crate::options::ClientBulkWriteOptions::verbose_results) was set.
+    pub delete_results: HashMap<usize, DeleteResult>,
+}
+
+impl ClientBulkWriteResult {
+    pub(crate) fn new() -> Self {
+        ClientBulkWriteResult {
+            inserted_count: 0,
+            upserted_count: 0,
+            matched_count: 0,
+            modified_count: 0,
+            deleted_count: 0,
+            insert_results: HashMap::new(),
+            update_results: HashMap::new(),
+            delete_results: HashMap::new(),
+        }
+    }
+}
+
 /// Information about the index created as a result of a
 /// [`Collection::create_index`](../struct.Collection.html#method.create_index).
 #[derive(Debug, Clone, PartialEq)]
@@ -103,6 +220,41 @@ impl CreateIndexesResult {
     }
 }
 
+/// The changes made by a
+/// [`Collection::sync_indexes`](../struct.Collection.html#method.sync_indexes), or the changes that
+/// would be made if it was a dry run.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct SyncIndexesResult {
+    /// The requested indexes that did not exist and were created.
+    pub created: Vec<IndexModel>,
+
+    /// The names of the existing indexes that were not requested and were dropped.
+    pub dropped: Vec<String>,
+
+    /// The requested indexes that conflict with an existing index, which were left unchanged.
+    pub conflicts: Vec<IndexConflict>,
+
+    /// Whether the changes were only planned and not applied.
+    pub dry_run: bool,
+}
+
+/// A requested index that conflicts with an existing index with the same keys or name, as
+/// reported in [`SyncIndexesResult::conflicts`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct IndexConflict {
+    /// The requested index.
+    pub requested: IndexModel,
+
+    /// The existing index.
+    pub existing: IndexModel,
+
+    /// The properties that differ between the indexes, e.g. `"unique"` or
+    /// `"expireAfterSeconds"`.
+    pub differences: Vec<String>,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct GetMoreResult {
     pub(crate) batch: VecDeque<RawDocumentBuf>,
@@ -166,6 +318,36 @@ pub struct CollectionSpecification {
     pub id_index: Option<Document>,
 }
 
+impl CollectionSpecification {
+    /// Returns the definition of the view if this is a view, or `None` otherwise.
+    pub fn view_specification(&self) -> Option<ViewSpecification> {
+        if !matches!(self.collection_type, CollectionType::View) {
+            return None;
+        }
+        Some(ViewSpecification {
+            view_on: self.options.view_on.clone()?,
+            pipeline: self.options.pipeline.clone().unwrap_or_default(),
+            collation: self.options.collation.clone(),
+        })
+    }
+}
+
+/// The definition of a view as reported by
+/// [`Database::list_collections`](../struct.Database.html#method.list_collections), returned from
+/// [`CollectionSpecification::view_specification`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ViewSpecification {
+    /// The name of the collection or view the view is based on.
+    pub view_on: String,
+
+    /// The aggregation pipeline that determines the contents of the view.
+    pub pipeline: Vec<Document>,
+
+    /// The default collation of the view.
+    pub collation: Option<Collation>,
+}
+
 /// A struct modeling the information about an individual database returned from
 /// [`Client::list_databases`](../struct.Client.html#method.list_databases).
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
@@ -190,3 +372,142 @@ pub struct DatabaseSpecification {
     /// is `None`.
     pub shards: Option<Document>,
 }
+
+/// The result of explaining an operation with one of the `explain_*` methods on
+/// [`Collection`](../struct.Collection.html), such as
+/// [`Collection::explain_find`](../struct.Collection.html#method.explain_find).
+///
+/// The shape of explain output varies between server versions, query engines and topologies, so
+/// only the commonly used fields are parsed. The full response is available in `raw`.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ExplainResult {
+    /// The plan selected by the query optimizer. For aggregations, this describes the query that
+    /// produces the pipeline's input documents.
+    pub query_planner: Option<QueryPlanner>,
+
+    /// Statistics about the execution of the winning plan. This is only present if the operation
+    /// was explained with [`ExplainVerbosity::ExecutionStats`] or
+    /// [`ExplainVerbosity::AllPlansExecution`].
+    ///
+    /// [`ExplainVerbosity::ExecutionStats`]: crate::options::ExplainVerbosity::ExecutionStats
+    /// [`ExplainVerbosity::AllPlansExecution`]: crate::options::ExplainVerbosity::AllPlansExecution
+    pub execution_stats: Option<ExecutionStats>,
+
+    /// The full response returned by the server.
+    pub raw: Document,
+}
+
+impl ExplainResult {
+    pub(crate) fn from_document(raw: Document) -> crate::error::Result<Self> {
+        // Aggregations whose pipeline is not executed entirely by the query layer report the plan
+        // of their initial `$cursor` stage.
+        let source = match raw
+            .get_array("stages")
+            .ok()
+            .and_then(|stages| stages.first())
+        {
+            Some(Bson::Document(stage)) if !raw.contains_key("queryPlanner") => {
+                stage.get_document("$cursor").unwrap_or(&raw)
+            }
+            _ => &raw,
+        };
+
+        let query_planner = match source.get("queryPlanner") {
+            Some(query_planner) => Some(bson::from_bson(query_planner.clone())?),
+            None => None,
+        };
+        let execution_stats = match source.get("executionStats") {
+            Some(execution_stats) => Some(bson::from_bson(execution_stats.clone())?),
+            None => None,
+        };
+
+        Ok(Self {
+            query_planner,
+            execution_stats,
+            raw,
+        })
+    }
+
+    /// The winning plan selected by the query optimizer, if it was reported.
+    pub fn winning_plan(&self) -> Option<&Document> {
+        self.query_planner.as_ref().map(|qp| &qp.winning_plan)
+    }
+
+    /// The names of the indexes used by the winning plan.
+    pub fn index_names(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        if let Some(plan) = self.winning_plan() {
+            collect_plan_values(plan, "indexName", &mut names);
+        }
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+
+    /// Whether the winning plan scans the whole collection rather than using an index.
+    pub fn is_collection_scan(&self) -> bool {
+        let mut stages = Vec::new();
+        if let Some(plan) = self.winning_plan() {
+            collect_plan_values(plan, "stage", &mut stages);
+        }
+        stages.contains(&"COLLSCAN")
+    }
+}
+
+/// Collects every string value stored under `key` in the stages of `plan`.
+fn collect_plan_values<'a>(plan: &'a Document, key: &str, values: &mut Vec<&'a str>) {
+    for (k, v) in plan {
+        match v {
+            Bson::String(s) if k == key => values.push(s),
+            Bson::Document(d) => collect_plan_values(d, key, values),
+            Bson::Array(a) => {
+                for d in a.iter().filter_map(Bson::as_document) {
+                    collect_plan_values(d, key, values);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The output of the query optimizer included in an [`ExplainResult`].
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct QueryPlanner {
+    /// The namespace that the operation was run against.
+    pub namespace: Option<String>,
+
+    /// The plan selected by the query optimizer, as a tree of stages.
+    pub winning_plan: Document,
+
+    /// The candidate plans that were considered and rejected by the query optimizer.
+    #[serde(default)]
+    pub rejected_plans: Vec<Document>,
+}
+
+/// Statistics about the execution of the winning plan included in an [`ExplainResult`].
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ExecutionStats {
+    /// The number of documents returned by the winning plan.
+    #[serde(deserialize_with = "bson_util::deserialize_u64_from_bson_number")]
+    pub n_returned: u64,
+
+    /// The time in milliseconds taken to select and execute the winning plan.
+    #[serde(deserialize_with = "bson_util::deserialize_u64_from_bson_number")]
+    pub execution_time_millis: u64,
+
+    /// The number of index entries scanned.
+    #[serde(deserialize_with = "bson_util::deserialize_u64_from_bson_number")]
+    pub total_keys_examined: u64,
+
+    /// The number of documents examined.
+    #[serde(deserialize_with = "bson_util::deserialize_u64_from_bson_number")]
+    pub total_docs_examined: u64,
+
+    /// The execution details of the winning plan, as a tree of stages.
+    pub execution_stages: Option<Document>,
+}
@@ -1,5 +1,7 @@
 pub mod session;
 
+use serde::Serialize;
+
 use super::{ChangeStream, ClientSession, Database, SessionChangeStream};
 use crate::{
     bson::Document,
@@ -7,15 +9,18 @@ use crate::{
     concern::{ReadConcern, WriteConcern},
     error::Result,
     options::{
+        ClientBulkWriteOptions,
         ClientOptions,
         DatabaseOptions,
         ListDatabasesOptions,
         SelectionCriteria,
         SessionOptions,
+        WriteModel,
     },
-    results::DatabaseSpecification,
+    results::{ClientBulkWriteResult, DatabaseSpecification},
     runtime,
     Client as AsyncClient,
+    Namespace,
 };
 
 /// This is the main entry point for the synchronous API. A `Client` is used to connect to a MongoDB
@@ -152,6 +157,31 @@ impl Client {
         )
     }
 
+    /// Performs the writes specified by `models`, each of which is performed on the [`Namespace`]
+    /// it is paired with. See [`crate::Client::bulk_write`] for more information.
+    pub fn bulk_write<T: Serialize>(
+        &self,
+        models: impl IntoIterator<Item = (Namespace, WriteModel<T>)>,
+        options: impl Into<Option<ClientBulkWriteOptions>>,
+    ) -> Result<ClientBulkWriteResult> {
+        runtime::block_on(self.async_client.bulk_write(models, options.into()))
+    }
+
+    /// Performs the writes specified by `models` using the provided session. See
+    /// [`crate::Client::bulk_write`] for more information.
+    pub fn bulk_write_with_session<T: Serialize>(
+        &self,
+        models: impl IntoIterator<Item = (Namespace, WriteModel<T>)>,
+        options: impl Into<Option<ClientBulkWriteOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<ClientBulkWriteResult> {
+        runtime::block_on(self.async_client.bulk_write_with_session(
+            models,
+            options.into(),
+            &mut session.async_client_session,
+        ))
+    }
+
     /// Starts a new `ClientSession`.
     pub fn start_session(&self, options: Option<SessionOptions>) -> Result<ClientSession> {
         runtime::block_on(self.async_client.start_session(options)).map(Into::into)
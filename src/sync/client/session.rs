@@ -1,8 +1,10 @@
+use std::time::Instant;
+
 use super::Client;
 use crate::{
     bson::Document,
-    client::session::ClusterTime,
-    error::Result,
+    client::session::{ClusterTime, WITH_TRANSACTION_TIMEOUT},
+    error::{Result, TRANSIENT_TRANSACTION_ERROR, UNKNOWN_TRANSACTION_COMMIT_RESULT},
     options::{SessionOptions, TransactionOptions},
     runtime,
     ClientSession as AsyncClientSession,
@@ -135,4 +137,81 @@ impl ClientSession {
     pub fn abort_transaction(&mut self) -> Result<()> {
         runtime::block_on(self.async_client_session.abort_transaction())
     }
+
+    /// Starts a transaction, runs the given callback, and commits the transaction, retrying the
+    /// callback and the commit on errors that allow it for up to 120 seconds. Returns the value
+    /// returned by the final invocation of the callback. See
+    /// [`crate::ClientSession::with_transaction`] for more information.
+    ///
+    /// ```rust
+    /// # use mongodb::{bson::{doc, Document}, error::Result, sync::{Client, ClientSession}};
+    /// #
+    /// # fn do_stuff() -> Result<()> {
+    /// # let client = Client::with_uri_str("mongodb://example.com")?;
+    /// # let coll = client.database("foo").collection::<Document>("bar");
+    /// # let mut session = client.start_session(None)?;
+    /// let deleted_count = session.with_transaction(
+    ///     |session| {
+    ///         coll.insert_one_with_session(doc! { "x": 1 }, None, session)?;
+    ///         let result = coll.delete_many_with_session(doc! { "y": 2 }, None, session)?;
+    ///         Ok(result.deleted_count)
+    ///     },
+    ///     None,
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_transaction<R, F>(
+        &mut self,
+        mut callback: F,
+        options: impl Into<Option<TransactionOptions>>,
+    ) -> Result<R>
+    where
+        F: FnMut(&mut ClientSession) -> Result<R>,
+    {
+        let options = options.into();
+        let start = Instant::now();
+
+        'transaction: loop {
+            self.start_transaction(options.clone())?;
+            let value = match callback(self) {
+                Ok(value) => value,
+                Err(error) => {
+                    if self.async_client_session.in_transaction() {
+                        self.abort_transaction()?;
+                    }
+                    if error.contains_label(TRANSIENT_TRANSACTION_ERROR)
+                        && start.elapsed() < WITH_TRANSACTION_TIMEOUT
+                    {
+                        continue 'transaction;
+                    }
+                    return Err(error);
+                }
+            };
+
+            if !self.async_client_session.in_transaction() {
+                // The callback committed or aborted the transaction itself.
+                return Ok(value);
+            }
+
+            loop {
+                let error = match self.commit_transaction() {
+                    Ok(()) => return Ok(value),
+                    Err(error) => error,
+                };
+                if start.elapsed() >= WITH_TRANSACTION_TIMEOUT {
+                    return Err(error);
+                }
+                if error.contains_label(UNKNOWN_TRANSACTION_COMMIT_RESULT)
+                    && !error.is_max_time_ms_expired_error()
+                {
+                    continue;
+                }
+                if error.contains_label(TRANSIENT_TRANSACTION_ERROR) {
+                    continue 'transaction;
+                }
+                return Err(error);
+            }
+        }
+    }
 }
@@ -7,16 +7,21 @@ use crate::{
     bson::{Bson, Document},
     change_stream::{event::ChangeStreamEvent, options::ChangeStreamOptions},
     error::Result,
-    index::IndexModel,
+    index::{IndexModel, SearchIndexModel},
     options::{
         AggregateOptions,
+        BulkWriteOptions,
+        ConvertToCappedOptions,
         CountOptions,
         CreateIndexOptions,
+        CreateSearchIndexOptions,
         DeleteOptions,
         DistinctOptions,
         DropCollectionOptions,
         DropIndexOptions,
+        DropSearchIndexOptions,
         EstimatedDocumentCountOptions,
+        ExplainVerbosity,
         FindOneAndDeleteOptions,
         FindOneAndReplaceOptions,
         FindOneAndUpdateOptions,
@@ -25,19 +30,28 @@ use crate::{
         InsertManyOptions,
         InsertOneOptions,
         ListIndexesOptions,
+        ListSearchIndexesOptions,
+        ModifyCollectionOptions,
         ReadConcern,
+        RenameCollectionOptions,
         ReplaceOptions,
         SelectionCriteria,
+        SyncIndexOptions,
         UpdateModifications,
         UpdateOptions,
+        UpdateSearchIndexOptions,
         WriteConcern,
+        WriteModel,
     },
     results::{
+        BulkWriteResult,
         CreateIndexResult,
         CreateIndexesResult,
         DeleteResult,
+        ExplainResult,
         InsertManyResult,
         InsertOneResult,
+        SyncIndexesResult,
         UpdateResult,
     },
     runtime,
@@ -144,6 +158,88 @@ impl<T> Collection<T> {
         )
     }
 
+    /// Renames the collection to the namespace `to`, which may be in a different database. If
+    /// `drop_target` is true, an existing collection at `to` is dropped first; otherwise renaming
+    /// to an existing collection fails.
+    pub fn rename(
+        &self,
+        to: Namespace,
+        drop_target: bool,
+        options: impl Into<Option<RenameCollectionOptions>>,
+    ) -> Result<()> {
+        runtime::block_on(
+            self.async_collection
+                .rename(to, drop_target, options.into()),
+        )
+    }
+
+    /// Renames the collection to the namespace `to` using the provided `ClientSession`. If
+    /// `drop_target` is true, an existing collection at `to` is dropped first; otherwise renaming
+    /// to an existing collection fails.
+    pub fn rename_with_session(
+        &self,
+        to: Namespace,
+        drop_target: bool,
+        options: impl Into<Option<RenameCollectionOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        runtime::block_on(self.async_collection.rename_with_session(
+            to,
+            drop_target,
+            options.into(),
+            &mut session.async_client_session,
+        ))
+    }
+
+    /// Changes the properties of the collection that are set in `options`, such as its validator,
+    /// the TTL of an index or whether an index is hidden, using the
+    /// [`collMod`](https://www.mongodb.com/docs/manual/reference/command/collMod/) command.
+    pub fn modify(&self, options: ModifyCollectionOptions) -> Result<()> {
+        runtime::block_on(self.async_collection.modify(options))
+    }
+
+    /// Changes the properties of the collection that are set in `options` using the provided
+    /// `ClientSession`.
+    pub fn modify_with_session(
+        &self,
+        options: ModifyCollectionOptions,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        runtime::block_on(
+            self.async_collection
+                .modify_with_session(options, &mut session.async_client_session),
+        )
+    }
+
+    /// Converts the collection into a capped collection with a maximum size of `size` bytes, using
+    /// the [`convertToCapped`](https://www.mongodb.com/docs/manual/reference/command/convertToCapped/)
+    /// command. The indexes of the collection other than the `_id` index are dropped.
+    pub fn convert_to_capped(
+        &self,
+        size: u64,
+        options: impl Into<Option<ConvertToCappedOptions>>,
+    ) -> Result<()> {
+        runtime::block_on(
+            self.async_collection
+                .convert_to_capped(size, options.into()),
+        )
+    }
+
+    /// Converts the collection into a capped collection with a maximum size of `size` bytes using
+    /// the provided `ClientSession`.
+    pub fn convert_to_capped_with_session(
+        &self,
+        size: u64,
+        options: impl Into<Option<ConvertToCappedOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        runtime::block_on(self.async_collection.convert_to_capped_with_session(
+            size,
+            options.into(),
+            &mut session.async_client_session,
+        ))
+    }
+
     /// Runs an aggregation operation.
     ///
     /// See the documentation [here](https://www.mongodb.com/docs/manual/aggregation/) for more
@@ -177,6 +273,38 @@ impl<T> Collection<T> {
         .map(SessionCursor::new)
     }
 
+    /// Runs an aggregation operation whose output documents are deserialized as `R`. Each
+    /// document is deserialized from the cursor's buffered batch when it is returned.
+    ///
+    /// See the documentation [here](https://www.mongodb.com/docs/manual/aggregation/) for more
+    /// information on aggregations.
+    pub fn aggregate_as<R>(
+        &self,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<AggregateOptions>>,
+    ) -> Result<Cursor<R>> {
+        let pipeline: Vec<Document> = pipeline.into_iter().collect();
+        runtime::block_on(self.async_collection.aggregate_as(pipeline, options.into()))
+            .map(Cursor::new)
+    }
+
+    /// Runs an aggregation operation whose output documents are deserialized as `R` using the
+    /// provided `ClientSession`. See [`Collection::aggregate_as`] for more information.
+    pub fn aggregate_as_with_session<R>(
+        &self,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<AggregateOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<SessionCursor<R>> {
+        let pipeline: Vec<Document> = pipeline.into_iter().collect();
+        runtime::block_on(self.async_collection.aggregate_as_with_session(
+            pipeline,
+            options.into(),
+            &mut session.async_client_session,
+        ))
+        .map(SessionCursor::new)
+    }
+
     /// Estimates the number of documents in the collection using collection metadata.
     ///
     /// Due to an oversight in versions 5.0.0 - 5.0.7 of MongoDB, the `count` server command,
@@ -459,6 +587,97 @@ impl<T> Collection<T> {
         )
     }
 
+    /// Brings the indexes on this collection in line with `models`: the requested indexes that do
+    /// not exist are created, and, if [`SyncIndexOptions::drop_unlisted`] is set, the existing
+    /// indexes that were not requested are dropped. The `_id_` index is never dropped.
+    ///
+    /// See [`crate::Collection::sync_indexes`] for how requested and existing indexes are
+    /// compared.
+    pub fn sync_indexes(
+        &self,
+        models: impl IntoIterator<Item = IndexModel>,
+        options: impl Into<Option<SyncIndexOptions>>,
+    ) -> Result<SyncIndexesResult> {
+        runtime::block_on(self.async_collection.sync_indexes(models, options.into()))
+    }
+
+    /// Creates the given Atlas Search or Atlas Vector Search index on this collection, returning
+    /// its name.
+    ///
+    /// Search indexes are built asynchronously; use
+    /// [`Collection::list_search_indexes`](#method.list_search_indexes) to check whether the index
+    /// is ready to be queried. This method is only supported on Atlas deployments.
+    pub fn create_search_index(
+        &self,
+        model: SearchIndexModel,
+        options: impl Into<Option<CreateSearchIndexOptions>>,
+    ) -> Result<String> {
+        runtime::block_on(
+            self.async_collection
+                .create_search_index(model, options.into()),
+        )
+    }
+
+    /// Creates the given Atlas Search or Atlas Vector Search indexes on this collection, returning
+    /// their names.
+    ///
+    /// Search indexes are built asynchronously; use
+    /// [`Collection::list_search_indexes`](#method.list_search_indexes) to check whether the
+    /// indexes are ready to be queried. This method is only supported on Atlas deployments.
+    pub fn create_search_indexes(
+        &self,
+        models: impl IntoIterator<Item = SearchIndexModel>,
+        options: impl Into<Option<CreateSearchIndexOptions>>,
+    ) -> Result<Vec<String>> {
+        runtime::block_on(
+            self.async_collection
+                .create_search_indexes(models, options.into()),
+        )
+    }
+
+    /// Replaces the definition of the Atlas Search or Atlas Vector Search index with the given
+    /// `name`. This method is only supported on Atlas deployments.
+    pub fn update_search_index(
+        &self,
+        name: impl AsRef<str>,
+        definition: Document,
+        options: impl Into<Option<UpdateSearchIndexOptions>>,
+    ) -> Result<()> {
+        runtime::block_on(self.async_collection.update_search_index(
+            name.as_ref(),
+            definition,
+            options.into(),
+        ))
+    }
+
+    /// Drops the Atlas Search or Atlas Vector Search index with the given `name`. Dropping an index
+    /// that does not exist succeeds. This method is only supported on Atlas deployments.
+    pub fn drop_search_index(
+        &self,
+        name: impl AsRef<str>,
+        options: impl Into<Option<DropSearchIndexOptions>>,
+    ) -> Result<()> {
+        runtime::block_on(
+            self.async_collection
+                .drop_search_index(name.as_ref(), options.into()),
+        )
+    }
+
+    /// Lists the Atlas Search and Atlas Vector Search indexes on this collection, or only the
+    /// index with the given `name` if one is specified, using a `$listSearchIndexes` aggregation.
+    /// This method is only supported on Atlas deployments.
+    pub fn list_search_indexes<'a>(
+        &self,
+        name: impl Into<Option<&'a str>>,
+        options: impl Into<Option<ListSearchIndexesOptions>>,
+    ) -> Result<Cursor<Document>> {
+        runtime::block_on(
+            self.async_collection
+                .list_search_indexes(name.into(), options.into()),
+        )
+        .map(Cursor::new)
+    }
+
     /// Updates all documents matching `query` in the collection using the provided `ClientSession`.
     ///
     /// Both `Document` and `Vec<Document>` implement `Into<UpdateModifications>`, so either can be
@@ -603,6 +822,162 @@ impl<T> Collection<T> {
         ))
         .map(SessionCursor::new)
     }
+
+    /// Returns the query plan the server would use to find the documents in the collection
+    /// matching `filter`, with the amount of detail specified by `verbosity`.
+    ///
+    /// See the MongoDB [manual](https://www.mongodb.com/docs/manual/reference/command/explain/)
+    /// for more information on explaining operations.
+    pub fn explain_find(
+        &self,
+        filter: impl Into<Option<Document>>,
+        options: impl Into<Option<FindOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<ExplainResult> {
+        runtime::block_on(self.async_collection.explain_find(
+            filter.into(),
+            options.into(),
+            verbosity,
+        ))
+    }
+
+    /// Returns the query plan the server would use to run the aggregation `pipeline` on the
+    /// collection, with the amount of detail specified by `verbosity`.
+    ///
+    /// See the MongoDB [manual](https://www.mongodb.com/docs/manual/reference/command/explain/)
+    /// for more information on explaining operations.
+    pub fn explain_aggregate(
+        &self,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<AggregateOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<ExplainResult> {
+        let pipeline: Vec<Document> = pipeline.into_iter().collect();
+        runtime::block_on(self.async_collection.explain_aggregate(
+            pipeline,
+            options.into(),
+            verbosity,
+        ))
+    }
+
+    /// Returns the query plan the server would use to count the documents in the collection
+    /// matching `filter`, with the amount of detail specified by `verbosity`.
+    ///
+    /// See the MongoDB [manual](https://www.mongodb.com/docs/manual/reference/command/explain/)
+    /// for more information on explaining operations.
+    pub fn explain_count_documents(
+        &self,
+        filter: impl Into<Option<Document>>,
+        options: impl Into<Option<CountOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<ExplainResult> {
+        runtime::block_on(self.async_collection.explain_count_documents(
+            filter.into(),
+            options.into(),
+            verbosity,
+        ))
+    }
+
+    /// Returns the query plan the server would use to find the distinct values of the field
+    /// specified by `field_name` across the collection, with the amount of detail specified by
+    /// `verbosity`.
+    ///
+    /// See the MongoDB [manual](https://www.mongodb.com/docs/manual/reference/command/explain/)
+    /// for more information on explaining operations.
+    pub fn explain_distinct(
+        &self,
+        field_name: impl AsRef<str>,
+        filter: impl Into<Option<Document>>,
+        options: impl Into<Option<DistinctOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<ExplainResult> {
+        runtime::block_on(self.async_collection.explain_distinct(
+            field_name.as_ref(),
+            filter.into(),
+            options.into(),
+            verbosity,
+        ))
+    }
+
+    /// Returns the query plan the server would use to update up to one document matching `query`
+    /// in the collection, with the amount of detail specified by `verbosity`. The update is
+    /// never performed, regardless of `verbosity`.
+    ///
+    /// See the MongoDB [manual](https://www.mongodb.com/docs/manual/reference/command/explain/)
+    /// for more information on explaining operations.
+    pub fn explain_update_one(
+        &self,
+        query: Document,
+        update: impl Into<UpdateModifications>,
+        options: impl Into<Option<UpdateOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<ExplainResult> {
+        runtime::block_on(self.async_collection.explain_update_one(
+            query,
+            update.into(),
+            options.into(),
+            verbosity,
+        ))
+    }
+
+    /// Returns the query plan the server would use to update all documents matching `query` in
+    /// the collection, with the amount of detail specified by `verbosity`. The update is
+    /// never performed, regardless of `verbosity`.
+    ///
+    /// See the MongoDB [manual](https://www.mongodb.com/docs/manual/reference/command/explain/)
+    /// for more information on explaining operations.
+    pub fn explain_update_many(
+        &self,
+        query: Document,
+        update: impl Into<UpdateModifications>,
+        options: impl Into<Option<UpdateOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<ExplainResult> {
+        runtime::block_on(self.async_collection.explain_update_many(
+            query,
+            update.into(),
+            options.into(),
+            verbosity,
+        ))
+    }
+
+    /// Returns the query plan the server would use to delete up to one document matching `query`
+    /// in the collection, with the amount of detail specified by `verbosity`. The delete is
+    /// never performed, regardless of `verbosity`.
+    ///
+    /// See the MongoDB [manual](https://www.mongodb.com/docs/manual/reference/command/explain/)
+    /// for more information on explaining operations.
+    pub fn explain_delete_one(
+        &self,
+        query: Document,
+        options: impl Into<Option<DeleteOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<ExplainResult> {
+        runtime::block_on(self.async_collection.explain_delete_one(
+            query,
+            options.into(),
+            verbosity,
+        ))
+    }
+
+    /// Returns the query plan the server would use to delete all documents matching `query` in
+    /// the collection, with the amount of detail specified by `verbosity`. The delete is
+    /// never performed, regardless of `verbosity`.
+    ///
+    /// See the MongoDB [manual](https://www.mongodb.com/docs/manual/reference/command/explain/)
+    /// for more information on explaining operations.
+    pub fn explain_delete_many(
+        &self,
+        query: Document,
+        options: impl Into<Option<DeleteOptions>>,
+        verbosity: ExplainVerbosity,
+    ) -> Result<ExplainResult> {
+        runtime::block_on(self.async_collection.explain_delete_many(
+            query,
+            options.into(),
+            verbosity,
+        ))
+    }
 }
 
 impl<T> Collection<T>
@@ -885,4 +1260,32 @@ where
             &mut session.async_client_session,
         ))
     }
+
+    /// Performs the writes specified by `models` on the collection.
+    ///
+    /// Consecutive models of the same kind (inserts, updates and replacements, or deletes) are
+    /// sent to the server together in as few commands as the server's size limits allow. See
+    /// [`crate::Collection::bulk_write`] for more details.
+    pub fn bulk_write(
+        &self,
+        models: impl IntoIterator<Item = WriteModel<T>>,
+        options: impl Into<Option<BulkWriteOptions>>,
+    ) -> Result<BulkWriteResult> {
+        runtime::block_on(self.async_collection.bulk_write(models, options.into()))
+    }
+
+    /// Performs the writes specified by `models` on the collection using the provided
+    /// `ClientSession`. See [`crate::Collection::bulk_write`] for more details.
+    pub fn bulk_write_with_session(
+        &self,
+        models: impl IntoIterator<Item = WriteModel<T>>,
+        options: impl Into<Option<BulkWriteOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<BulkWriteResult> {
+        runtime::block_on(self.async_collection.bulk_write_with_session(
+            models,
+            options.into(),
+            &mut session.async_client_session,
+        ))
+    }
 }
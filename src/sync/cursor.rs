@@ -1,10 +1,13 @@
+use std::collections::VecDeque;
+
 use futures_util::stream::StreamExt;
 use serde::de::{Deserialize, DeserializeOwned};
 
 use super::ClientSession;
 use crate::{
-    bson::{Document, RawDocument},
+    bson::{Document, RawDocument, RawDocumentBuf},
     error::Result,
+    options::ServerAddress,
     runtime,
     Cursor as AsyncCursor,
     SessionCursor as AsyncSessionCursor,
@@ -165,6 +168,45 @@ impl<T> Cursor<T> {
     {
         self.async_cursor.deserialize_current()
     }
+
+    /// The server-side ID of this cursor, or 0 if the server returned all of its results in the
+    /// first batch.
+    pub fn id(&self) -> i64 {
+        self.async_cursor.id()
+    }
+
+    /// The address of the server this cursor was created on.
+    pub fn address(&self) -> &ServerAddress {
+        self.async_cursor.address()
+    }
+
+    /// Closes the cursor with a
+    /// [killCursors](https://www.mongodb.com/docs/manual/reference/command/killCursors/) command,
+    /// waiting for the command to complete. Any buffered results are discarded, and the cursor
+    /// will not return any further results.
+    ///
+    /// Unlike the `killCursors` command executed when a [`Cursor`] is dropped, this returns any
+    /// error encountered closing the cursor. Closing a cursor that is already exhausted has no
+    /// effect.
+    pub fn close(&mut self) -> Result<()> {
+        runtime::block_on(self.async_cursor.close())
+    }
+
+    /// Returns the rest of the current batch of results, fetching the next batch from the server
+    /// if the current one has been consumed. Returns `None` once the cursor is exhausted.
+    ///
+    /// If [`Cursor::set_prefetch`] is enabled, the request for the following batch is started in
+    /// the background before the batch is returned.
+    pub fn next_batch(&mut self) -> Result<Option<VecDeque<RawDocumentBuf>>> {
+        runtime::block_on(self.async_cursor.next_batch())
+    }
+
+    /// Sets whether [`Cursor::next_batch`] should request the following batch from the server as
+    /// soon as it returns a batch, so that the network round trip overlaps with processing the
+    /// returned batch. This is disabled by default.
+    pub fn set_prefetch(&mut self, prefetch: bool) {
+        self.async_cursor.set_prefetch(prefetch)
+    }
 }
 
 impl<T> Iterator for Cursor<T>
@@ -296,6 +338,29 @@ impl<T> SessionCursor<T> {
     {
         self.async_cursor.deserialize_current()
     }
+
+    /// The server-side ID of this cursor, or 0 if the server returned all of its results in the
+    /// first batch.
+    pub fn id(&self) -> i64 {
+        self.async_cursor.id()
+    }
+
+    /// The address of the server this cursor was created on.
+    pub fn address(&self) -> &ServerAddress {
+        self.async_cursor.address()
+    }
+
+    /// Closes the cursor with a
+    /// [killCursors](https://www.mongodb.com/docs/manual/reference/command/killCursors/) command
+    /// using the provided session, waiting for the command to complete. Any buffered results are
+    /// discarded, and the cursor will not return any further results.
+    ///
+    /// Unlike the `killCursors` command executed when a [`SessionCursor`] is dropped, this returns
+    /// any error encountered closing the cursor. Closing a cursor that is already exhausted has no
+    /// effect.
+    pub fn close(&mut self, session: &mut ClientSession) -> Result<()> {
+        runtime::block_on(self.async_cursor.close(&mut session.async_client_session))
+    }
 }
 
 impl<T> SessionCursor<T>
@@ -1,16 +1,27 @@
 use std::fmt::Debug;
 
-use super::{ChangeStream, ClientSession, Collection, Cursor, SessionChangeStream, SessionCursor};
+use super::{
+    ChangeStream,
+    ClientSession,
+    Collection,
+    Cursor,
+    GridFsBucket,
+    SessionChangeStream,
+    SessionCursor,
+};
 use crate::{
     bson::Document,
     change_stream::{event::ChangeStreamEvent, options::ChangeStreamOptions},
     error::Result,
+    gridfs::options::GridFsBucketOptions,
     options::{
         AggregateOptions,
         CollectionOptions,
         CreateCollectionOptions,
+        CreateViewOptions,
         DropDatabaseOptions,
         ListCollectionsOptions,
+        ModifyViewOptions,
         ReadConcern,
         SelectionCriteria,
         WriteConcern,
@@ -210,6 +221,83 @@ impl Database {
         ))
     }
 
+    /// Creates a read-only view with the given `name` whose contents are the results of running
+    /// the aggregation `pipeline` on the collection or view `source` in this database.
+    ///
+    /// See the MongoDB [manual](https://www.mongodb.com/docs/manual/core/views/) for more
+    /// information on views.
+    pub fn create_view(
+        &self,
+        name: impl AsRef<str>,
+        source: impl AsRef<str>,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<CreateViewOptions>>,
+    ) -> Result<()> {
+        runtime::block_on(self.async_database.create_view(
+            name.as_ref(),
+            source.as_ref(),
+            pipeline,
+            options.into(),
+        ))
+    }
+
+    /// Creates a read-only view with the given `name` whose contents are the results of running
+    /// the aggregation `pipeline` on the collection or view `source` in this database using the
+    /// provided `ClientSession`.
+    pub fn create_view_with_session(
+        &self,
+        name: impl AsRef<str>,
+        source: impl AsRef<str>,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<CreateViewOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        runtime::block_on(self.async_database.create_view_with_session(
+            name.as_ref(),
+            source.as_ref(),
+            pipeline,
+            options.into(),
+            &mut session.async_client_session,
+        ))
+    }
+
+    /// Replaces the definition of the view with the given `name`, so that its contents are the
+    /// results of running the aggregation `pipeline` on the collection or view `source`.
+    pub fn modify_view(
+        &self,
+        name: impl AsRef<str>,
+        source: impl AsRef<str>,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<ModifyViewOptions>>,
+    ) -> Result<()> {
+        runtime::block_on(self.async_database.modify_view(
+            name.as_ref(),
+            source.as_ref(),
+            pipeline,
+            options.into(),
+        ))
+    }
+
+    /// Replaces the definition of the view with the given `name` using the provided
+    /// `ClientSession`, so that its contents are the results of running the aggregation `pipeline`
+    /// on the collection or view `source`.
+    pub fn modify_view_with_session(
+        &self,
+        name: impl AsRef<str>,
+        source: impl AsRef<str>,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<ModifyViewOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        runtime::block_on(self.async_database.modify_view_with_session(
+            name.as_ref(),
+            source.as_ref(),
+            pipeline,
+            options.into(),
+            &mut session.async_client_session,
+        ))
+    }
+
     /// Runs a database-level command.
     ///
     /// Note that no inspection is done on `doc`, so the command will not use the database's default
@@ -276,6 +364,38 @@ impl Database {
         .map(SessionCursor::new)
     }
 
+    /// Runs an aggregation operation whose output documents are deserialized as `R`. Each
+    /// document is deserialized from the cursor's buffered batch when it is returned.
+    ///
+    /// See the documentation [here](https://www.mongodb.com/docs/manual/aggregation/) for more
+    /// information on aggregations.
+    pub fn aggregate_as<R>(
+        &self,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<AggregateOptions>>,
+    ) -> Result<Cursor<R>> {
+        let pipeline: Vec<Document> = pipeline.into_iter().collect();
+        runtime::block_on(self.async_database.aggregate_as(pipeline, options.into()))
+            .map(Cursor::new)
+    }
+
+    /// Runs an aggregation operation whose output documents are deserialized as `R` using the
+    /// provided `ClientSession`. See [`Database::aggregate_as`] for more information.
+    pub fn aggregate_as_with_session<R>(
+        &self,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<AggregateOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<SessionCursor<R>> {
+        let pipeline: Vec<Document> = pipeline.into_iter().collect();
+        runtime::block_on(self.async_database.aggregate_as_with_session(
+            pipeline,
+            options.into(),
+            &mut session.async_client_session,
+        ))
+        .map(SessionCursor::new)
+    }
+
     /// Starts a new [`ChangeStream`](change_stream/struct.ChangeStream.html) that receives events
     /// for all changes in this database. The stream does not observe changes from system
     /// collections and cannot be started on "config", "local" or "admin" databases.
@@ -316,4 +436,9 @@ impl Database {
         ))
         .map(SessionChangeStream::new)
     }
+
+    /// Creates a new [`GridFsBucket`] in the database with the given options.
+    pub fn gridfs_bucket(&self, options: impl Into<Option<GridFsBucketOptions>>) -> GridFsBucket {
+        GridFsBucket::new(self.async_database.gridfs_bucket(options))
+    }
 }
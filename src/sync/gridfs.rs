@@ -0,0 +1,678 @@
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use futures_util::io::{AllowStdIo, AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{ClientSession, Cursor, SessionCursor};
+use crate::{
+    bson::{Bson, Document},
+    concern::{ReadConcern, WriteConcern},
+    error::Result,
+    gridfs::{
+        download::GridFsDownloadStream as AsyncGridFsDownloadStream,
+        options::{GridFsDownloadByNameOptions, GridFsFindOptions, GridFsUploadOptions},
+        FilesCollectionDocument,
+        GridFsBucket as AsyncGridFsBucket,
+        GridFsUploadStream as AsyncGridFsUploadStream,
+        TypedGridFsBucket as AsyncTypedGridFsBucket,
+    },
+    runtime,
+    selection_criteria::SelectionCriteria,
+};
+
+/// Struct for storing GridFS managed files within a [`Database`](super::Database). A
+/// `GridFsBucket` can be obtained through
+/// [`Database::gridfs_bucket`](super::Database::gridfs_bucket).
+///
+/// This is a blocking wrapper around the async `GridFsBucket`. See its documentation for more
+/// details on the behavior of each method.
+#[derive(Debug, Clone)]
+pub struct GridFsBucket {
+    async_bucket: AsyncGridFsBucket,
+}
+
+impl GridFsBucket {
+    pub(crate) fn new(async_bucket: AsyncGridFsBucket) -> Self {
+        Self { async_bucket }
+    }
+
+    /// Gets the read concern of the [`GridFsBucket`].
+    pub fn read_concern(&self) -> Option<&ReadConcern> {
+        self.async_bucket.read_concern()
+    }
+
+    /// Gets the write concern of the [`GridFsBucket`].
+    pub fn write_concern(&self) -> Option<&WriteConcern> {
+        self.async_bucket.write_concern()
+    }
+
+    /// Gets the selection criteria of the [`GridFsBucket`].
+    pub fn selection_criteria(&self) -> Option<&SelectionCriteria> {
+        self.async_bucket.selection_criteria()
+    }
+
+    /// Gets a view of the [`GridFsBucket`] in which the `metadata` field of each file is
+    /// serialized from and deserialized to the type `M`.
+    pub fn typed_metadata<M>(&self) -> TypedGridFsBucket<M>
+    where
+        M: Serialize + DeserializeOwned + Unpin + Send + Sync,
+    {
+        TypedGridFsBucket {
+            async_bucket: self.async_bucket.typed_metadata(),
+            bucket: self.clone(),
+        }
+    }
+
+    /// Opens a [`GridFsUploadStream`] that the application can write the contents of the file to.
+    /// The driver generates a unique [`Bson::ObjectId`] for the file id.
+    pub fn open_upload_stream(
+        &self,
+        filename: String,
+        options: impl Into<Option<GridFsUploadOptions>>,
+    ) -> Result<GridFsUploadStream> {
+        runtime::block_on(self.async_bucket.open_upload_stream(filename, options))
+            .map(GridFsUploadStream::new)
+    }
+
+    /// Opens a [`GridFsUploadStream`] that the application can write the contents of the file to.
+    /// The application provides a custom file id.
+    pub fn open_upload_stream_with_id(
+        &self,
+        id: Bson,
+        filename: String,
+        options: impl Into<Option<GridFsUploadOptions>>,
+    ) -> Result<GridFsUploadStream> {
+        runtime::block_on(
+            self.async_bucket
+                .open_upload_stream_with_id(id, filename, options),
+        )
+        .map(GridFsUploadStream::new)
+    }
+
+    /// Uploads the contents of `source` to a GridFS bucket. The driver generates a unique
+    /// [`Bson::ObjectId`] for the file id. Any chunks that have been written are deleted if reading
+    /// from `source` or writing to the bucket fails.
+    ///
+    /// Returns the id of the uploaded file.
+    pub fn upload_from_reader(
+        &self,
+        filename: String,
+        source: impl Read,
+        options: impl Into<Option<GridFsUploadOptions>>,
+    ) -> Result<Bson> {
+        runtime::block_on(self.async_bucket.upload_from_futures_0_3_reader(
+            filename,
+            AllowStdIo::new(source),
+            options,
+        ))
+    }
+
+    /// Uploads the contents of `source` to a GridFS bucket. The application supplies a custom file
+    /// id. Any chunks that have been written are deleted if reading from `source` or writing to
+    /// the bucket fails.
+    ///
+    /// Returns the id of the uploaded file.
+    pub fn upload_from_reader_with_id(
+        &self,
+        id: Bson,
+        filename: String,
+        source: impl Read,
+        options: impl Into<Option<GridFsUploadOptions>>,
+    ) -> Result<Bson> {
+        runtime::block_on(self.async_bucket.upload_from_futures_0_3_reader_with_id(
+            id,
+            filename,
+            AllowStdIo::new(source),
+            options,
+        ))
+    }
+
+    /// Uploads the contents of `source` to a GridFS bucket using the provided session. The driver
+    /// generates a unique [`Bson::ObjectId`] for the file id.
+    ///
+    /// Returns the id of the uploaded file.
+    pub fn upload_from_reader_with_session(
+        &self,
+        filename: String,
+        source: impl Read,
+        options: impl Into<Option<GridFsUploadOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<Bson> {
+        runtime::block_on(
+            self.async_bucket
+                .upload_from_futures_0_3_reader_with_session(
+                    filename,
+                    AllowStdIo::new(source),
+                    options,
+                    &mut session.async_client_session,
+                ),
+        )
+    }
+
+    /// Uploads the contents of `source` to a GridFS bucket using the provided session. The
+    /// application supplies a custom file id.
+    ///
+    /// Returns the id of the uploaded file.
+    pub fn upload_from_reader_with_id_and_session(
+        &self,
+        id: Bson,
+        filename: String,
+        source: impl Read,
+        options: impl Into<Option<GridFsUploadOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<Bson> {
+        runtime::block_on(
+            self.async_bucket
+                .upload_from_futures_0_3_reader_with_id_and_session(
+                    id,
+                    filename,
+                    AllowStdIo::new(source),
+                    options,
+                    &mut session.async_client_session,
+                ),
+        )
+    }
+
+    /// Opens and returns a [`GridFsDownloadStream`] from which the application can read
+    /// the contents of the stored file specified by `id`.
+    pub fn open_download_stream(&self, id: Bson) -> Result<GridFsDownloadStream> {
+        runtime::block_on(self.async_bucket.open_download_stream(id)).map(GridFsDownloadStream::new)
+    }
+
+    /// Opens and returns a [`GridFsDownloadStream`] from which the application can read the bytes
+    /// within `range` of the stored file specified by `id`.
+    pub fn open_download_stream_range(
+        &self,
+        id: Bson,
+        range: impl std::ops::RangeBounds<u64>,
+    ) -> Result<GridFsDownloadStream> {
+        runtime::block_on(self.async_bucket.open_download_stream_range(id, range))
+            .map(GridFsDownloadStream::new)
+    }
+
+    /// Opens and returns a [`GridFsDownloadStream`] from which the application can read
+    /// the contents of the stored file specified by `filename` and the revision
+    /// in `options`.
+    pub fn open_download_stream_by_name(
+        &self,
+        filename: String,
+        options: impl Into<Option<GridFsDownloadByNameOptions>>,
+    ) -> Result<GridFsDownloadStream> {
+        runtime::block_on(
+            self.async_bucket
+                .open_download_stream_by_name(filename, options),
+        )
+        .map(GridFsDownloadStream::new)
+    }
+
+    /// Downloads the contents of the stored file specified by `id` and writes
+    /// the contents to the `destination`.
+    pub fn download_to_writer(&self, id: Bson, mut destination: impl Write) -> Result<()> {
+        runtime::block_on(
+            self.async_bucket
+                .download_to_futures_0_3_writer(id, AllowStdIo::new(&mut destination)),
+        )
+    }
+
+    /// Downloads the contents of the stored file specified by `filename` and writes the contents to
+    /// the `destination`. If there are multiple files with the same filename, the `revision` in the
+    /// options provided is used to determine which one to download. If no `revision` is specified,
+    /// the most recent file with the given filename is chosen.
+    pub fn download_to_writer_by_name(
+        &self,
+        filename: impl AsRef<str>,
+        mut destination: impl Write,
+        options: impl Into<Option<GridFsDownloadByNameOptions>>,
+    ) -> Result<()> {
+        runtime::block_on(self.async_bucket.download_to_futures_0_3_writer_by_name(
+            filename,
+            AllowStdIo::new(&mut destination),
+            options,
+        ))
+    }
+
+    /// Downloads the contents of the stored file specified by `id` and writes the contents to the
+    /// `destination`, reading the file and its chunks using the provided session.
+    pub fn download_to_writer_with_session(
+        &self,
+        id: Bson,
+        mut destination: impl Write,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        runtime::block_on(
+            self.async_bucket
+                .download_to_futures_0_3_writer_with_session(
+                    id,
+                    AllowStdIo::new(&mut destination),
+                    &mut session.async_client_session,
+                ),
+        )
+    }
+
+    /// Downloads the contents of the stored file specified by `filename` and writes the contents to
+    /// the `destination`, reading the file and its chunks using the provided session. See
+    /// [`GridFsBucket::download_to_writer_by_name`] for how the revision is chosen.
+    pub fn download_to_writer_by_name_with_session(
+        &self,
+        filename: impl AsRef<str>,
+        mut destination: impl Write,
+        options: impl Into<Option<GridFsDownloadByNameOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        runtime::block_on(
+            self.async_bucket
+                .download_to_futures_0_3_writer_by_name_with_session(
+                    filename,
+                    AllowStdIo::new(&mut destination),
+                    options,
+                    &mut session.async_client_session,
+                ),
+        )
+    }
+
+    /// Deletes the [`FilesCollectionDocument`] with the given `id `and its associated chunks from
+    /// this bucket.
+    pub fn delete(&self, id: Bson) -> Result<()> {
+        runtime::block_on(self.async_bucket.delete(id))
+    }
+
+    /// Deletes the [`FilesCollectionDocument`] with the given `id `and its associated chunks from
+    /// this bucket using the provided session.
+    pub fn delete_with_session(&self, id: Bson, session: &mut ClientSession) -> Result<()> {
+        runtime::block_on(
+            self.async_bucket
+                .delete_with_session(id, &mut session.async_client_session),
+        )
+    }
+
+    /// Finds and returns the [`FilesCollectionDocument`]s within this bucket that match the given
+    /// filter.
+    pub fn find(
+        &self,
+        filter: Document,
+        options: impl Into<Option<GridFsFindOptions>>,
+    ) -> Result<Cursor<FilesCollectionDocument>> {
+        runtime::block_on(self.async_bucket.find(filter, options)).map(Cursor::new)
+    }
+
+    /// Finds and returns the [`FilesCollectionDocument`]s within this bucket that match the given
+    /// filter using the provided session.
+    pub fn find_with_session(
+        &self,
+        filter: Document,
+        options: impl Into<Option<GridFsFindOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<SessionCursor<FilesCollectionDocument>> {
+        runtime::block_on(self.async_bucket.find_with_session(
+            filter,
+            options,
+            &mut session.async_client_session,
+        ))
+        .map(SessionCursor::new)
+    }
+
+    /// Renames the file with the given 'id' to the provided `new_filename`.
+    pub fn rename(&self, id: Bson, new_filename: impl AsRef<str>) -> Result<()> {
+        runtime::block_on(self.async_bucket.rename(id, new_filename))
+    }
+
+    /// Renames the file with the given 'id' to the provided `new_filename` using the provided
+    /// session.
+    pub fn rename_with_session(
+        &self,
+        id: Bson,
+        new_filename: impl AsRef<str>,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        runtime::block_on(self.async_bucket.rename_with_session(
+            id,
+            new_filename,
+            &mut session.async_client_session,
+        ))
+    }
+
+    /// Deletes all revisions of the file with the given `filename` and their associated chunks
+    /// from this bucket. Returns the number of revisions that were deleted.
+    pub fn delete_by_name(&self, filename: impl AsRef<str>) -> Result<u64> {
+        runtime::block_on(self.async_bucket.delete_by_name(filename))
+    }
+
+    /// Deletes all revisions of the file with the given `filename` and their associated chunks
+    /// from this bucket using the provided session. Returns the number of revisions that were
+    /// deleted.
+    pub fn delete_by_name_with_session(
+        &self,
+        filename: impl AsRef<str>,
+        session: &mut ClientSession,
+    ) -> Result<u64> {
+        runtime::block_on(
+            self.async_bucket
+                .delete_by_name_with_session(filename, &mut session.async_client_session),
+        )
+    }
+
+    /// Renames all revisions of the file with the given `filename` to `new_filename`.
+    pub fn rename_all_revisions(
+        &self,
+        filename: impl AsRef<str>,
+        new_filename: impl AsRef<str>,
+    ) -> Result<()> {
+        runtime::block_on(
+            self.async_bucket
+                .rename_all_revisions(filename, new_filename),
+        )
+    }
+
+    /// Renames all revisions of the file with the given `filename` to `new_filename` using the
+    /// provided session.
+    pub fn rename_all_revisions_with_session(
+        &self,
+        filename: impl AsRef<str>,
+        new_filename: impl AsRef<str>,
+        session: &mut ClientSession,
+    ) -> Result<()> {
+        runtime::block_on(self.async_bucket.rename_all_revisions_with_session(
+            filename,
+            new_filename,
+            &mut session.async_client_session,
+        ))
+    }
+
+    /// Deletes all but the `keep_latest` most recent revisions of the file with the given
+    /// `filename`, along with their associated chunks. Returns the number of revisions that were
+    /// deleted.
+    pub fn prune_revisions(&self, filename: impl AsRef<str>, keep_latest: u32) -> Result<u64> {
+        runtime::block_on(self.async_bucket.prune_revisions(filename, keep_latest))
+    }
+
+    /// Deletes all but the `keep_latest` most recent revisions of the file with the given
+    /// `filename`, along with their associated chunks, using the provided session. Returns the
+    /// number of revisions that were deleted.
+    pub fn prune_revisions_with_session(
+        &self,
+        filename: impl AsRef<str>,
+        keep_latest: u32,
+        session: &mut ClientSession,
+    ) -> Result<u64> {
+        runtime::block_on(self.async_bucket.prune_revisions_with_session(
+            filename,
+            keep_latest,
+            &mut session.async_client_session,
+        ))
+    }
+
+    /// Drops all of the files and their associated chunks in this bucket.
+    pub fn drop(&self) -> Result<()> {
+        runtime::block_on(self.async_bucket.drop())
+    }
+
+    /// Drops all of the files and their associated chunks in this bucket using the provided
+    /// session.
+    pub fn drop_with_session(&self, session: &mut ClientSession) -> Result<()> {
+        runtime::block_on(
+            self.async_bucket
+                .drop_with_session(&mut session.async_client_session),
+        )
+    }
+}
+
+/// A view of a [`GridFsBucket`] in which the `metadata` field of each file is serialized from and
+/// deserialized to the type `M`. Instances of this type can be created using
+/// [`GridFsBucket::typed_metadata`].
+///
+/// This is a blocking wrapper around the async `TypedGridFsBucket`. See its documentation for more
+/// details on the behavior of each method.
+#[derive(Debug)]
+pub struct TypedGridFsBucket<M> {
+    async_bucket: AsyncTypedGridFsBucket<M>,
+    bucket: GridFsBucket,
+}
+
+impl<M> Clone for TypedGridFsBucket<M> {
+    fn clone(&self) -> Self {
+        Self {
+            async_bucket: self.async_bucket.clone(),
+            bucket: self.bucket.clone(),
+        }
+    }
+}
+
+impl<M> TypedGridFsBucket<M>
+where
+    M: Serialize + DeserializeOwned + Unpin + Send + Sync,
+{
+    /// Gets the underlying [`GridFsBucket`].
+    pub fn bucket(&self) -> &GridFsBucket {
+        &self.bucket
+    }
+
+    /// Finds and returns the [`FilesCollectionDocument`]s within this bucket that match the given
+    /// filter, deserializing the `metadata` field of each as an `M`.
+    pub fn find(
+        &self,
+        filter: Document,
+        options: impl Into<Option<GridFsFindOptions>>,
+    ) -> Result<Cursor<FilesCollectionDocument<M>>> {
+        runtime::block_on(self.async_bucket.find(filter, options)).map(Cursor::new)
+    }
+
+    /// Finds and returns the [`FilesCollectionDocument`]s within this bucket that match the given
+    /// filter using the provided session, deserializing the `metadata` field of each as an `M`.
+    pub fn find_with_session(
+        &self,
+        filter: Document,
+        options: impl Into<Option<GridFsFindOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<SessionCursor<FilesCollectionDocument<M>>> {
+        runtime::block_on(self.async_bucket.find_with_session(
+            filter,
+            options,
+            &mut session.async_client_session,
+        ))
+        .map(SessionCursor::new)
+    }
+
+    /// Opens a [`GridFsUploadStream`] that the application can write the contents of the file to,
+    /// storing `metadata` in the file's `metadata` field. The driver generates a unique
+    /// [`Bson::ObjectId`] for the file id.
+    pub fn open_upload_stream(
+        &self,
+        filename: String,
+        metadata: &M,
+        options: impl Into<Option<GridFsUploadOptions>>,
+    ) -> Result<GridFsUploadStream> {
+        runtime::block_on(
+            self.async_bucket
+                .open_upload_stream(filename, metadata, options),
+        )
+        .map(GridFsUploadStream::new)
+    }
+
+    /// Opens a [`GridFsUploadStream`] that the application can write the contents of the file to,
+    /// storing `metadata` in the file's `metadata` field. The application provides a custom file
+    /// id.
+    pub fn open_upload_stream_with_id(
+        &self,
+        id: Bson,
+        filename: String,
+        metadata: &M,
+        options: impl Into<Option<GridFsUploadOptions>>,
+    ) -> Result<GridFsUploadStream> {
+        runtime::block_on(
+            self.async_bucket
+                .open_upload_stream_with_id(id, filename, metadata, options),
+        )
+        .map(GridFsUploadStream::new)
+    }
+
+    /// Uploads the contents of `source` to a GridFS bucket, storing `metadata` in the file's
+    /// `metadata` field. The driver generates a unique [`Bson::ObjectId`] for the file id.
+    ///
+    /// Returns the id of the uploaded file.
+    pub fn upload_from_reader(
+        &self,
+        filename: String,
+        source: impl Read,
+        metadata: &M,
+        options: impl Into<Option<GridFsUploadOptions>>,
+    ) -> Result<Bson> {
+        runtime::block_on(self.async_bucket.upload_from_futures_0_3_reader(
+            filename,
+            AllowStdIo::new(source),
+            metadata,
+            options,
+        ))
+    }
+
+    /// Uploads the contents of `source` to a GridFS bucket, storing `metadata` in the file's
+    /// `metadata` field. The application supplies a custom file id.
+    ///
+    /// Returns the id of the uploaded file.
+    pub fn upload_from_reader_with_id(
+        &self,
+        id: Bson,
+        filename: String,
+        source: impl Read,
+        metadata: &M,
+        options: impl Into<Option<GridFsUploadOptions>>,
+    ) -> Result<Bson> {
+        runtime::block_on(self.async_bucket.upload_from_futures_0_3_reader_with_id(
+            id,
+            filename,
+            AllowStdIo::new(source),
+            metadata,
+            options,
+        ))
+    }
+
+    /// Uploads the contents of `source` to a GridFS bucket using the provided session, storing
+    /// `metadata` in the file's `metadata` field. The driver generates a unique
+    /// [`Bson::ObjectId`] for the file id.
+    ///
+    /// Returns the id of the uploaded file.
+    pub fn upload_from_reader_with_session(
+        &self,
+        filename: String,
+        source: impl Read,
+        metadata: &M,
+        options: impl Into<Option<GridFsUploadOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<Bson> {
+        runtime::block_on(
+            self.async_bucket
+                .upload_from_futures_0_3_reader_with_session(
+                    filename,
+                    AllowStdIo::new(source),
+                    metadata,
+                    options,
+                    &mut session.async_client_session,
+                ),
+        )
+    }
+
+    /// Uploads the contents of `source` to a GridFS bucket using the provided session, storing
+    /// `metadata` in the file's `metadata` field. The application supplies a custom file id.
+    ///
+    /// Returns the id of the uploaded file.
+    pub fn upload_from_reader_with_id_and_session(
+        &self,
+        id: Bson,
+        filename: String,
+        source: impl Read,
+        metadata: &M,
+        options: impl Into<Option<GridFsUploadOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<Bson> {
+        runtime::block_on(
+            self.async_bucket
+                .upload_from_futures_0_3_reader_with_id_and_session(
+                    id,
+                    filename,
+                    AllowStdIo::new(source),
+                    metadata,
+                    options,
+                    &mut session.async_client_session,
+                ),
+        )
+    }
+}
+
+/// A stream to which bytes can be written to be uploaded to a GridFS bucket. Instances of this
+/// type can be created using [`GridFsBucket::open_upload_stream`] or
+/// [`GridFsBucket::open_upload_stream_with_id`].
+///
+/// The upload is completed by calling [`GridFsUploadStream::finish`]. If the stream is dropped
+/// before then, any chunks that have already been written will be deleted.
+pub struct GridFsUploadStream {
+    async_stream: AsyncGridFsUploadStream,
+}
+
+impl GridFsUploadStream {
+    fn new(async_stream: AsyncGridFsUploadStream) -> Self {
+        Self { async_stream }
+    }
+
+    /// Gets the file `id` for the stream.
+    pub fn files_id(&self) -> &Bson {
+        self.async_stream.files_id()
+    }
+
+    /// Consumes the stream and uploads data in the stream to the server.
+    pub fn finish(self) -> Result<()> {
+        runtime::block_on(self.async_stream.finish())
+    }
+
+    /// Aborts the upload and discards the upload stream. Any chunks that have already been
+    /// written to the chunks collection will be deleted.
+    pub fn abort(self) -> Result<()> {
+        runtime::block_on(self.async_stream.abort())
+    }
+}
+
+impl Write for GridFsUploadStream {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        runtime::block_on(self.async_stream.write(buf))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        runtime::block_on(self.async_stream.flush())
+    }
+}
+
+/// A stream from which a file stored in a GridFS bucket can be read. Instances of this type can
+/// be created using [`GridFsBucket::open_download_stream`],
+/// [`GridFsBucket::open_download_stream_by_name`], or
+/// [`GridFsBucket::open_download_stream_range`].
+///
+/// Positions used when seeking are relative to the start of the range of the file being read.
+pub struct GridFsDownloadStream {
+    async_stream: AsyncGridFsDownloadStream,
+}
+
+impl GridFsDownloadStream {
+    fn new(async_stream: AsyncGridFsDownloadStream) -> Self {
+        Self { async_stream }
+    }
+
+    /// Gets the file `id` for the stream.
+    pub fn files_id(&self) -> &Bson {
+        self.async_stream.files_id()
+    }
+
+    /// Gets the [`FilesCollectionDocument`] for the file being read.
+    pub fn file(&self) -> &FilesCollectionDocument {
+        self.async_stream.file()
+    }
+}
+
+impl Read for GridFsDownloadStream {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        runtime::block_on(self.async_stream.read(buf))
+    }
+}
+
+impl Seek for GridFsDownloadStream {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        runtime::block_on(self.async_stream.seek(pos))
+    }
+}
@@ -5,6 +5,7 @@ mod client;
 mod coll;
 mod cursor;
 mod db;
+mod gridfs;
 
 #[cfg(test)]
 mod test;
@@ -14,6 +15,7 @@ pub use client::{session::ClientSession, Client};
 pub use coll::Collection;
 pub use cursor::{Cursor, SessionCursor, SessionCursorIter};
 pub use db::Database;
+pub use gridfs::{GridFsBucket, GridFsDownloadStream, GridFsUploadStream, TypedGridFsBucket};
 
 #[cfg(feature = "tokio-sync")]
 lazy_static::lazy_static! {
@@ -1,4 +1,7 @@
-use std::fmt::Debug;
+use std::{
+    fmt::Debug,
+    io::{Read, Seek, SeekFrom, Write},
+};
 
 use lazy_static::lazy_static;
 use pretty_assertions::assert_eq;
@@ -8,6 +11,7 @@ use tokio::sync::RwLockReadGuard;
 use crate::{
     bson::{doc, Document},
     error::Result,
+    gridfs::options::GridFsBucketOptions,
     options::{
         Acknowledgment,
         ClientOptions,
@@ -18,7 +22,7 @@ use crate::{
         WriteConcern,
     },
     runtime,
-    sync::{Client, Collection},
+    sync::{Client, Collection, Cursor},
     test::{TestClient as AsyncTestClient, LOCK},
 };
 
@@ -134,13 +138,25 @@ fn database() {
         doc! { "$project": { "_id": 0, "dummy": 1 } },
     ];
     let cursor = admin_db
-        .aggregate(pipeline, None)
+        .aggregate(pipeline.clone(), None)
         .expect("aggregate should succeed");
     let results: Vec<Document> = cursor
         .collect::<Result<Vec<Document>>>()
         .expect("cursor iteration should succeed");
     assert_eq!(results, vec![doc! { "dummy": 1 }]);
 
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Dummy {
+        dummy: i32,
+    }
+    let cursor: Cursor<Dummy> = admin_db
+        .aggregate_as(pipeline, None)
+        .expect("aggregate should succeed");
+    let results = cursor
+        .collect::<Result<Vec<Dummy>>>()
+        .expect("cursor iteration should succeed");
+    assert_eq!(results, vec![Dummy { dummy: 1 }]);
+
     let wc = WriteConcern {
         w: Some(Acknowledgment::Majority),
         journal: None,
@@ -271,6 +287,44 @@ fn transactions() {
         .expect("abort transaction should succeed");
 }
 
+#[test]
+#[function_name::named]
+fn with_transaction() {
+    let _guard: RwLockReadGuard<()> = runtime::block_on(async { LOCK.run_concurrently().await });
+
+    let should_skip = runtime::block_on(async {
+        let test_client = AsyncTestClient::new().await;
+        !test_client.supports_transactions()
+    });
+    if should_skip {
+        return;
+    }
+
+    let options = CLIENT_OPTIONS.clone();
+    let client = Client::with_options(options).expect("client creation should succeed");
+    let mut session = client
+        .start_session(None)
+        .expect("session creation should succeed");
+    let coll = init_db_and_typed_coll(&client, function_name!(), function_name!());
+
+    client
+        .database(function_name!())
+        .create_collection(function_name!(), None)
+        .expect("create collection should succeed");
+
+    let value = session
+        .with_transaction(
+            |session| {
+                coll.insert_one_with_session(doc! { "x": 1 }, None, session)?;
+                Ok(1)
+            },
+            None,
+        )
+        .expect("with_transaction should succeed");
+    assert_eq!(value, 1);
+    assert_eq!(coll.count_documents(None, None).unwrap(), 1);
+}
+
 #[test]
 #[function_name::named]
 fn collection_generic_bounds() {
@@ -397,3 +451,104 @@ fn mixed_sync_and_async() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+#[function_name::named]
+fn gridfs() {
+    let _guard: RwLockReadGuard<()> = runtime::block_on(async { LOCK.run_concurrently().await });
+
+    let options = CLIENT_OPTIONS.clone();
+    let client = Client::with_options(options).expect("client creation should succeed");
+    let options = GridFsBucketOptions::builder().chunk_size_bytes(4).build();
+    let bucket = client.database(function_name!()).gridfs_bucket(options);
+    bucket.drop().unwrap();
+
+    let data: Vec<u8> = (0..50).collect();
+
+    let mut upload_stream = bucket
+        .open_upload_stream("stream".to_string(), None)
+        .unwrap();
+    upload_stream.write_all(&data).unwrap();
+    let id = upload_stream.files_id().clone();
+    upload_stream.finish().unwrap();
+
+    let mut download_stream = bucket.open_download_stream(id).unwrap();
+    let mut buf = Vec::new();
+    download_stream.read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, data);
+    download_stream.seek(SeekFrom::Start(45)).unwrap();
+    let mut buf = Vec::new();
+    download_stream.read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, &data[45..]);
+
+    let id = bucket
+        .upload_from_reader("reader".to_string(), data.as_slice(), None)
+        .unwrap();
+    let mut written = Vec::new();
+    bucket.download_to_writer(id, &mut written).unwrap();
+    assert_eq!(written, data);
+
+    let mut written = Vec::new();
+    bucket
+        .download_to_writer_by_name("reader", &mut written, None)
+        .unwrap();
+    assert_eq!(written, data);
+
+    let files: Vec<_> = bucket
+        .find(doc! {}, None)
+        .unwrap()
+        .collect::<Result<_>>()
+        .unwrap();
+    assert_eq!(files.len(), 2);
+}
+
+#[test]
+#[function_name::named]
+fn gridfs_typed_metadata() {
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Metadata {
+        owner: String,
+    }
+
+    let _guard: RwLockReadGuard<()> = runtime::block_on(async { LOCK.run_concurrently().await });
+
+    let options = CLIENT_OPTIONS.clone();
+    let client = Client::with_options(options).expect("client creation should succeed");
+    let bucket = client.database(function_name!()).gridfs_bucket(None);
+    bucket.drop().unwrap();
+
+    let typed = bucket.typed_metadata::<Metadata>();
+    let metadata = Metadata {
+        owner: "audit".to_string(),
+    };
+    let mut upload_stream = typed
+        .open_upload_stream("stream".to_string(), &metadata, None)
+        .unwrap();
+    upload_stream.write_all(&[1, 2, 3]).unwrap();
+    upload_stream.finish().unwrap();
+
+    let mut session = client.start_session(None).unwrap();
+    let id = typed
+        .upload_from_reader_with_session(
+            "reader".to_string(),
+            &[4u8, 5][..],
+            &metadata,
+            None,
+            &mut session,
+        )
+        .unwrap();
+
+    let files: Vec<_> = typed
+        .find(doc! {}, None)
+        .unwrap()
+        .collect::<Result<_>>()
+        .unwrap();
+    assert_eq!(files.len(), 2);
+    assert!(files
+        .iter()
+        .all(|file| file.metadata.as_ref() == Some(&metadata)));
+
+    let mut written = Vec::new();
+    typed.bucket().download_to_writer(id, &mut written).unwrap();
+    assert_eq!(written, vec![4, 5]);
+}
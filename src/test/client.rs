@@ -8,12 +8,23 @@ use crate::{
     bson::{doc, Bson},
     error::{CommandError, Error, ErrorKind},
     hello::LEGACY_HELLO_COMMAND_NAME,
-    options::{AuthMechanism, ClientOptions, Credential, ListDatabasesOptions, ServerAddress},
+    options::{
+        AuthMechanism,
+        ClientBulkWriteOptions,
+        ClientOptions,
+        Credential,
+        DeleteModel,
+        InsertOneModel,
+        ListDatabasesOptions,
+        ServerAddress,
+        UpdateModel,
+        WriteModel,
+    },
     runtime,
     selection_criteria::{ReadPreference, ReadPreferenceOptions, SelectionCriteria},
     test::{
         log_uncaptured,
-        util::TestClient,
+        util::{EventClient, TestClient},
         CmapEvent,
         Event,
         EventHandler,
@@ -25,6 +36,7 @@ use crate::{
         LOCK,
     },
     Client,
+    Namespace,
     ServerType,
 };
 
@@ -845,3 +857,128 @@ async fn retry_commit_txn_check_out() {
         .await
         .expect("should see checked out event");
 }
+
+fn client_bulk_write_insert(
+    ns: &Namespace,
+    document: Document,
+) -> (Namespace, WriteModel<Document>) {
+    (
+        ns.clone(),
+        WriteModel::InsertOne(InsertOneModel::builder().document(document).build()),
+    )
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn client_bulk_write() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = EventClient::new().await;
+    if client.server_version_lt(8, 0) {
+        log_uncaptured("skipping client_bulk_write due to server version < 8.0");
+        return;
+    }
+    let coll_a = client
+        .init_db_and_coll(function_name!(), &format!("{}_a", function_name!()))
+        .await;
+    let coll_b = client
+        .init_db_and_coll(function_name!(), &format!("{}_b", function_name!()))
+        .await;
+    let (ns_a, ns_b) = (coll_a.namespace(), coll_b.namespace());
+
+    let models = vec![
+        client_bulk_write_insert(&ns_a, doc! { "_id": 1, "x": 1 }),
+        client_bulk_write_insert(&ns_b, doc! { "x": 2 }),
+        (
+            ns_a.clone(),
+            WriteModel::UpdateOne(
+                UpdateModel::builder()
+                    .filter(doc! { "_id": 2 })
+                    .update(doc! { "$set": { "x": 2 } })
+                    .upsert(true)
+                    .build(),
+            ),
+        ),
+        (
+            ns_b.clone(),
+            WriteModel::DeleteMany(DeleteModel::builder().filter(doc! {}).build()),
+        ),
+    ];
+    let options = ClientBulkWriteOptions::builder()
+        .verbose_results(true)
+        .build();
+    let result = client.bulk_write(models, options).await.unwrap();
+
+    assert_eq!(result.inserted_count, 2);
+    assert_eq!(result.upserted_count, 1);
+    assert_eq!(result.matched_count, 0);
+    assert_eq!(result.deleted_count, 1);
+    assert_eq!(
+        result.insert_results.get(&0).map(|r| &r.inserted_id),
+        Some(&Bson::Int32(1))
+    );
+    assert!(matches!(
+        result.insert_results.get(&1).map(|r| &r.inserted_id),
+        Some(Bson::ObjectId(_))
+    ));
+    assert_eq!(
+        result.update_results.get(&2).unwrap().upserted_id,
+        Some(Bson::Int32(2))
+    );
+    assert_eq!(result.delete_results.get(&3).unwrap().deleted_count, 1);
+
+    assert_eq!(coll_a.count_documents(None, None).await.unwrap(), 2);
+    assert_eq!(coll_b.count_documents(None, None).await.unwrap(), 0);
+
+    // All of the writes are sent in a single command.
+    let events = client.get_command_started_events(&["bulkWrite"]);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].command.get_array("ops").unwrap().len(), 4);
+    assert_eq!(events[0].command.get_array("nsInfo").unwrap().len(), 2);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn client_bulk_write_errors() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = EventClient::new().await;
+    if client.server_version_lt(8, 0) {
+        log_uncaptured("skipping client_bulk_write_errors due to server version < 8.0");
+        return;
+    }
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+    let ns = coll.namespace();
+
+    let models = vec![
+        client_bulk_write_insert(&ns, doc! { "_id": 1 }),
+        client_bulk_write_insert(&ns, doc! { "_id": 1 }),
+        client_bulk_write_insert(&ns, doc! { "_id": 2 }),
+    ];
+
+    let error = client.bulk_write(models.clone(), None).await.unwrap_err();
+    match *error.kind {
+        ErrorKind::ClientBulkWrite(ref failure) => {
+            assert_eq!(failure.write_errors.len(), 1);
+            assert_eq!(failure.write_errors.get(&1).unwrap().code, 11000);
+            assert_eq!(failure.partial_result.as_ref().unwrap().inserted_count, 1);
+        }
+        ref e => panic!("expected client bulk write error, got {:?}", e),
+    }
+
+    coll.delete_many(doc! {}, None).await.unwrap();
+    let options = ClientBulkWriteOptions::builder().ordered(false).build();
+    let error = client.bulk_write(models, options).await.unwrap_err();
+    match *error.kind {
+        ErrorKind::ClientBulkWrite(ref failure) => {
+            assert_eq!(failure.write_errors.len(), 1);
+            assert!(failure.write_errors.contains_key(&1));
+            assert_eq!(failure.partial_result.as_ref().unwrap().inserted_count, 2);
+        }
+        ref e => panic!("expected client bulk write error, got {:?}", e),
+    }
+}
@@ -12,31 +12,51 @@ use crate::{
     options::{
         Acknowledgment,
         AggregateOptions,
+        BulkWriteOptions,
         CollectionOptions,
+        CreateCollectionOptions,
+        DeleteModel,
         DeleteOptions,
         DropCollectionOptions,
+        ExplainVerbosity,
         FindOneAndDeleteOptions,
         FindOneOptions,
         FindOptions,
         Hint,
         IndexOptions,
         InsertManyOptions,
+        InsertOneModel,
+        ModifyCollectionOptions,
+        ModifyIndex,
         ReadConcern,
         ReadPreference,
+        ReplaceOneModel,
         SelectionCriteria,
+        TailOptions,
+        UpdateModel,
         UpdateOptions,
         WriteConcern,
+        WriteModel,
     },
     results::DeleteResult,
     runtime,
     test::{
         log_uncaptured,
         util::{drop_collection, EventClient, TestClient},
+        FailCommandOptions,
+        FailPoint,
+        FailPointMode,
         CLIENT_OPTIONS,
         LOCK,
+        SERVERLESS,
     },
     Collection,
+    ContinuationToken,
+    Cursor,
     IndexModel,
+    Page,
+    Paginator,
+    SessionCursor,
 };
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
@@ -268,6 +288,438 @@ async fn aggregate_out() {
         .any(|name| name.as_str() == out_coll.name()));
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn paginate() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+    coll.insert_many(
+        (0i32..10).map(|n| doc! { "_id": n, "group": n % 3, "x": n % 2 }),
+        None,
+    )
+    .await
+    .unwrap();
+
+    async fn collect_ids(paginator: &mut Paginator<Document>) -> Vec<Vec<i32>> {
+        let mut pages = Vec::new();
+        while let Some(page) = paginator.next_page().await.unwrap() {
+            assert_eq!(page.continuation.is_none(), pages.len() == 3);
+            pages.push(
+                page.items
+                    .iter()
+                    .map(|d| d.get_i32("_id").unwrap())
+                    .collect(),
+            );
+        }
+        pages
+    }
+
+    let mut paginator = coll
+        .paginate(
+            doc! { "_id": { "$lt": 10 } },
+            doc! { "group": 1, "x": -1 },
+            3,
+        )
+        .unwrap();
+    assert_eq!(
+        collect_ids(&mut paginator).await,
+        vec![vec![3, 9, 0], vec![6, 1, 7], vec![4, 5, 2], vec![8]]
+    );
+    assert!(paginator.next_page().await.unwrap().is_none());
+
+    let mut paginator = coll.paginate(None, doc! { "_id": -1 }, 3).unwrap();
+    assert_eq!(
+        collect_ids(&mut paginator).await,
+        vec![vec![9, 8, 7], vec![6, 5, 4], vec![3, 2, 1], vec![0]]
+    );
+
+    // Resume from a token passed around as a string.
+    let mut paginator = coll.paginate(None, doc! { "group": -1 }, 4).unwrap();
+    let first = paginator.next_page().await.unwrap().unwrap();
+    let token: ContinuationToken = first
+        .continuation
+        .as_ref()
+        .unwrap()
+        .to_string()
+        .parse()
+        .unwrap();
+    let mut resumed = coll
+        .paginate(None, doc! { "group": -1 }, 4)
+        .unwrap()
+        .resume(&token)
+        .unwrap();
+    let ids = |page: Page<Document>| -> Vec<i32> {
+        page.items
+            .iter()
+            .map(|d| d.get_i32("_id").unwrap())
+            .collect()
+    };
+    assert_eq!(ids(first), vec![2, 5, 8, 1]);
+    assert_eq!(
+        ids(resumed.next_page().await.unwrap().unwrap()),
+        vec![4, 7, 0, 3]
+    );
+    let last = resumed.next_page().await.unwrap().unwrap();
+    assert!(last.continuation.is_none());
+    assert_eq!(ids(last), vec![6, 9]);
+    assert!(resumed.next_page().await.unwrap().is_none());
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test(flavor = "multi_thread"))] // multi_thread required for FailPoint
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn tail_resumes_on_error() {
+    if *SERVERLESS {
+        log_uncaptured(
+            "skipping tail_resumes_on_error; serverless does not support capped collections",
+        );
+        return;
+    }
+
+    let _guard: RwLockWriteGuard<()> = LOCK.run_exclusively().await;
+
+    // CursorNotFound, and state change errors regardless of server version.
+    for code in [43, 11602, 10107] {
+        let client = EventClient::new().await;
+        if !client.supports_fail_command() {
+            log_uncaptured("skipping tail_resumes_on_error due to fail points not being supported");
+            return;
+        }
+        tail_resume_after_error(&client, &format!("{}_{}", function_name!(), code), code).await;
+    }
+}
+
+async fn tail_resume_after_error(client: &EventClient, name: &str, code: i64) {
+    let coll = client
+        .create_fresh_collection(
+            name,
+            name,
+            CreateCollectionOptions::builder()
+                .capped(true)
+                .size(100_000)
+                .build(),
+        )
+        .await;
+    coll.insert_many((0i32..3).map(|n| doc! { "_id": n }), None)
+        .await
+        .unwrap();
+
+    let options = TailOptions::builder()
+        .batch_size(1)
+        .max_await_time(Duration::from_millis(500))
+        .build();
+    let mut cursor = coll.tail(None, options).await.unwrap();
+    let first = cursor.try_next().await.unwrap().unwrap();
+    assert_eq!(first, doc! { "_id": 0 });
+    assert_eq!(cursor.resume_value(), Some(&Bson::Int32(0)));
+
+    let _fp_guard = FailPoint::fail_command(
+        &["getMore"],
+        FailPointMode::Times(1),
+        FailCommandOptions::builder().error_code(code).build(),
+    )
+    .enable(client, None)
+    .await
+    .unwrap();
+
+    let mut ids = Vec::new();
+    for _ in 0..2 {
+        let doc = cursor.try_next().await.unwrap().unwrap();
+        ids.push(doc.get_i32("_id").unwrap());
+    }
+    coll.insert_one(doc! { "_id": 3 }, None).await.unwrap();
+    let doc = cursor.try_next().await.unwrap().unwrap();
+    ids.push(doc.get_i32("_id").unwrap());
+    assert_eq!(ids, vec![1, 2, 3], "error code {}", code);
+    assert!(cursor.is_alive());
+
+    // Assert that the find was re-issued after the last document returned.
+    let finds = client.get_command_started_events(&["find"]);
+    assert_eq!(finds.len(), 2, "error code {}", code);
+    assert_eq!(
+        finds[1].command.get_document("filter").unwrap(),
+        &doc! { "_id": { "$gt": 0 } }
+    );
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn tail_without_await_data() {
+    if *SERVERLESS {
+        log_uncaptured(
+            "skipping tail_without_await_data; serverless does not support capped collections",
+        );
+        return;
+    }
+
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = EventClient::new().await;
+    let coll = client
+        .create_fresh_collection(
+            function_name!(),
+            function_name!(),
+            CreateCollectionOptions::builder()
+                .capped(true)
+                .size(100_000)
+                .build(),
+        )
+        .await;
+    coll.insert_many((0i32..2).map(|n| doc! { "_id": n }), None)
+        .await
+        .unwrap();
+
+    let options = TailOptions::builder()
+        .batch_size(1)
+        .await_data(false)
+        .max_await_time(Duration::from_millis(500))
+        .build();
+    let mut cursor = coll.tail(None, options).await.unwrap();
+    for n in 0..2 {
+        let doc = cursor.try_next().await.unwrap().unwrap();
+        assert_eq!(doc, doc! { "_id": n });
+    }
+
+    let finds = client.get_command_started_events(&["find"]);
+    assert!(!finds[0].command.contains_key("awaitData"));
+    let get_mores = client.get_command_started_events(&["getMore"]);
+    assert!(!get_mores.is_empty());
+    assert!(get_mores
+        .iter()
+        .all(|event| !event.command.contains_key("maxTimeMS")));
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn tail_empty_collection() {
+    if *SERVERLESS {
+        log_uncaptured(
+            "skipping tail_empty_collection; serverless does not support capped collections",
+        );
+        return;
+    }
+
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = EventClient::new().await;
+    let coll = client
+        .create_fresh_collection(
+            function_name!(),
+            function_name!(),
+            CreateCollectionOptions::builder()
+                .capped(true)
+                .size(100_000)
+                .build(),
+        )
+        .await;
+
+    let options = TailOptions::builder()
+        .max_await_time(Duration::from_millis(100))
+        .build();
+    let mut cursor = coll.tail(None, options).await.unwrap();
+    // The server closes the cursor of a query on an empty capped collection.
+    assert!(!cursor.is_alive());
+
+    let insert = async {
+        runtime::delay_for(Duration::from_millis(500)).await;
+        coll.insert_one(doc! { "_id": 0 }, None).await.unwrap();
+    };
+    let (doc, _) = futures::join!(cursor.try_next(), insert);
+    assert_eq!(doc.unwrap(), Some(doc! { "_id": 0 }));
+    assert!(cursor.is_alive());
+
+    // Assert that the find was re-issued until the document was inserted.
+    let finds = client.get_command_started_events(&["find"]);
+    assert!(finds.len() > 1);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn rename() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+    let target = client
+        .init_db_and_coll(function_name!(), &format!("{}_target", function_name!()))
+        .await;
+    coll.insert_one(doc! { "_id": 1 }, None).await.unwrap();
+    target.insert_one(doc! { "_id": 2 }, None).await.unwrap();
+
+    let error = coll
+        .rename(target.namespace(), false, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(*error.kind, ErrorKind::Command(ref e) if e.code == 48));
+
+    coll.rename(target.namespace(), true, None).await.unwrap();
+    let docs: Vec<Document> = target
+        .find(None, None)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(docs, vec![doc! { "_id": 1 }]);
+    assert_eq!(coll.count_documents(None, None).await.unwrap(), 0);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn modify() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+    let index = IndexModel::builder()
+        .keys(doc! { "createdAt": 1 })
+        .options(
+            IndexOptions::builder()
+                .expire_after(Duration::from_secs(60))
+                .build(),
+        )
+        .build();
+    coll.create_index(index, None).await.unwrap();
+
+    let ttl = ModifyIndex::builder()
+        .key_pattern(doc! { "createdAt": 1 })
+        .expire_after_seconds(Duration::from_secs(3600))
+        .build();
+    coll.modify(
+        ModifyCollectionOptions::builder()
+            .index(ttl)
+            .validator(doc! { "x": { "$type": "int" } })
+            .build(),
+    )
+    .await
+    .unwrap();
+
+    let index = coll
+        .list_indexes(None)
+        .await
+        .unwrap()
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|index| index.keys == doc! { "createdAt": 1 })
+        .unwrap();
+    assert_eq!(
+        index.options.unwrap().expire_after,
+        Some(Duration::from_secs(3600))
+    );
+
+    coll.insert_one(doc! { "x": 1 }, None).await.unwrap();
+    let error = coll.insert_one(doc! { "x": "a" }, None).await.unwrap_err();
+    assert!(matches!(
+        *error.kind,
+        ErrorKind::Write(WriteFailure::WriteError(ref e)) if e.code == 121
+    ));
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn convert_to_capped() {
+    if *SERVERLESS {
+        log_uncaptured(
+            "skipping convert_to_capped; serverless does not support capped collections",
+        );
+        return;
+    }
+
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+    coll.insert_many((0i32..3).map(|n| doc! { "_id": n }), None)
+        .await
+        .unwrap();
+
+    coll.convert_to_capped(100_000, None).await.unwrap();
+
+    let spec = client
+        .database(function_name!())
+        .list_collections(doc! { "name": function_name!() }, None)
+        .await
+        .unwrap()
+        .try_next()
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(spec.options.capped, Some(true));
+    assert_eq!(coll.count_documents(None, None).await.unwrap(), 3);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn aggregate_as() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Total {
+        #[serde(rename = "_id")]
+        parity: i32,
+        total: i32,
+    }
+
+    let client = TestClient::new().await;
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+    coll.insert_many((0i32..5).map(|n| doc! { "x": n }), None)
+        .await
+        .unwrap();
+
+    let pipeline = vec![
+        doc! { "$group": { "_id": { "$mod": ["$x", 2] }, "total": { "$sum": "$x" } } },
+        doc! { "$sort": { "_id": 1 } },
+    ];
+    let cursor: Cursor<Total> = coll.aggregate_as(pipeline.clone(), None).await.unwrap();
+    let totals: Vec<Total> = cursor.try_collect().await.unwrap();
+    assert_eq!(
+        totals,
+        vec![
+            Total {
+                parity: 0,
+                total: 6
+            },
+            Total {
+                parity: 1,
+                total: 4
+            },
+        ]
+    );
+
+    if client.is_standalone() {
+        log_uncaptured("skipping aggregate_as with session due to standalone topology");
+        return;
+    }
+    let mut session = client.start_session(None).await.unwrap();
+    let mut cursor: SessionCursor<Total> = coll
+        .aggregate_as_with_session(pipeline, None, &mut session)
+        .await
+        .unwrap();
+    let totals: Vec<Total> = cursor.stream(&mut session).try_collect().await.unwrap();
+    assert_eq!(totals.len(), 2);
+}
+
 fn kill_cursors_sent(client: &EventClient) -> bool {
     !client
         .get_command_started_events(&["killCursors"])
@@ -555,6 +1007,236 @@ async fn empty_insert() {
     };
 }
 
+fn insert_model(document: Document) -> WriteModel<Document> {
+    WriteModel::InsertOne(InsertOneModel::builder().document(document).build())
+}
+
+fn update_model(filter: Document, update: Document, upsert: bool) -> UpdateModel {
+    UpdateModel::builder()
+        .filter(filter)
+        .update(update)
+        .upsert(upsert)
+        .build()
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn bulk_write_mixed_models() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = EventClient::new().await;
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+
+    let models = vec![
+        insert_model(doc! { "_id": 1, "x": 1 }),
+        insert_model(doc! { "x": 2 }),
+        WriteModel::UpdateOne(update_model(
+            doc! { "_id": 3 },
+            doc! { "$set": { "x": 3 } },
+            true,
+        )),
+        WriteModel::UpdateMany(update_model(
+            doc! { "x": { "$gte": 2 } },
+            doc! { "$inc": { "x": 10 } },
+            false,
+        )),
+        WriteModel::ReplaceOne(
+            ReplaceOneModel::builder()
+                .filter(doc! { "_id": 1 })
+                .replacement(doc! { "x": 100 })
+                .build(),
+        ),
+        WriteModel::DeleteOne(DeleteModel::builder().filter(doc! { "x": 12 }).build()),
+        insert_model(doc! { "_id": 4, "x": 4 }),
+        WriteModel::DeleteMany(
+            DeleteModel::builder()
+                .filter(doc! { "x": { "$lt": 10 } })
+                .build(),
+        ),
+    ];
+
+    let result = coll.bulk_write(models, None).await.unwrap();
+    assert_eq!(result.inserted_count, 3);
+    assert_eq!(result.matched_count, 3);
+    assert_eq!(result.modified_count, 3);
+    assert_eq!(result.upserted_count, 1);
+    assert_eq!(result.deleted_count, 2);
+
+    let mut inserted_indexes: Vec<_> = result.inserted_ids.keys().copied().collect();
+    inserted_indexes.sort_unstable();
+    assert_eq!(inserted_indexes, vec![0, 1, 6]);
+    assert_eq!(result.inserted_ids.get(&0), Some(&Bson::Int32(1)));
+    assert_eq!(result.upserted_ids.len(), 1);
+    assert_eq!(result.upserted_ids.get(&2), Some(&Bson::Int32(3)));
+
+    let remaining: Vec<Document> = coll
+        .find(None, FindOptions::builder().sort(doc! { "_id": 1 }).build())
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(
+        remaining,
+        vec![doc! { "_id": 1, "x": 100 }, doc! { "_id": 3, "x": 13 }]
+    );
+
+    // Consecutive models using the same command are sent together.
+    let command_names: Vec<_> = client
+        .get_command_started_events(&["insert", "update", "delete"])
+        .into_iter()
+        .map(|event| event.command_name)
+        .collect();
+    assert_eq!(
+        command_names,
+        vec!["insert", "update", "delete", "insert", "delete"]
+    );
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn bulk_write_unordered_with_errors() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+
+    let models = vec![
+        insert_model(doc! { "_id": 1 }),
+        insert_model(doc! { "_id": 1 }),
+        WriteModel::UpdateOne(update_model(
+            doc! { "_id": 1 },
+            doc! { "$set": { "_id": 2 } },
+            false,
+        )),
+        WriteModel::UpdateOne(update_model(
+            doc! { "_id": 1 },
+            doc! { "$set": { "x": 1 } },
+            false,
+        )),
+        insert_model(doc! { "_id": 2 }),
+    ];
+    let options = BulkWriteOptions::builder().ordered(false).build();
+
+    match *coll
+        .bulk_write(models.clone(), options)
+        .await
+        .expect_err("should get error")
+        .kind
+    {
+        ErrorKind::BulkWrite(ref failure) => {
+            let mut indexes: Vec<_> = failure
+                .write_errors
+                .iter()
+                .flatten()
+                .map(|error| error.index)
+                .collect();
+            indexes.sort_unstable();
+            assert_eq!(indexes, vec![1, 2]);
+
+            let partial_result = failure.partial_result.as_ref().unwrap();
+            assert_eq!(partial_result.inserted_count, 2);
+            assert_eq!(partial_result.matched_count, 1);
+            assert_eq!(partial_result.modified_count, 1);
+            assert_eq!(partial_result.inserted_ids.len(), 2);
+            assert_eq!(partial_result.inserted_ids.get(&0), Some(&Bson::Int32(1)));
+            assert_eq!(partial_result.inserted_ids.get(&4), Some(&Bson::Int32(2)));
+        }
+        e => panic!("expected bulk write error, got {:?} instead", e),
+    }
+    assert_eq!(coll.count_documents(None, None).await.unwrap(), 2);
+
+    coll.drop(None).await.unwrap();
+    match *coll
+        .bulk_write(models, None)
+        .await
+        .expect_err("should get error")
+        .kind
+    {
+        ErrorKind::BulkWrite(ref failure) => {
+            let write_errors = failure.write_errors.clone().unwrap();
+            assert_eq!(write_errors.len(), 1);
+            assert_eq!(write_errors[0].index, 1);
+
+            let partial_result = failure.partial_result.as_ref().unwrap();
+            assert_eq!(partial_result.inserted_count, 1);
+            assert_eq!(partial_result.inserted_ids.len(), 1);
+            assert_eq!(partial_result.inserted_ids.get(&0), Some(&Bson::Int32(1)));
+        }
+        e => panic!("expected bulk write error, got {:?} instead", e),
+    }
+    assert_eq!(coll.count_documents(None, None).await.unwrap(), 1);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn large_bulk_write() {
+    if std::env::consts::OS != "linux" {
+        log_uncaptured("skipping large_bulk_write due to unsupported OS");
+        return;
+    }
+
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = EventClient::new().await;
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+
+    let mut models: Vec<_> = (0..35000)
+        .map(|i| {
+            let mut doc = LARGE_DOC.clone();
+            doc.insert("_id", i);
+            insert_model(doc)
+        })
+        .collect();
+    models.extend((0..35000).map(|i| {
+        WriteModel::UpdateOne(update_model(
+            doc! { "_id": i },
+            doc! { "$set": { "updated": true } },
+            false,
+        ))
+    }));
+
+    let result = coll.bulk_write(models, None).await.unwrap();
+    assert_eq!(result.inserted_count, 35000);
+    assert_eq!(result.inserted_ids.len(), 35000);
+    assert_eq!(result.matched_count, 35000);
+    assert_eq!(result.modified_count, 35000);
+
+    // The inserts are split by size, so they require more than one command.
+    assert!(client.get_command_started_events(&["insert"]).len() > 1);
+    assert!(!client.get_command_started_events(&["update"]).is_empty());
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn empty_bulk_write() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    let coll = client
+        .database(function_name!())
+        .collection::<Document>(function_name!());
+    match *coll
+        .bulk_write(Vec::new(), None)
+        .await
+        .expect_err("should get error")
+        .kind
+    {
+        ErrorKind::InvalidArgument { .. } => {}
+        e => panic!("expected argument error, got {:?}", e),
+    };
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 async fn find_allow_disk_use() {
@@ -1101,6 +1783,58 @@ async fn cursor_batch_size() {
     assert_eq!(docs.len(), 10);
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn explain() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = EventClient::new().await;
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+    coll.insert_many((0..5).map(|i| doc! { "x": i, "y": i }), None)
+        .await
+        .unwrap();
+    let index = IndexModel::builder().keys(doc! { "x": 1 }).build();
+    coll.create_index(index, None).await.unwrap();
+
+    let result = coll
+        .explain_find(doc! { "x": 2 }, None, ExplainVerbosity::ExecutionStats)
+        .await
+        .unwrap();
+    assert_eq!(result.index_names(), vec!["x_1"]);
+    assert!(!result.is_collection_scan());
+    assert_eq!(result.execution_stats.unwrap().n_returned, 1);
+
+    let result = coll
+        .explain_count_documents(doc! { "y": 2 }, None, ExplainVerbosity::QueryPlanner)
+        .await
+        .unwrap();
+    assert!(result.is_collection_scan());
+
+    let result = coll
+        .explain_update_many(
+            doc! { "x": { "$gt": 1 } },
+            doc! { "$set": { "z": 1 } },
+            None,
+            ExplainVerbosity::QueryPlanner,
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.index_names(), vec!["x_1"]);
+    assert_eq!(
+        coll.count_documents(doc! { "z": 1 }, None).await.unwrap(),
+        0
+    );
+
+    let events = client.get_command_started_events(&["explain"]);
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[0].command.get_str("verbosity"), Ok("executionStats"));
+    let explained = events[0].command.get_document("explain").unwrap();
+    assert_eq!(explained.get_str("find"), Ok(function_name!()));
+}
+
 /// Test that the driver gracefully handles cases where the server returns invalid UTF-8 in error
 /// messages. See SERVER-24007 and related tickets for details.
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
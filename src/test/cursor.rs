@@ -1,11 +1,11 @@
-use std::time::Duration;
+use std::{collections::VecDeque, time::Duration};
 
 use futures::{future::Either, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLockReadGuard;
 
 use crate::{
-    bson::doc,
+    bson::{doc, Bson, RawDocumentBuf},
     options::{CreateCollectionOptions, CursorType, FindOptions},
     runtime,
     test::{log_uncaptured, util::EventClient, TestClient, LOCK, SERVERLESS},
@@ -165,6 +165,153 @@ async fn batch_exhaustion() {
     assert_eq!(0, id);
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn close() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = EventClient::new().await;
+    let coll = client
+        .create_fresh_collection(function_name!(), function_name!(), None)
+        .await;
+    coll.insert_many((0..5).map(|i| doc! { "_id": i }), None)
+        .await
+        .unwrap();
+
+    let mut cursor = coll
+        .find(None, FindOptions::builder().batch_size(2).build())
+        .await
+        .unwrap();
+    assert_ne!(cursor.id(), 0);
+    let find = client.get_command_started_events(&["find"]).pop().unwrap();
+    assert_eq!(cursor.address(), &find.connection.address);
+    assert_eq!(cursor.try_next().await.unwrap(), Some(doc! { "_id": 0 }));
+
+    cursor.close().await.unwrap();
+    assert_eq!(cursor.try_next().await.unwrap(), None);
+    // Closing the cursor again does nothing.
+    cursor.close().await.unwrap();
+
+    let kill_cursors = client.get_command_started_events(&["killCursors"]);
+    assert_eq!(kill_cursors.len(), 1);
+    assert_eq!(
+        kill_cursors[0].command.get_array("cursors").unwrap(),
+        &vec![Bson::Int64(cursor.id())]
+    );
+    assert_eq!(kill_cursors[0].connection.address, find.connection.address);
+
+    let mut session = client.start_session(None).await.unwrap();
+    let mut cursor = coll
+        .find_with_session(
+            None,
+            FindOptions::builder().batch_size(2).build(),
+            &mut session,
+        )
+        .await
+        .unwrap();
+    cursor.close(&mut session).await.unwrap();
+    assert_eq!(cursor.next(&mut session).await.transpose().unwrap(), None);
+
+    let kill_cursors = client.get_command_started_events(&["killCursors"]);
+    assert_eq!(kill_cursors.len(), 2);
+    assert_eq!(
+        kill_cursors[1].command.get_document("lsid").unwrap(),
+        session.id()
+    );
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn next_batch() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = EventClient::new().await;
+    let coll = client
+        .create_fresh_collection(function_name!(), function_name!(), None)
+        .await;
+    coll.insert_many((0..10).map(|i| doc! { "_id": i }), None)
+        .await
+        .unwrap();
+    let options = FindOptions::builder()
+        .batch_size(3)
+        .sort(doc! { "_id": 1 })
+        .build();
+
+    let mut cursor = coll.find(None, options.clone()).await.unwrap();
+    let mut sizes = Vec::new();
+    while let Some(batch) = cursor.next_batch().await.unwrap() {
+        sizes.push(batch.len());
+    }
+    assert_eq!(sizes, vec![3, 3, 3, 1]);
+    assert_eq!(client.get_command_started_events(&["getMore"]).len(), 3);
+
+    // With prefetching, the getMore is sent before the next batch is requested.
+    let mut cursor = coll.find(None, options).await.unwrap();
+    cursor.set_prefetch(true);
+    let batch = cursor.next_batch().await.unwrap().unwrap();
+    assert_eq!(batch.front().unwrap().get_i32("_id").unwrap(), 0);
+    runtime::timeout(Duration::from_secs(10), async {
+        while client.get_command_started_events(&["getMore"]).len() < 4 {
+            runtime::delay_for(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+
+    // Iterating individual documents picks up the prefetched batch.
+    assert_eq!(cursor.try_next().await.unwrap(), Some(doc! { "_id": 3 }));
+    let batch = cursor.next_batch().await.unwrap().unwrap();
+    assert_eq!(batch.len(), 2);
+    assert!(cursor.advance().await.unwrap());
+    assert_eq!(cursor.deserialize_current().unwrap(), doc! { "_id": 6 });
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn next_batch_after_advance() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = EventClient::new().await;
+    let coll = client
+        .create_fresh_collection(function_name!(), function_name!(), None)
+        .await;
+    coll.insert_many((0..5).map(|i| doc! { "_id": i }), None)
+        .await
+        .unwrap();
+    let options = FindOptions::builder()
+        .batch_size(3)
+        .sort(doc! { "_id": 1 })
+        .build();
+    let ids = |batch: VecDeque<RawDocumentBuf>| -> Vec<i32> {
+        batch
+            .iter()
+            .map(|doc| doc.get_i32("_id").unwrap())
+            .collect()
+    };
+
+    // The current document is not returned again in the rest of the batch.
+    let mut cursor = coll.find(None, options.clone()).await.unwrap();
+    assert!(cursor.advance().await.unwrap());
+    assert_eq!(cursor.deserialize_current().unwrap(), doc! { "_id": 0 });
+    let batch = cursor.next_batch().await.unwrap().unwrap();
+    assert_eq!(ids(batch), vec![1, 2]);
+    let batch = cursor.next_batch().await.unwrap().unwrap();
+    assert_eq!(ids(batch), vec![3, 4]);
+
+    // If the current document is the last of its batch, the next batch is fetched.
+    let mut cursor = coll.find(None, options).await.unwrap();
+    for id in 0..3 {
+        assert!(cursor.advance().await.unwrap());
+        assert_eq!(cursor.deserialize_current().unwrap(), doc! { "_id": id });
+    }
+    let batch = cursor.next_batch().await.unwrap().unwrap();
+    assert_eq!(ids(batch), vec![3, 4]);
+    assert!(cursor.next_batch().await.unwrap().is_none());
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 async fn borrowed_deserialization() {
@@ -238,6 +238,54 @@ async fn collection_management() {
     assert!(coll3.id_index.is_none());
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn views() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    let db = client.database(function_name!());
+    db.drop(None).await.unwrap();
+
+    let coll = db.collection::<Document>("source");
+    coll.insert_many(
+        vec![doc! { "x": 1 }, doc! { "x": 2 }, doc! { "x": 3 }],
+        None,
+    )
+    .await
+    .unwrap();
+
+    let pipeline = vec![doc! { "$match": { "x": { "$gt": 1 } } }];
+    db.create_view("view", "source", pipeline.clone(), None)
+        .await
+        .unwrap();
+
+    let colls = get_coll_info(&db, Some(doc! { "name": "view" })).await;
+    assert_eq!(colls.len(), 1);
+    assert_eq!(colls[0].collection_type, CollectionType::View);
+    let view = colls[0]
+        .view_specification()
+        .expect("view should have a view specification");
+    assert_eq!(view.view_on, "source");
+    assert_eq!(view.pipeline, pipeline);
+
+    let view_coll = db.collection::<Document>("view");
+    assert_eq!(view_coll.count_documents(None, None).await.unwrap(), 2);
+
+    let pipeline = vec![doc! { "$match": { "x": { "$gt": 2 } } }];
+    db.modify_view("view", "source", pipeline.clone(), None)
+        .await
+        .unwrap();
+
+    let colls = get_coll_info(&db, Some(doc! { "name": "view" })).await;
+    assert_eq!(colls[0].view_specification().unwrap().pipeline, pipeline);
+    assert_eq!(view_coll.count_documents(None, None).await.unwrap(), 1);
+
+    let colls = get_coll_info(&db, Some(doc! { "name": "source" })).await;
+    assert!(colls[0].view_specification().is_none());
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 async fn db_aggregate() {
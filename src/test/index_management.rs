@@ -4,7 +4,7 @@ use tokio::sync::RwLockReadGuard;
 use crate::{
     bson::doc,
     error::ErrorKind,
-    options::{CommitQuorum, CreateIndexOptions, IndexOptions},
+    options::{CommitQuorum, CreateIndexOptions, IndexOptions, SyncIndexOptions},
     test::{
         log_uncaptured,
         util::{EventClient, TestClient},
@@ -308,3 +308,73 @@ async fn commit_quorum_error() {
         assert!(result.is_ok());
     }
 }
+
+// Test that syncing indexes creates, drops and reports the expected indexes.
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn index_management_syncs() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+    let client = EventClient::new().await;
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+
+    coll.create_indexes(
+        vec![
+            IndexModel::builder().keys(doc! { "a": 1 }).build(),
+            IndexModel::builder().keys(doc! { "stale": 1 }).build(),
+        ],
+        None,
+    )
+    .await
+    .expect("Create Indexes op failed");
+
+    let models = vec![
+        IndexModel::builder()
+            .keys(doc! { "a": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build(),
+        IndexModel::builder().keys(doc! { "b": 1 }).build(),
+    ];
+
+    // A dry run plans the changes without executing any commands.
+    let options = SyncIndexOptions::builder()
+        .drop_unlisted(true)
+        .dry_run(true)
+        .build();
+    let result = coll
+        .sync_indexes(models.clone(), options)
+        .await
+        .expect("Sync Indexes op failed");
+    assert!(result.dry_run);
+    let created: Vec<_> = result.created.iter().map(|m| m.get_name()).collect();
+    assert_eq!(created, vec![Some("b_1".to_string())]);
+    assert_eq!(result.dropped, vec!["stale_1".to_string()]);
+    assert_eq!(result.conflicts.len(), 1);
+    assert_eq!(result.conflicts[0].differences, vec!["unique".to_string()]);
+    assert_eq!(
+        client
+            .get_command_started_events(&["createIndexes", "dropIndexes"])
+            .len(),
+        1
+    );
+
+    let options = SyncIndexOptions::builder().fail_on_conflict(true).build();
+    let err = coll
+        .sync_indexes(models.clone(), options)
+        .await
+        .expect_err("Sync Indexes op should fail on conflict");
+    assert!(matches!(*err.kind, ErrorKind::InvalidArgument { .. }));
+
+    let options = SyncIndexOptions::builder().drop_unlisted(true).build();
+    coll.sync_indexes(models, options)
+        .await
+        .expect("Sync Indexes op failed");
+    let mut names = coll
+        .list_index_names()
+        .await
+        .expect("List index names op failed");
+    names.sort();
+    assert_eq!(names, vec!["_id_", "a_1", "b_1"]);
+}
@@ -1,7 +1,34 @@
-use crate::test::{
-    run_spec_test_with_path,
-    spec::unified_runner::{run_unified_format_test_filtered, TestCase},
-    LOCK,
+use std::{
+    io::SeekFrom,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::{
+    io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt},
+    StreamExt,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bson::{doc, spec::BinarySubtype, Binary, Bson, DateTime, Decimal128, Document},
+    error::{Error, ErrorKind, GridFsErrorKind},
+    gridfs::options::{
+        GridFsBucketOptions,
+        GridFsChecksumAlgorithm,
+        GridFsCollectGarbageOptions,
+        GridFsUploadOptions,
+    },
+    runtime,
+    test::{
+        log_uncaptured,
+        run_spec_test_with_path,
+        spec::unified_runner::{run_unified_format_test_filtered, TestCase},
+        util::{FailCommandOptions, FailPoint, FailPointMode},
+        TestClient,
+        LOCK,
+    },
 };
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
@@ -20,3 +47,834 @@ fn test_predicate(test: &TestCase) -> bool {
     // The Rust driver doesn't support the disableMD5 and contentType options for upload.
     !lower.contains("sans md5") && !lower.contains("contenttype")
 }
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn download_stream_across_batches() {
+    let _guard = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    let db = client.database(function_name!());
+    let options = GridFsBucketOptions::builder().chunk_size_bytes(4).build();
+    let bucket = db.gridfs_bucket(options);
+    bucket.drop().await.unwrap();
+
+    // 1000 bytes split into 4-byte chunks requires multiple batches with the default batch size.
+    let data: Vec<u8> = (0..1000).map(|i| (i % 256) as u8).collect();
+    let mut upload_stream = bucket
+        .open_upload_stream("test".to_string(), None)
+        .await
+        .unwrap();
+    for piece in data.chunks(7) {
+        upload_stream.write_all(piece).await.unwrap();
+    }
+    let id = upload_stream.files_id().clone();
+    upload_stream.finish().await.unwrap();
+
+    let mut download_stream = bucket.open_download_stream(id.clone()).await.unwrap();
+    let mut buf = [0u8; 3];
+    download_stream.read_exact(&mut buf).await.unwrap();
+    assert_eq!(&buf, &data[..3]);
+    let mut rest = Vec::new();
+    download_stream.read_to_end(&mut rest).await.unwrap();
+    assert_eq!(rest, &data[3..]);
+
+    let mut written = Vec::new();
+    bucket
+        .download_to_futures_0_3_writer(id, &mut written)
+        .await
+        .unwrap();
+    assert_eq!(written, data);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn download_stream_missing_chunk() {
+    let _guard = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    let db = client.database(function_name!());
+    let options = GridFsBucketOptions::builder().chunk_size_bytes(4).build();
+    let bucket = db.gridfs_bucket(options);
+    bucket.drop().await.unwrap();
+
+    let mut upload_stream = bucket
+        .open_upload_stream("test".to_string(), None)
+        .await
+        .unwrap();
+    upload_stream.write_all(&[1u8; 12]).await.unwrap();
+    let id = upload_stream.files_id().clone();
+    upload_stream.finish().await.unwrap();
+
+    db.collection::<Document>("fs.chunks")
+        .delete_one(doc! { "files_id": id.clone(), "n": 1 }, None)
+        .await
+        .unwrap();
+
+    let mut download_stream = bucket.open_download_stream(id).await.unwrap();
+    let mut buf = Vec::new();
+    let error = download_stream.read_to_end(&mut buf).await.unwrap_err();
+    let error = error.into_inner().unwrap().downcast::<Error>().unwrap();
+    assert!(matches!(
+        *error.kind,
+        ErrorKind::GridFs(GridFsErrorKind::MissingChunk { n: 1 })
+    ));
+    // The bytes from the chunk before the missing one are still returned.
+    assert_eq!(buf, vec![1u8; 4]);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn download_stream_range_and_seek() {
+    let _guard = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    let db = client.database(function_name!());
+    let options = GridFsBucketOptions::builder().chunk_size_bytes(4).build();
+    let bucket = db.gridfs_bucket(options);
+    bucket.drop().await.unwrap();
+
+    let data: Vec<u8> = (0..100).collect();
+    let mut upload_stream = bucket
+        .open_upload_stream("test".to_string(), None)
+        .await
+        .unwrap();
+    upload_stream.write_all(&data).await.unwrap();
+    let id = upload_stream.files_id().clone();
+    upload_stream.finish().await.unwrap();
+
+    let mut range_stream = bucket
+        .open_download_stream_range(id.clone(), 10..30)
+        .await
+        .unwrap();
+    let mut buf = Vec::new();
+    range_stream.read_to_end(&mut buf).await.unwrap();
+    assert_eq!(buf, &data[10..30]);
+
+    // Positions are relative to the start of the range.
+    assert_eq!(range_stream.seek(SeekFrom::Start(5)).await.unwrap(), 5);
+    let mut buf = [0u8; 3];
+    range_stream.read_exact(&mut buf).await.unwrap();
+    assert_eq!(&buf, &data[15..18]);
+    assert_eq!(range_stream.seek(SeekFrom::End(-2)).await.unwrap(), 18);
+    let mut buf = Vec::new();
+    range_stream.read_to_end(&mut buf).await.unwrap();
+    assert_eq!(buf, &data[28..30]);
+    assert!(range_stream.seek(SeekFrom::Current(-21)).await.is_err());
+
+    // The end of the range is clamped to the length of the file.
+    let mut range_stream = bucket
+        .open_download_stream_range(id.clone(), 95..)
+        .await
+        .unwrap();
+    let mut buf = Vec::new();
+    range_stream.read_to_end(&mut buf).await.unwrap();
+    assert_eq!(buf, &data[95..]);
+
+    assert!(bucket
+        .open_download_stream_range(id.clone(), 101..)
+        .await
+        .is_err());
+
+    let mut download_stream = bucket.open_download_stream(id).await.unwrap();
+    download_stream.seek(SeekFrom::Start(50)).await.unwrap();
+    download_stream.seek(SeekFrom::Current(-48)).await.unwrap();
+    let mut buf = Vec::new();
+    download_stream.read_to_end(&mut buf).await.unwrap();
+    assert_eq!(buf, &data[2..]);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn upload_from_reader_and_download_to_writer() {
+    let _guard = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    let db = client.database(function_name!());
+    let options = GridFsBucketOptions::builder().chunk_size_bytes(10).build();
+    let bucket = db.gridfs_bucket(options);
+    bucket.drop().await.unwrap();
+
+    let data: Vec<u8> = (0..95).collect();
+
+    let id = bucket
+        .upload_from_tokio_reader("tokio".to_string(), data.as_slice(), None)
+        .await
+        .unwrap();
+    let mut written = Vec::new();
+    bucket
+        .download_to_tokio_writer(id.clone(), &mut written)
+        .await
+        .unwrap();
+    assert_eq!(written, data);
+
+    let id = bucket
+        .upload_from_futures_0_3_reader("futures".to_string(), data.as_slice(), None)
+        .await
+        .unwrap();
+    let mut written = Vec::new();
+    bucket
+        .download_to_tokio_writer_by_name("futures", &mut written, None)
+        .await
+        .unwrap();
+    assert_eq!(written, data);
+    let file = bucket
+        .find(doc! { "_id": id }, None)
+        .await
+        .unwrap()
+        .next()
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(file.length, 95);
+}
+
+/// A reader that returns the given bytes and then fails.
+struct FailingReader {
+    bytes: Vec<u8>,
+}
+
+impl tokio::io::AsyncRead for FailingReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let reader = self.get_mut();
+        if reader.bytes.is_empty() {
+            return Poll::Ready(Err(std::io::ErrorKind::BrokenPipe.into()));
+        }
+        let amt = std::cmp::min(reader.bytes.len(), buf.remaining());
+        buf.put_slice(&reader.bytes[..amt]);
+        reader.bytes.drain(..amt);
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn upload_from_reader_error_cleans_up() {
+    let _guard = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    let db = client.database(function_name!());
+    let options = GridFsBucketOptions::builder().chunk_size_bytes(4).build();
+    let bucket = db.gridfs_bucket(options);
+    bucket.drop().await.unwrap();
+
+    let source = FailingReader {
+        bytes: vec![0u8; 10],
+    };
+    let error = bucket
+        .upload_from_tokio_reader("test".to_string(), source, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(*error.kind, ErrorKind::Io(_)));
+
+    let chunks = db.collection::<Document>("fs.chunks");
+    assert_eq!(chunks.count_documents(None, None).await.unwrap(), 0);
+    let files = db.collection::<Document>("fs.files");
+    assert_eq!(files.count_documents(None, None).await.unwrap(), 0);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn upload_stream_drop_after_failed_close_cleans_up() {
+    let _guard = LOCK.run_exclusively().await;
+
+    let client = TestClient::new().await;
+    if !client.supports_fail_command() {
+        log_uncaptured(format!(
+            "skipping {} due to fail points not being supported",
+            function_name!()
+        ));
+        return;
+    }
+
+    let db = client.database(function_name!());
+    let options = GridFsBucketOptions::builder().chunk_size_bytes(8).build();
+    let bucket = db.gridfs_bucket(options);
+    bucket.drop().await.unwrap();
+
+    let mut upload_stream = bucket
+        .open_upload_stream("test".to_string(), None)
+        .await
+        .unwrap();
+    upload_stream.write_all(&[1u8; 4]).await.unwrap();
+
+    // Let the insert of the final chunk succeed and fail the insert of the files document.
+    let fail_point_guard = FailPoint::fail_command(
+        &["insert"],
+        FailPointMode::Skip(1),
+        FailCommandOptions::builder().error_code(8).build(),
+    )
+    .enable(&client, None)
+    .await
+    .unwrap();
+    upload_stream.close().await.unwrap_err();
+    drop(fail_point_guard);
+
+    let chunks = db.collection::<Document>("fs.chunks");
+    assert_eq!(chunks.count_documents(None, None).await.unwrap(), 1);
+
+    // The chunk is deleted in the background when the stream is dropped.
+    drop(upload_stream);
+    let mut count = 1;
+    for _ in 0..50 {
+        count = chunks.count_documents(None, None).await.unwrap();
+        if count == 0 {
+            break;
+        }
+        runtime::delay_for(Duration::from_millis(100)).await;
+    }
+    assert_eq!(count, 0);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn checksum_verified_on_download() {
+    use sha2::{Digest, Sha256};
+
+    let _guard = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    let db = client.database(function_name!());
+    let options = GridFsBucketOptions::builder()
+        .chunk_size_bytes(4)
+        .checksum(GridFsChecksumAlgorithm::Sha256)
+        .build();
+    let bucket = db.gridfs_bucket(options);
+    bucket.drop().await.unwrap();
+
+    let data: Vec<u8> = (0..10).collect();
+    let id = bucket
+        .upload_from_futures_0_3_reader("test".to_string(), data.as_slice(), None)
+        .await
+        .unwrap();
+    let file = bucket
+        .find(doc! { "_id": id.clone() }, None)
+        .await
+        .unwrap()
+        .next()
+        .await
+        .unwrap()
+        .unwrap();
+    let checksum = file.checksum.unwrap();
+    assert_eq!(checksum.algorithm, GridFsChecksumAlgorithm::Sha256);
+    assert_eq!(checksum.digest, hex::encode(Sha256::digest(&data)));
+
+    let mut buf = Vec::new();
+    let mut download_stream = bucket.open_download_stream(id.clone()).await.unwrap();
+    download_stream.read_to_end(&mut buf).await.unwrap();
+    assert_eq!(buf, data);
+
+    // Upload options without a checksum use the bucket's algorithm.
+    let upload_options = GridFsUploadOptions::builder()
+        .chunk_size_bytes(None)
+        .metadata(None)
+        .build();
+    let default_id = bucket
+        .upload_from_futures_0_3_reader("default".to_string(), data.as_slice(), upload_options)
+        .await
+        .unwrap();
+    let file = bucket
+        .find(doc! { "_id": default_id }, None)
+        .await
+        .unwrap()
+        .next()
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        file.checksum.unwrap().algorithm,
+        GridFsChecksumAlgorithm::Sha256
+    );
+
+    // The upload options take precedence over the bucket options.
+    let upload_options = GridFsUploadOptions::builder()
+        .chunk_size_bytes(None)
+        .metadata(None)
+        .checksum(GridFsChecksumAlgorithm::Md5)
+        .build();
+    let md5_id = bucket
+        .upload_from_futures_0_3_reader("md5".to_string(), data.as_slice(), upload_options)
+        .await
+        .unwrap();
+    let file = bucket
+        .find(doc! { "_id": md5_id }, None)
+        .await
+        .unwrap()
+        .next()
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        file.checksum.unwrap().algorithm,
+        GridFsChecksumAlgorithm::Md5
+    );
+
+    let corrupted = Binary {
+        subtype: BinarySubtype::Generic,
+        bytes: vec![0, 0, 0, 0],
+    };
+    db.collection::<Document>("fs.chunks")
+        .update_one(
+            doc! { "files_id": id.clone(), "n": 1 },
+            doc! { "$set": { "data": corrupted } },
+            None,
+        )
+        .await
+        .unwrap();
+
+    let mut buf = Vec::new();
+    let mut download_stream = bucket.open_download_stream(id.clone()).await.unwrap();
+    let error = download_stream.read_to_end(&mut buf).await.unwrap_err();
+    let error = error.into_inner().unwrap().downcast::<Error>().unwrap();
+    assert!(matches!(
+        *error.kind,
+        ErrorKind::GridFs(GridFsErrorKind::ChecksumMismatch { .. })
+    ));
+    // The final chunk is withheld when the checksum does not match.
+    assert_eq!(buf.len(), 8);
+
+    let mut written = Vec::new();
+    let error = bucket
+        .download_to_futures_0_3_writer(id.clone(), &mut written)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        *error.kind,
+        ErrorKind::GridFs(GridFsErrorKind::ChecksumMismatch { .. })
+    ));
+
+    // The checksum is not verified for range reads that do not cover the whole file.
+    let mut buf = Vec::new();
+    let mut range_stream = bucket.open_download_stream_range(id, 0..9).await.unwrap();
+    range_stream.read_to_end(&mut buf).await.unwrap();
+    assert_eq!(buf.len(), 9);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn upload_and_download_in_transaction() {
+    let _guard = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    if !client.is_replica_set() || !client.supports_transactions() {
+        log_uncaptured(
+            "skipping upload_and_download_in_transaction due to lack of transaction support",
+        );
+        return;
+    }
+
+    let db = client.database(function_name!());
+    let options = GridFsBucketOptions::builder().chunk_size_bytes(4).build();
+    let bucket = db.gridfs_bucket(options);
+    bucket.drop().await.unwrap();
+    let refs = db.collection::<Document>("refs");
+    refs.drop(None).await.unwrap();
+
+    // Collections can't be created implicitly within a transaction on older server versions, so
+    // create them and the GridFS indexes up front.
+    let setup_id = bucket
+        .upload_from_futures_0_3_reader("setup".to_string(), &[0u8][..], None)
+        .await
+        .unwrap();
+    bucket.delete(setup_id).await.unwrap();
+    refs.insert_one(doc! {}, None).await.unwrap();
+
+    let data: Vec<u8> = (0..10).collect();
+    let mut session = client.start_session(None).await.unwrap();
+
+    session.start_transaction(None).await.unwrap();
+    let id = bucket
+        .upload_from_futures_0_3_reader_with_session(
+            "aborted".to_string(),
+            data.as_slice(),
+            None,
+            &mut session,
+        )
+        .await
+        .unwrap();
+    refs.insert_one_with_session(doc! { "file": id.clone() }, None, &mut session)
+        .await
+        .unwrap();
+    session.abort_transaction().await.unwrap();
+    assert!(bucket.open_download_stream(id).await.is_err());
+    let chunks = db.collection::<Document>("fs.chunks");
+    assert_eq!(chunks.count_documents(None, None).await.unwrap(), 0);
+
+    session.start_transaction(None).await.unwrap();
+    let id = bucket
+        .upload_from_futures_0_3_reader_with_session(
+            "committed".to_string(),
+            data.as_slice(),
+            None,
+            &mut session,
+        )
+        .await
+        .unwrap();
+    refs.insert_one_with_session(doc! { "file": id.clone() }, None, &mut session)
+        .await
+        .unwrap();
+    session.commit_transaction().await.unwrap();
+
+    let mut written = Vec::new();
+    bucket
+        .download_to_futures_0_3_writer_with_session(id.clone(), &mut written, &mut session)
+        .await
+        .unwrap();
+    assert_eq!(written, data);
+
+    bucket
+        .rename_with_session(id.clone(), "renamed", &mut session)
+        .await
+        .unwrap();
+    let mut written = Vec::new();
+    bucket
+        .download_to_futures_0_3_writer_by_name_with_session(
+            "renamed",
+            &mut written,
+            None,
+            &mut session,
+        )
+        .await
+        .unwrap();
+    assert_eq!(written, data);
+
+    bucket.delete_with_session(id, &mut session).await.unwrap();
+    let mut cursor = bucket
+        .find_with_session(doc! {}, None, &mut session)
+        .await
+        .unwrap();
+    assert!(cursor.next(&mut session).await.is_none());
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn typed_metadata() {
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Metadata {
+        owner: String,
+        retention_days: i32,
+    }
+
+    let _guard = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    let db = client.database(function_name!());
+    let bucket = db.gridfs_bucket(None);
+    bucket.drop().await.unwrap();
+
+    let typed = bucket.typed_metadata::<Metadata>();
+    let metadata = Metadata {
+        owner: "audit".to_string(),
+        retention_days: 365,
+    };
+    let id = typed
+        .upload_from_futures_0_3_reader("report".to_string(), &[1u8, 2, 3][..], &metadata, None)
+        .await
+        .unwrap();
+
+    let mut session = client.start_session(None).await.unwrap();
+    let session_id = typed
+        .upload_from_futures_0_3_reader_with_session(
+            "session".to_string(),
+            &[4u8][..],
+            &metadata,
+            None,
+            &mut session,
+        )
+        .await
+        .unwrap();
+    let file = typed
+        .find_with_session(doc! { "_id": session_id }, None, &mut session)
+        .await
+        .unwrap()
+        .next(&mut session)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(file.metadata.as_ref(), Some(&metadata));
+
+    let file = typed
+        .find(doc! { "_id": id.clone() }, None)
+        .await
+        .unwrap()
+        .next()
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(file.metadata, Some(metadata));
+    assert_eq!(file.length, 3);
+
+    let file = bucket
+        .find(doc! { "_id": id }, None)
+        .await
+        .unwrap()
+        .next()
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        file.metadata,
+        Some(doc! { "owner": "audit", "retention_days": 365 })
+    );
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn verify_and_collect_garbage() {
+    let _guard = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    if client.server_version_lt(4, 4) {
+        log_uncaptured("skipping verify_and_collect_garbage due to server version < 4.4");
+        return;
+    }
+
+    let db = client.database(function_name!());
+    let options = GridFsBucketOptions::builder().chunk_size_bytes(4).build();
+    let bucket = db.gridfs_bucket(options);
+    bucket.drop().await.unwrap();
+
+    let intact_id = bucket
+        .upload_from_futures_0_3_reader("intact".to_string(), &[0u8; 10][..], None)
+        .await
+        .unwrap();
+    let damaged_id = bucket
+        .upload_from_futures_0_3_reader("damaged".to_string(), &[0u8; 10][..], None)
+        .await
+        .unwrap();
+    let orphan_id = bucket
+        .upload_from_futures_0_3_reader("orphan".to_string(), &[0u8; 10][..], None)
+        .await
+        .unwrap();
+    assert!(bucket.verify().await.unwrap().is_consistent());
+
+    db.collection::<Document>("fs.chunks")
+        .delete_one(doc! { "files_id": damaged_id.clone(), "n": 1 }, None)
+        .await
+        .unwrap();
+    db.collection::<Document>("fs.files")
+        .delete_one(doc! { "_id": orphan_id.clone() }, None)
+        .await
+        .unwrap();
+
+    let report = bucket.verify().await.unwrap();
+    assert_eq!(report.orphaned_chunks.len(), 1);
+    assert_eq!(report.orphaned_chunks[0].files_id, orphan_id);
+    assert_eq!(report.orphaned_chunks[0].count, 3);
+    assert!(report
+        .file_errors
+        .iter()
+        .all(|error| error.files_id == damaged_id));
+    assert!(report
+        .file_errors
+        .iter()
+        .any(|error| matches!(error.kind, GridFsErrorKind::MissingChunk { n: 1 })));
+    assert!(report.file_errors.iter().any(|error| matches!(
+        error.kind,
+        GridFsErrorKind::WrongNumberOfChunks {
+            actual_number: 2,
+            expected_number: 3
+        }
+    )));
+
+    // The orphaned chunks are within the default grace period.
+    let result = bucket.collect_garbage(None).await.unwrap();
+    assert_eq!(result.deleted_count, 0);
+
+    let options = GridFsCollectGarbageOptions::builder()
+        .grace_period(Duration::from_secs(0))
+        .build();
+    let result = bucket.collect_garbage(options).await.unwrap();
+    assert_eq!(result.deleted_count, 3);
+    assert!(bucket.verify().await.unwrap().orphaned_chunks.is_empty());
+
+    let mut written = Vec::new();
+    bucket
+        .download_to_futures_0_3_writer(intact_id, &mut written)
+        .await
+        .unwrap();
+    assert_eq!(written, vec![0u8; 10]);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn verify_mixed_id_types() {
+    let _guard = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    if client.server_version_lt(4, 4) {
+        log_uncaptured("skipping verify_mixed_id_types due to server version < 4.4");
+        return;
+    }
+
+    let db = client.database(function_name!());
+    let bucket = db.gridfs_bucket(None);
+    bucket.drop().await.unwrap();
+
+    let file = |id: Bson| {
+        doc! {
+            "_id": id,
+            "length": 4_i64,
+            "chunkSize": 4,
+            "uploadDate": DateTime::now(),
+            "filename": "test",
+        }
+    };
+    let chunk = |files_id: Bson| {
+        doc! {
+            "files_id": files_id,
+            "n": 0,
+            "data": Binary { subtype: BinarySubtype::Generic, bytes: vec![0u8; 4] },
+        }
+    };
+    // The Decimal128 5, with a coefficient of 5 and an exponent of 0.
+    let five = Decimal128::from_bytes(((6176_u128 << 113) | 5).to_le_bytes());
+    db.collection::<Document>("fs.files")
+        .insert_many(
+            vec![
+                file(Bson::Int32(1)),
+                file(Bson::Decimal128(five)),
+                file(Bson::Int32(7)),
+                file(Bson::String("a".to_string())),
+            ],
+            None,
+        )
+        .await
+        .unwrap();
+    // The chunks of files 5 and 7 have an Int64 files_id and belong to the files, since downloads
+    // find chunks by numeric equality. The chunks with files_id 3 and "b" have no file.
+    db.collection::<Document>("fs.chunks")
+        .insert_many(
+            vec![
+                chunk(Bson::Int32(1)),
+                chunk(Bson::Int32(3)),
+                chunk(Bson::Int64(5)),
+                chunk(Bson::Int64(7)),
+                chunk(Bson::String("a".to_string())),
+                chunk(Bson::String("b".to_string())),
+            ],
+            None,
+        )
+        .await
+        .unwrap();
+
+    let report = bucket.verify().await.unwrap();
+    assert!(report.file_errors.is_empty(), "{:?}", report.file_errors);
+    let orphans: Vec<_> = report
+        .orphaned_chunks
+        .iter()
+        .map(|orphan| orphan.files_id.clone())
+        .collect();
+    assert_eq!(orphans, vec![Bson::Int32(3), Bson::String("b".to_string())]);
+
+    let options = GridFsCollectGarbageOptions::builder()
+        .grace_period(Duration::from_secs(0))
+        .build();
+    let result = bucket.collect_garbage(options).await.unwrap();
+    assert_eq!(result.deleted_count, 2);
+
+    for id in [Bson::Decimal128(five), Bson::Int32(7)] {
+        let mut written = Vec::new();
+        bucket
+            .download_to_futures_0_3_writer(id, &mut written)
+            .await
+            .unwrap();
+        assert_eq!(written, vec![0u8; 4]);
+    }
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn manage_revisions() {
+    let _guard = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    let db = client.database(function_name!());
+    let bucket = db.gridfs_bucket(None);
+    bucket.drop().await.unwrap();
+
+    let mut ids = Vec::new();
+    for i in 0..5u8 {
+        let id = bucket
+            .upload_from_futures_0_3_reader("doc".to_string(), &[i][..], None)
+            .await
+            .unwrap();
+        ids.push(id);
+    }
+    let other_id = bucket
+        .upload_from_futures_0_3_reader("other".to_string(), &[9u8][..], None)
+        .await
+        .unwrap();
+    // Give every revision the same upload date so that revisions are ordered by their ids.
+    db.collection::<Document>("fs.files")
+        .update_many(
+            doc! { "filename": "doc" },
+            doc! { "$set": { "uploadDate": DateTime::now() } },
+            None,
+        )
+        .await
+        .unwrap();
+
+    let mut written = Vec::new();
+    bucket
+        .download_to_futures_0_3_writer_by_name("doc", &mut written, None)
+        .await
+        .unwrap();
+    assert_eq!(written, vec![4u8]);
+
+    assert_eq!(bucket.prune_revisions("doc", 2).await.unwrap(), 3);
+    assert_eq!(bucket.prune_revisions("doc", 2).await.unwrap(), 0);
+    let remaining: Vec<_> = bucket
+        .find(doc! { "filename": "doc" }, None)
+        .await
+        .unwrap()
+        .map(|file| file.unwrap().id)
+        .collect()
+        .await;
+    assert_eq!(remaining.len(), 2);
+    assert!(remaining.contains(&ids[3]));
+    assert!(remaining.contains(&ids[4]));
+    let chunks = db.collection::<Document>("fs.chunks");
+    assert_eq!(chunks.count_documents(None, None).await.unwrap(), 3);
+
+    bucket.rename_all_revisions("doc", "renamed").await.unwrap();
+    let mut written = Vec::new();
+    bucket
+        .download_to_futures_0_3_writer_by_name("renamed", &mut written, None)
+        .await
+        .unwrap();
+    assert_eq!(written, vec![4u8]);
+    let error = bucket
+        .rename_all_revisions("doc", "renamed")
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        *error.kind,
+        ErrorKind::GridFs(GridFsErrorKind::FileNotFound { .. })
+    ));
+
+    assert_eq!(bucket.delete_by_name("renamed").await.unwrap(), 2);
+    assert_eq!(chunks.count_documents(None, None).await.unwrap(), 1);
+    let error = bucket.delete_by_name("renamed").await.unwrap_err();
+    assert!(matches!(
+        *error.kind,
+        ErrorKind::GridFs(GridFsErrorKind::FileNotFound { .. })
+    ));
+    bucket.open_download_stream(other_id).await.unwrap();
+}
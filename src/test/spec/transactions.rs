@@ -1,9 +1,20 @@
+use futures::FutureExt;
 use serde::{Deserialize, Serialize};
 use tokio::sync::{RwLockReadGuard, RwLockWriteGuard};
 
 use crate::{
     bson::{doc, Document},
-    test::{log_uncaptured, run_spec_test, TestClient, LOCK},
+    error::{ErrorKind, Result, TRANSIENT_TRANSACTION_ERROR, UNKNOWN_TRANSACTION_COMMIT_RESULT},
+    test::{
+        log_uncaptured,
+        run_spec_test,
+        EventClient,
+        FailCommandOptions,
+        FailPoint,
+        FailPointMode,
+        TestClient,
+        LOCK,
+    },
     Collection,
 };
 
@@ -80,3 +91,147 @@ async fn deserialize_recovery_token() {
     // Nevertheless, the recovery token should have been retrieved from the ok: 1 response.
     assert!(session.transaction.recovery_token.is_some());
 }
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test(flavor = "multi_thread"))]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn convenient_api_custom_error() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = EventClient::new().await;
+    if !client.supports_transactions() {
+        log_uncaptured("skipping convenient_api_custom_error due to test topology");
+        return;
+    }
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+
+    let mut session = client.start_session(None).await.unwrap();
+    let result: Result<()> = session
+        .with_transaction(
+            |session| {
+                let coll = coll.clone();
+                async move {
+                    coll.insert_one_with_session(doc! {}, None, session).await?;
+                    Err(ErrorKind::InvalidArgument {
+                        message: "callback error".to_string(),
+                    }
+                    .into())
+                }
+                .boxed()
+            },
+            None,
+        )
+        .await;
+
+    let error = result.unwrap_err();
+    assert!(matches!(*error.kind, ErrorKind::InvalidArgument { .. }));
+    assert!(!session.in_transaction());
+    assert_eq!(
+        client
+            .get_command_started_events(&["abortTransaction"])
+            .len(),
+        1
+    );
+    assert_eq!(
+        client
+            .get_command_started_events(&["commitTransaction"])
+            .len(),
+        0
+    );
+    assert_eq!(coll.count_documents(None, None).await.unwrap(), 0);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test(flavor = "multi_thread"))]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn convenient_api_returned_value() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    if !client.supports_transactions() {
+        log_uncaptured("skipping convenient_api_returned_value due to test topology");
+        return;
+    }
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+
+    let mut session = client.start_session(None).await.unwrap();
+    let value = session
+        .with_transaction(
+            |session| {
+                let coll = coll.clone();
+                async move {
+                    coll.insert_one_with_session(doc! {}, None, session).await?;
+                    Ok(42)
+                }
+                .boxed()
+            },
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(value, 42);
+    assert_eq!(coll.count_documents(None, None).await.unwrap(), 1);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test(flavor = "multi_thread"))]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn convenient_api_retries_transient_errors() {
+    let _guard: RwLockWriteGuard<()> = LOCK.run_exclusively().await;
+
+    let client = EventClient::new().await;
+    if !client.supports_transactions() || client.server_version_lt(4, 4) {
+        log_uncaptured("skipping convenient_api_retries_transient_errors due to test topology");
+        return;
+    }
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+
+    let options = FailCommandOptions::builder()
+        .error_code(112)
+        .error_labels(vec![TRANSIENT_TRANSACTION_ERROR.to_string()])
+        .build();
+    let failpoint = FailPoint::fail_command(&["insert"], FailPointMode::Times(1), options);
+    let _fp_guard = client.enable_failpoint(failpoint, None).await.unwrap();
+
+    let options = FailCommandOptions::builder()
+        .error_code(91)
+        .error_labels(vec![UNKNOWN_TRANSACTION_COMMIT_RESULT.to_string()])
+        .build();
+    let failpoint =
+        FailPoint::fail_command(&["commitTransaction"], FailPointMode::Times(1), options);
+    let _commit_fp_guard = client.enable_failpoint(failpoint, None).await.unwrap();
+
+    let mut session = client.start_session(None).await.unwrap();
+    session
+        .with_transaction(
+            |session| {
+                let coll = coll.clone();
+                async move {
+                    coll.insert_one_with_session(doc! {}, None, session).await?;
+                    Ok(())
+                }
+                .boxed()
+            },
+            None,
+        )
+        .await
+        .unwrap();
+
+    // The callback is retried after the transient insert error, and the commit is retried after
+    // the unknown commit result.
+    assert_eq!(client.get_command_started_events(&["insert"]).len(), 2);
+    assert_eq!(
+        client
+            .get_command_started_events(&["commitTransaction"])
+            .len(),
+        2
+    );
+    assert_eq!(coll.count_documents(None, None).await.unwrap(), 1);
+}
@@ -12,6 +12,7 @@ use std::{
 
 use futures::{
     future::BoxFuture,
+    io::AsyncWriteExt,
     stream::{StreamExt, TryStreamExt},
     FutureExt,
 };
@@ -38,7 +39,7 @@ use crate::{
     coll::options::Hint,
     collation::Collation,
     error::{ErrorKind, Result},
-    gridfs::options::GridFsDownloadByNameOptions,
+    gridfs::options::{GridFsDownloadByNameOptions, GridFsUploadOptions},
     options::{
         AggregateOptions,
         CountOptions,
@@ -342,6 +343,7 @@ impl<'de> Deserialize<'de> for Operation {
             "download" => deserialize_op::<Download>(definition.arguments),
             "downloadByName" => deserialize_op::<DownloadByName>(definition.arguments),
             "delete" => deserialize_op::<Delete>(definition.arguments),
+            "upload" => deserialize_op::<Upload>(definition.arguments),
             _ => Ok(Box::new(UnimplementedOperation) as Box<dyn TestOperation>),
         }
         .map_err(|e| serde::de::Error::custom(format!("{}", e)))?;
@@ -2721,6 +2723,39 @@ impl TestOperation for Delete {
     }
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Upload {
+    source: Document,
+    filename: String,
+    #[serde(flatten)]
+    options: GridFsUploadOptions,
+}
+
+impl TestOperation for Upload {
+    fn execute_entity_operation<'a>(
+        &'a self,
+        id: &'a str,
+        test_runner: &'a TestRunner,
+    ) -> BoxFuture<'a, Result<Option<Entity>>> {
+        async move {
+            let bucket = test_runner.get_bucket(id).await;
+            let hex_string = self.source.get_str("$$hexBytes").unwrap();
+            let bytes = hex::decode(hex_string).unwrap();
+
+            let mut stream = bucket
+                .open_upload_stream(self.filename.clone(), self.options.clone())
+                .await?;
+            stream.write_all(&bytes).await?;
+            let id = stream.files_id().clone();
+            stream.finish().await?;
+
+            Ok(Some(Entity::Bson(id)))
+        }
+        .boxed()
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub(super) struct UnimplementedOperation;
 
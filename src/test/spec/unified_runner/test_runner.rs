@@ -60,7 +60,6 @@ const SKIPPED_OPERATIONS: &[&str] = &[
     "listCollectionObjects",
     "listDatabaseObjects",
     "mapReduce",
-    "upload",
     "watch",
 ];
 
@@ -0,0 +1,352 @@
+//! Contains a builder for update documents with typed update operators.
+//!
+//! An [`Update`] converts into [`UpdateModifications`], so it can be passed to
+//! [`Collection::update_one`](../struct.Collection.html#method.update_one),
+//! [`Collection::update_many`](../struct.Collection.html#method.update_many) and
+//! [`Collection::find_one_and_update`](../struct.Collection.html#method.find_one_and_update):
+//!
+//! ```rust
+//! # use mongodb::{bson::{doc, Document}, error::Result, Collection};
+//! use mongodb::update::{PushEach, Update};
+//!
+//! # async fn run(coll: Collection<Document>) -> Result<()> {
+//! let update = Update::new()
+//!     .set("status", "shipped")
+//!     .inc("version", 1)
+//!     .push_each("history", PushEach::builder().values(vec!["shipped".into()]).slice(-10).build());
+//! coll.update_one(doc! { "_id": 1 }, update, None).await?;
+//!
+//! // Array filters are not part of the update document, so updates that use them must be split
+//! // into the modifications and the options that carry the filters.
+//! let (update, options) = Update::new()
+//!     .set("items.$[item].shipped", true)
+//!     .array_filter(doc! { "item.sku": "abc" })
+//!     .into_parts();
+//! coll.update_one(doc! { "_id": 1 }, update, options).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(test)]
+mod test;
+
+use typed_builder::TypedBuilder;
+
+use crate::{
+    bson::{doc, Bson, Decimal128, Document},
+    options::{FindOneAndUpdateOptions, UpdateModel, UpdateModifications, UpdateOptions},
+};
+
+/// A builder for an update document made up of update operators.
+///
+/// Each method adds a field to the given operator. Updating the same field with the same operator
+/// again replaces the earlier value; updating it with conflicting operators is rejected by the
+/// server.
+#[derive(Clone, Debug, Default)]
+pub struct Update {
+    document: Document,
+    array_filters: Vec<Document>,
+}
+
+impl Update {
+    /// Creates an empty update.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the value of `field` (`$set`).
+    pub fn set(self, field: impl Into<String>, value: impl Into<Bson>) -> Self {
+        self.operator("$set", field, value.into())
+    }
+
+    /// Removes `field` (`$unset`).
+    pub fn unset(self, field: impl Into<String>) -> Self {
+        self.operator("$unset", field, Bson::String(String::new()))
+    }
+
+    /// Increments `field` by `amount` (`$inc`).
+    pub fn inc(self, field: impl Into<String>, amount: impl Into<Number>) -> Self {
+        self.operator("$inc", field, amount.into().0)
+    }
+
+    /// Multiplies `field` by `factor` (`$mul`).
+    pub fn mul(self, field: impl Into<String>, factor: impl Into<Number>) -> Self {
+        self.operator("$mul", field, factor.into().0)
+    }
+
+    /// Sets `field` to `value` if `value` is less than its current value (`$min`).
+    pub fn min(self, field: impl Into<String>, value: impl Into<Bson>) -> Self {
+        self.operator("$min", field, value.into())
+    }
+
+    /// Sets `field` to `value` if `value` is greater than its current value (`$max`).
+    pub fn max(self, field: impl Into<String>, value: impl Into<Bson>) -> Self {
+        self.operator("$max", field, value.into())
+    }
+
+    /// Appends `value` to the array `field` (`$push`).
+    pub fn push(self, field: impl Into<String>, value: impl Into<Bson>) -> Self {
+        self.operator("$push", field, value.into())
+    }
+
+    /// Appends multiple values to the array `field`, applying the modifiers specified in
+    /// `each` (`$push` with `$each`).
+    pub fn push_each(self, field: impl Into<String>, each: PushEach) -> Self {
+        let value = each.to_document();
+        self.operator("$push", field, Bson::Document(value))
+    }
+
+    /// Removes the elements of the array `field` that are equal to `condition`, or that match it
+    /// if it is a query document (`$pull`).
+    pub fn pull(self, field: impl Into<String>, condition: impl Into<Bson>) -> Self {
+        self.operator("$pull", field, condition.into())
+    }
+
+    /// Appends `value` to the array `field` if it is not already present (`$addToSet`).
+    pub fn add_to_set(self, field: impl Into<String>, value: impl Into<Bson>) -> Self {
+        self.operator("$addToSet", field, value.into())
+    }
+
+    /// Appends each of `values` to the array `field` if it is not already present (`$addToSet`
+    /// with `$each`).
+    pub fn add_to_set_each(
+        self,
+        field: impl Into<String>,
+        values: impl IntoIterator<Item = impl Into<Bson>>,
+    ) -> Self {
+        let values: Vec<Bson> = values.into_iter().map(Into::into).collect();
+        self.operator("$addToSet", field, Bson::Document(doc! { "$each": values }))
+    }
+
+    /// Renames `field` to `new_name` (`$rename`).
+    pub fn rename(self, field: impl Into<String>, new_name: impl Into<String>) -> Self {
+        self.operator("$rename", field, Bson::String(new_name.into()))
+    }
+
+    /// Sets `field` to the current date (`$currentDate`).
+    pub fn current_date(self, field: impl Into<String>, date_type: CurrentDateType) -> Self {
+        let value = doc! { "$type": date_type.as_str() };
+        self.operator("$currentDate", field, Bson::Document(value))
+    }
+
+    /// Adds a filter that determines which array elements are updated for the identifier it
+    /// uses, e.g. `{ "elem.grade": { "$gte": 85 } }` for fields containing `$[elem]`.
+    ///
+    /// Array filters are not part of the update document, so an update that has them cannot be
+    /// converted into [`UpdateModifications`]. Use [`Update::into_parts`],
+    /// [`Update::into_find_one_and_update_parts`] or [`Update::into_update_model`] instead, or
+    /// pass [`Update::array_filters`] to the update's options.
+    pub fn array_filter(mut self, filter: Document) -> Self {
+        self.array_filters.push(filter);
+        self
+    }
+
+    /// Adds the operators and array filters of `other` to this update. Fields of `other` replace
+    /// the same fields updated by the same operator in this update.
+    pub fn merge(mut self, other: Update) -> Self {
+        for (operator, fields) in other.document {
+            if let Bson::Document(fields) = fields {
+                for (field, value) in fields {
+                    self = self.operator(&operator, field, value);
+                }
+            }
+        }
+        self.array_filters.extend(other.array_filters);
+        self
+    }
+
+    /// The array filters added with [`Update::array_filter`], for use with the
+    /// `array_filters` field of
+    /// [`UpdateOptions`](crate::options::UpdateOptions::array_filters) or
+    /// [`FindOneAndUpdateOptions`](crate::options::FindOneAndUpdateOptions::array_filters).
+    pub fn array_filters(&self) -> Option<Vec<Document>> {
+        if self.array_filters.is_empty() {
+            None
+        } else {
+            Some(self.array_filters.clone())
+        }
+    }
+
+    /// Splits the update into its update document and [`UpdateOptions`] with the array filters
+    /// added with [`Update::array_filter`] set.
+    pub fn into_parts(self) -> (UpdateModifications, UpdateOptions) {
+        let options = UpdateOptions::builder()
+            .array_filters(self.array_filters())
+            .build();
+        (UpdateModifications::Document(self.document), options)
+    }
+
+    /// Splits the update into its update document and [`FindOneAndUpdateOptions`] with the array
+    /// filters added with [`Update::array_filter`] set.
+    pub fn into_find_one_and_update_parts(self) -> (UpdateModifications, FindOneAndUpdateOptions) {
+        let options = FindOneAndUpdateOptions::builder()
+            .array_filters(self.array_filters())
+            .build();
+        (UpdateModifications::Document(self.document), options)
+    }
+
+    /// Creates an [`UpdateModel`] for
+    /// [`Collection::bulk_write`](crate::Collection::bulk_write) that applies the update to the
+    /// documents matching `filter`, with the array filters added with [`Update::array_filter`]
+    /// set.
+    pub fn into_update_model(self, filter: Document) -> UpdateModel {
+        UpdateModel::builder()
+            .filter(filter)
+            .array_filters(self.array_filters())
+            .update(UpdateModifications::Document(self.document))
+            .build()
+    }
+
+    /// The update document.
+    pub fn document(&self) -> &Document {
+        &self.document
+    }
+
+    fn operator(mut self, operator: &str, field: impl Into<String>, value: Bson) -> Self {
+        match self.document.get_mut(operator) {
+            Some(Bson::Document(fields)) => {
+                fields.insert(field, value);
+            }
+            _ => {
+                let mut fields = Document::new();
+                fields.insert(field, value);
+                self.document.insert(operator, fields);
+            }
+        }
+        self
+    }
+}
+
+/// Converts the update into its update document.
+///
+/// Panics in debug builds if the update has array filters, since they would be dropped.
+impl From<Update> for UpdateModifications {
+    fn from(update: Update) -> Self {
+        debug_assert!(
+            update.array_filters.is_empty(),
+            "an update with array filters must be split with Update::into_parts"
+        );
+        UpdateModifications::Document(update.document)
+    }
+}
+
+/// Converts the update into its update document.
+///
+/// Panics in debug builds if the update has array filters, since they would be dropped.
+impl From<&Update> for UpdateModifications {
+    fn from(update: &Update) -> Self {
+        debug_assert!(
+            update.array_filters.is_empty(),
+            "an update with array filters must be split with Update::into_parts"
+        );
+        UpdateModifications::Document(update.document.clone())
+    }
+}
+
+/// A numeric operand for the `$inc` and `$mul` operators.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Number(Bson);
+
+impl From<i32> for Number {
+    fn from(n: i32) -> Self {
+        Self(Bson::Int32(n))
+    }
+}
+
+impl From<i64> for Number {
+    fn from(n: i64) -> Self {
+        Self(Bson::Int64(n))
+    }
+}
+
+impl From<f64> for Number {
+    fn from(n: f64) -> Self {
+        Self(Bson::Double(n))
+    }
+}
+
+impl From<Decimal128> for Number {
+    fn from(n: Decimal128) -> Self {
+        Self(Bson::Decimal128(n))
+    }
+}
+
+/// The values and modifiers of a `$push` with `$each`.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct PushEach {
+    /// The values to append.
+    #[builder(!default)]
+    pub values: Vec<Bson>,
+
+    /// The position in the array at which to insert the values (`$position`).
+    pub position: Option<i32>,
+
+    /// The number of elements to keep after the values are inserted and the array is sorted
+    /// (`$slice`). Negative numbers keep elements from the end of the array.
+    pub slice: Option<i32>,
+
+    /// How to order the elements of the array after the values are inserted (`$sort`).
+    pub sort: Option<PushSort>,
+}
+
+impl PushEach {
+    fn to_document(&self) -> Document {
+        let mut document = doc! { "$each": self.values.clone() };
+        if let Some(position) = self.position {
+            document.insert("$position", position);
+        }
+        if let Some(slice) = self.slice {
+            document.insert("$slice", slice);
+        }
+        if let Some(ref sort) = self.sort {
+            document.insert("$sort", sort.to_bson());
+        }
+        document
+    }
+}
+
+/// The order of the array elements after a `$push` with `$each`.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum PushSort {
+    /// Sort the elements in ascending order.
+    Ascending,
+
+    /// Sort the elements in descending order.
+    Descending,
+
+    /// Sort document elements by the given fields, e.g. `{ "score": -1 }`.
+    Fields(Document),
+}
+
+impl PushSort {
+    fn to_bson(&self) -> Bson {
+        match self {
+            PushSort::Ascending => Bson::Int32(1),
+            PushSort::Descending => Bson::Int32(-1),
+            PushSort::Fields(fields) => Bson::Document(fields.clone()),
+        }
+    }
+}
+
+/// The type of value set by `$currentDate`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum CurrentDateType {
+    /// A BSON date.
+    Date,
+
+    /// A BSON timestamp.
+    Timestamp,
+}
+
+impl CurrentDateType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            CurrentDateType::Date => "date",
+            CurrentDateType::Timestamp => "timestamp",
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{
+    bson::{doc, Bson, Document},
+    options::UpdateModifications,
+    update::{CurrentDateType, PushEach, PushSort, Update},
+};
+
+fn document(update: impl Into<UpdateModifications>) -> Document {
+    match update.into() {
+        UpdateModifications::Document(document) => document,
+        other => panic!("expected update document, got {:?}", other),
+    }
+}
+
+#[test]
+fn build() {
+    let update = Update::new()
+        .set("a", 1)
+        .set("b.c", "x")
+        .unset("d")
+        .inc("e", 2)
+        .inc("f", 1.5)
+        .mul("g", 2_i64)
+        .min("h", 0)
+        .max("i", 10)
+        .rename("j", "k")
+        .current_date("l", CurrentDateType::Date)
+        .current_date("m", CurrentDateType::Timestamp)
+        .push("n", 1)
+        .pull("o", doc! { "$gte": 6 })
+        .add_to_set("p", "q")
+        .add_to_set_each("r", vec![1, 2]);
+
+    assert_eq!(
+        document(update),
+        doc! {
+            "$set": { "a": 1, "b.c": "x" },
+            "$unset": { "d": "" },
+            "$inc": { "e": 2, "f": 1.5 },
+            "$mul": { "g": 2_i64 },
+            "$min": { "h": 0 },
+            "$max": { "i": 10 },
+            "$rename": { "j": "k" },
+            "$currentDate": { "l": { "$type": "date" }, "m": { "$type": "timestamp" } },
+            "$push": { "n": 1 },
+            "$pull": { "o": { "$gte": 6 } },
+            "$addToSet": { "r": { "$each": [1, 2] }, "p": "q" },
+        }
+    );
+}
+
+#[test]
+fn push_each() {
+    let update = Update::new()
+        .push_each(
+            "scores",
+            PushEach::builder()
+                .values(vec![Bson::Int32(90), Bson::Int32(85)])
+                .position(0)
+                .slice(-3)
+                .sort(PushSort::Descending)
+                .build(),
+        )
+        .push_each(
+            "quizzes",
+            PushEach::builder()
+                .values(vec![Bson::Document(doc! { "wk": 5, "score": 8 })])
+                .sort(PushSort::Fields(doc! { "score": 1 }))
+                .build(),
+        );
+
+    assert_eq!(
+        document(update),
+        doc! {
+            "$push": {
+                "scores": { "$each": [90, 85], "$position": 0, "$slice": -3, "$sort": -1 },
+                "quizzes": { "$each": [{ "wk": 5, "score": 8 }], "$sort": { "score": 1 } },
+            },
+        }
+    );
+}
+
+#[test]
+fn merge() {
+    let first = Update::new()
+        .set("a", 1)
+        .set("b", 1)
+        .array_filter(doc! { "x.y": 1 });
+    let second = Update::new()
+        .set("b", 2)
+        .inc("c", 1)
+        .array_filter(doc! { "z.y": 2 });
+
+    let update = first.merge(second);
+    assert_eq!(
+        update.array_filters(),
+        Some(vec![doc! { "x.y": 1 }, doc! { "z.y": 2 }])
+    );
+    assert_eq!(
+        update.document(),
+        &doc! { "$set": { "a": 1, "b": 2 }, "$inc": { "c": 1 } }
+    );
+}
+
+#[test]
+fn array_filters() {
+    let update = Update::new().set("grades.$[elem].passed", true);
+    assert_eq!(update.array_filters(), None);
+
+    let update = update.array_filter(doc! { "elem.grade": { "$gte": 85 } });
+    assert_eq!(
+        update.array_filters(),
+        Some(vec![doc! { "elem.grade": { "$gte": 85 } }])
+    );
+    assert_eq!(
+        update.document(),
+        &doc! { "$set": { "grades.$[elem].passed": true } }
+    );
+}
+
+#[test]
+fn into_parts() {
+    let (modifications, options) = Update::new()
+        .set("grades.$[elem].passed", true)
+        .array_filter(doc! { "elem.grade": { "$gte": 85 } })
+        .into_parts();
+    assert_eq!(
+        document(modifications),
+        doc! { "$set": { "grades.$[elem].passed": true } }
+    );
+    assert_eq!(
+        options.array_filters,
+        Some(vec![doc! { "elem.grade": { "$gte": 85 } }])
+    );
+
+    let (_, options) = Update::new().set("a", 1).into_parts();
+    assert_eq!(options.array_filters, None);
+}
+
+#[test]
+fn into_find_one_and_update_parts() {
+    let (modifications, options) = Update::new()
+        .set("grades.$[elem].passed", true)
+        .array_filter(doc! { "elem.grade": { "$gte": 85 } })
+        .into_find_one_and_update_parts();
+    assert_eq!(
+        document(modifications),
+        doc! { "$set": { "grades.$[elem].passed": true } }
+    );
+    assert_eq!(
+        options.array_filters,
+        Some(vec![doc! { "elem.grade": { "$gte": 85 } }])
+    );
+}
+
+#[test]
+fn into_update_model() {
+    let model = Update::new()
+        .set("grades.$[elem].passed", true)
+        .array_filter(doc! { "elem.grade": { "$gte": 85 } })
+        .into_update_model(doc! { "_id": 1 });
+    assert_eq!(model.filter, doc! { "_id": 1 });
+    assert_eq!(
+        document(model.update),
+        doc! { "$set": { "grades.$[elem].passed": true } }
+    );
+    assert_eq!(
+        model.array_filters,
+        Some(vec![doc! { "elem.grade": { "$gte": 85 } }])
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "array filters")]
+fn conversion_with_array_filters_panics() {
+    let update = Update::new()
+        .set("grades.$[elem].passed", true)
+        .array_filter(doc! { "elem.grade": { "$gte": 85 } });
+    let _ = UpdateModifications::from(update);
+}